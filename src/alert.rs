@@ -0,0 +1,152 @@
+use strum_macros::{Display, EnumIter, EnumString, IntoStaticStr};
+
+/// A TLS alert description, named as in the IANA TLS Alerts registry.
+///
+/// Alerts that TLS 1.3 no longer sends keep their `_RESERVED` suffix from
+/// the registry, e.g. `no_certificate_RESERVED`.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Display, EnumIter, EnumString, IntoStaticStr)]
+pub enum AlertDescription {
+    close_notify,
+    unexpected_message,
+    bad_record_mac,
+    decryption_failed_RESERVED,
+    record_overflow,
+    decompression_failure_RESERVED,
+    handshake_failure,
+    no_certificate_RESERVED,
+    bad_certificate,
+    unsupported_certificate,
+    certificate_revoked,
+    certificate_expired,
+    certificate_unknown,
+    illegal_parameter,
+    unknown_ca,
+    access_denied,
+    decode_error,
+    decrypt_error,
+    too_many_cids_requested,
+    export_restriction_RESERVED,
+    protocol_version,
+    insufficient_security,
+    internal_error,
+    inappropriate_fallback,
+    user_canceled,
+    no_renegotiation_RESERVED,
+    missing_extension,
+    unsupported_extension,
+    certificate_unobtainable_RESERVED,
+    unrecognized_name,
+    bad_certificate_status_response,
+    bad_certificate_hash_value_RESERVED,
+    unknown_psk_identity,
+    certificate_required,
+    general_error,
+    no_application_protocol,
+    ech_required,
+}
+
+impl AlertDescription {
+    pub fn from_u8(value: u8) -> Option<AlertDescription> {
+        match value {
+            0 => Some(Self::close_notify),
+            10 => Some(Self::unexpected_message),
+            20 => Some(Self::bad_record_mac),
+            21 => Some(Self::decryption_failed_RESERVED),
+            22 => Some(Self::record_overflow),
+            30 => Some(Self::decompression_failure_RESERVED),
+            40 => Some(Self::handshake_failure),
+            41 => Some(Self::no_certificate_RESERVED),
+            42 => Some(Self::bad_certificate),
+            43 => Some(Self::unsupported_certificate),
+            44 => Some(Self::certificate_revoked),
+            45 => Some(Self::certificate_expired),
+            46 => Some(Self::certificate_unknown),
+            47 => Some(Self::illegal_parameter),
+            48 => Some(Self::unknown_ca),
+            49 => Some(Self::access_denied),
+            50 => Some(Self::decode_error),
+            51 => Some(Self::decrypt_error),
+            52 => Some(Self::too_many_cids_requested),
+            60 => Some(Self::export_restriction_RESERVED),
+            70 => Some(Self::protocol_version),
+            71 => Some(Self::insufficient_security),
+            80 => Some(Self::internal_error),
+            86 => Some(Self::inappropriate_fallback),
+            90 => Some(Self::user_canceled),
+            100 => Some(Self::no_renegotiation_RESERVED),
+            109 => Some(Self::missing_extension),
+            110 => Some(Self::unsupported_extension),
+            111 => Some(Self::certificate_unobtainable_RESERVED),
+            112 => Some(Self::unrecognized_name),
+            113 => Some(Self::bad_certificate_status_response),
+            114 => Some(Self::bad_certificate_hash_value_RESERVED),
+            115 => Some(Self::unknown_psk_identity),
+            116 => Some(Self::certificate_required),
+            117 => Some(Self::general_error),
+            120 => Some(Self::no_application_protocol),
+            121 => Some(Self::ech_required),
+            _ => None,
+        }
+    }
+
+    pub fn as_u8(&self) -> u8 {
+        match self {
+            Self::close_notify => 0,
+            Self::unexpected_message => 10,
+            Self::bad_record_mac => 20,
+            Self::decryption_failed_RESERVED => 21,
+            Self::record_overflow => 22,
+            Self::decompression_failure_RESERVED => 30,
+            Self::handshake_failure => 40,
+            Self::no_certificate_RESERVED => 41,
+            Self::bad_certificate => 42,
+            Self::unsupported_certificate => 43,
+            Self::certificate_revoked => 44,
+            Self::certificate_expired => 45,
+            Self::certificate_unknown => 46,
+            Self::illegal_parameter => 47,
+            Self::unknown_ca => 48,
+            Self::access_denied => 49,
+            Self::decode_error => 50,
+            Self::decrypt_error => 51,
+            Self::too_many_cids_requested => 52,
+            Self::export_restriction_RESERVED => 60,
+            Self::protocol_version => 70,
+            Self::insufficient_security => 71,
+            Self::internal_error => 80,
+            Self::inappropriate_fallback => 86,
+            Self::user_canceled => 90,
+            Self::no_renegotiation_RESERVED => 100,
+            Self::missing_extension => 109,
+            Self::unsupported_extension => 110,
+            Self::certificate_unobtainable_RESERVED => 111,
+            Self::unrecognized_name => 112,
+            Self::bad_certificate_status_response => 113,
+            Self::bad_certificate_hash_value_RESERVED => 114,
+            Self::unknown_psk_identity => 115,
+            Self::certificate_required => 116,
+            Self::general_error => 117,
+            Self::no_application_protocol => 120,
+            Self::ech_required => 121,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_names() {
+        assert_eq!(
+            AlertDescription::from_u8(40),
+            Some(AlertDescription::handshake_failure)
+        );
+        assert_eq!(
+            AlertDescription::from_u8(86).unwrap().to_string(),
+            "inappropriate_fallback"
+        );
+        assert_eq!(AlertDescription::from_u8(1), None);
+    }
+}
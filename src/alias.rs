@@ -0,0 +1,183 @@
+use strum::IntoEnumIterator;
+
+use crate::lookup::normalize;
+use crate::TLSCipherSuite;
+
+/// Names that OpenSSL releases or LibreSSL have used for a suite besides the
+/// one `as_openssl_name` returns.
+///
+/// Two sources are covered. The first is the `SSL3_TXT_*` and `TLS1_TXT_*`
+/// names in OpenSSL 3.x's `ssl3.h` and `tls1.h`, which still carry the
+/// spellings 1.1.0 replaced. The second is LibreSSL's names for the TLS 1.3
+/// suites. Names found only in the headers of releases before 3.x are not
+/// covered. The pre-RFC ChaCha20 suites get no alias either, since
+/// LibreSSL 2.x used names that the RFC suites now carry.
+const ALIASES: &[(&str, TLSCipherSuite)] = &[
+    // OpenSSL 1.1.0 renamed the EDH- suites to DHE-, and 1.1.0 and later
+    // accept both spellings.
+    (
+        "DHE-DSS-DES-CBC3-SHA",
+        TLSCipherSuite::TLS_DHE_DSS_WITH_3DES_EDE_CBC_SHA,
+    ),
+    (
+        "DHE-RSA-DES-CBC3-SHA",
+        TLSCipherSuite::TLS_DHE_RSA_WITH_3DES_EDE_CBC_SHA,
+    ),
+    (
+        "DHE-DSS-DES-CBC-SHA",
+        TLSCipherSuite::TLS_DHE_DSS_WITH_DES_CBC_SHA,
+    ),
+    (
+        "DHE-RSA-DES-CBC-SHA",
+        TLSCipherSuite::TLS_DHE_RSA_WITH_DES_CBC_SHA,
+    ),
+    (
+        "EXP-DHE-DSS-DES-CBC-SHA",
+        TLSCipherSuite::TLS_DHE_DSS_EXPORT_WITH_DES40_CBC_SHA,
+    ),
+    (
+        "EXP-DHE-RSA-DES-CBC-SHA",
+        TLSCipherSuite::TLS_DHE_RSA_EXPORT_WITH_DES40_CBC_SHA,
+    ),
+    // OpenSSL 1.1.1 and later name these ARIA-GCM suites in tls1.h but never
+    // implemented them, so `as_openssl_name` returns the RFC name.
+    (
+        "DH-RSA-ARIA128-GCM-SHA256",
+        TLSCipherSuite::TLS_DH_RSA_WITH_ARIA_128_GCM_SHA256,
+    ),
+    (
+        "DH-RSA-ARIA256-GCM-SHA384",
+        TLSCipherSuite::TLS_DH_RSA_WITH_ARIA_256_GCM_SHA384,
+    ),
+    (
+        "DH-DSS-ARIA128-GCM-SHA256",
+        TLSCipherSuite::TLS_DH_DSS_WITH_ARIA_128_GCM_SHA256,
+    ),
+    (
+        "DH-DSS-ARIA256-GCM-SHA384",
+        TLSCipherSuite::TLS_DH_DSS_WITH_ARIA_256_GCM_SHA384,
+    ),
+    (
+        "ADH-ARIA128-GCM-SHA256",
+        TLSCipherSuite::TLS_DH_anon_WITH_ARIA_128_GCM_SHA256,
+    ),
+    (
+        "ADH-ARIA256-GCM-SHA384",
+        TLSCipherSuite::TLS_DH_anon_WITH_ARIA_256_GCM_SHA384,
+    ),
+    (
+        "ECDH-ECDSA-ARIA128-GCM-SHA256",
+        TLSCipherSuite::TLS_ECDH_ECDSA_WITH_ARIA_128_GCM_SHA256,
+    ),
+    (
+        "ECDH-ECDSA-ARIA256-GCM-SHA384",
+        TLSCipherSuite::TLS_ECDH_ECDSA_WITH_ARIA_256_GCM_SHA384,
+    ),
+    (
+        "ECDH-ARIA128-GCM-SHA256",
+        TLSCipherSuite::TLS_ECDH_RSA_WITH_ARIA_128_GCM_SHA256,
+    ),
+    (
+        "ECDH-ARIA256-GCM-SHA384",
+        TLSCipherSuite::TLS_ECDH_RSA_WITH_ARIA_256_GCM_SHA384,
+    ),
+    // LibreSSL names the TLS 1.3 suites after their AEAD.
+    (
+        "AEAD-AES128-GCM-SHA256",
+        TLSCipherSuite::TLS_AES_128_GCM_SHA256,
+    ),
+    (
+        "AEAD-AES256-GCM-SHA384",
+        TLSCipherSuite::TLS_AES_256_GCM_SHA384,
+    ),
+    (
+        "AEAD-CHACHA20-POLY1305-SHA256",
+        TLSCipherSuite::TLS_CHACHA20_POLY1305_SHA256,
+    ),
+];
+
+impl TLSCipherSuite {
+    /// Like `from_openssl_name`, but ignores case and surrounding whitespace
+    /// and also accepts names that other OpenSSL releases and LibreSSL have
+    /// used for the same suite. The accepted names are those in OpenSSL 3.x's
+    /// headers and LibreSSL's TLS 1.3 names. Names found only in older
+    /// releases' headers are not included.
+    ///
+    /// ```
+    /// use openssl_to_rfc::TLSCipherSuite;
+    ///
+    /// let cipher = TLSCipherSuite::from_openssl_alias("dhe-rsa-des-cbc3-sha").unwrap();
+    /// assert_eq!(cipher, TLSCipherSuite::TLS_DHE_RSA_WITH_3DES_EDE_CBC_SHA);
+    /// assert_eq!(cipher.as_openssl_name(), "EDH-RSA-DES-CBC3-SHA");
+    /// ```
+    pub fn from_openssl_alias(name: &str) -> Option<TLSCipherSuite> {
+        if let Some(cipher) = TLSCipherSuite::from_openssl_name(name) {
+            return Some(cipher);
+        }
+
+        let wanted = normalize(name);
+        if let Some((_, cipher)) = ALIASES.iter().find(|(alias, _)| *alias == wanted) {
+            return Some(*cipher);
+        }
+
+        TLSCipherSuite::iter().find(|cipher| normalize(&cipher.as_openssl_name()) == wanted)
+    }
+
+    /// The names OpenSSL or LibreSSL have used for this suite that
+    /// `from_openssl_alias` accepts, starting with the one `as_openssl_name`
+    /// returns.
+    pub fn openssl_aliases(&self) -> Vec<String> {
+        let mut names = vec![self.as_openssl_name()];
+        names.extend(
+            ALIASES
+                .iter()
+                .filter(|(_, cipher)| cipher == self)
+                .map(|(alias, _)| (*alias).to_owned()),
+        );
+        names
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aliases_resolve() {
+        for (alias, cipher) in ALIASES {
+            assert_eq!(TLSCipherSuite::from_openssl_alias(alias), Some(*cipher));
+            assert_eq!(
+                TLSCipherSuite::from_openssl_alias(&alias.to_lowercase()),
+                Some(*cipher)
+            );
+            // An alias must never shadow another suite's canonical name.
+            let strict = TLSCipherSuite::from_openssl_name(alias);
+            assert!(strict.is_none() || strict == Some(*cipher));
+        }
+
+        assert_eq!(
+            TLSCipherSuite::from_openssl_alias(" tls_aes_128_gcm_sha256"),
+            Some(TLSCipherSuite::TLS_AES_128_GCM_SHA256)
+        );
+        assert_eq!(TLSCipherSuite::from_openssl_alias("NOT-A-SUITE"), None);
+    }
+
+    #[test]
+    fn aliases_listed() {
+        assert_eq!(
+            TLSCipherSuite::TLS_AES_256_GCM_SHA384.openssl_aliases(),
+            vec!["TLS_AES_256_GCM_SHA384", "AEAD-AES256-GCM-SHA384"]
+        );
+        assert_eq!(
+            TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA.openssl_aliases(),
+            vec!["AES128-SHA"]
+        );
+        assert_eq!(
+            TLSCipherSuite::TLS_ECDH_RSA_WITH_ARIA_128_GCM_SHA256.openssl_aliases(),
+            vec![
+                "TLS_ECDH_RSA_WITH_ARIA_128_GCM_SHA256",
+                "ECDH-ARIA128-GCM-SHA256"
+            ]
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use crate::components::{Authentication, Cipher, KeyExchange, Mac};
+use crate::policy::assess_with;
+use crate::{Assessment, Policy, TLSCipherSuite, Verdict};
+
+const TLS13: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_AES_128_CCM_SHA256,
+];
+
+const FORWARD_SECRECY: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CCM,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CCM,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_DHE_DSS_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_DHE_DSS_WITH_AES_256_CBC_SHA256,
+    TLSCipherSuite::TLS_DHE_DSS_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_DHE_DSS_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_CBC_SHA256,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_128_CCM,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_CCM,
+];
+
+const STATIC_DH: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_ECDH_ECDSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDH_ECDSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_ECDH_ECDSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDH_ECDSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDH_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDH_RSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_ECDH_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDH_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_DH_DSS_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_DH_DSS_WITH_AES_256_CBC_SHA256,
+    TLSCipherSuite::TLS_DH_DSS_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_DH_DSS_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_DH_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_DH_RSA_WITH_AES_256_CBC_SHA256,
+    TLSCipherSuite::TLS_DH_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_DH_RSA_WITH_AES_256_GCM_SHA384,
+];
+
+const PRE_SHARED_KEY: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_ECDHE_PSK_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDHE_PSK_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_ECDHE_PSK_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_PSK_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_PSK_WITH_AES_128_CCM_SHA256,
+    TLSCipherSuite::TLS_DHE_PSK_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_DHE_PSK_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_DHE_PSK_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_DHE_PSK_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_DHE_PSK_WITH_AES_128_CCM,
+    TLSCipherSuite::TLS_DHE_PSK_WITH_AES_256_CCM,
+    TLSCipherSuite::TLS_RSA_PSK_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_RSA_PSK_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_RSA_PSK_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_RSA_PSK_WITH_AES_256_GCM_SHA384,
+];
+
+const TLS12_SECTION: &str = "BSI TR-02102-2, Section 3.3.1";
+const TLS13_SECTION: &str = "BSI TR-02102-2, Section 3.4.4";
+
+/// BSI TR-02102-2, the German Federal Office for Information Security's
+/// recommendations for TLS.
+///
+/// For TLS 1.2 the guideline recommends AES suites with a SHA-2 MAC. It
+/// lists them in three groups: ephemeral (EC)DH, static (EC)DH without
+/// forward secrecy, and pre-shared keys. All three are approved here, and
+/// the reason says which group a suite comes from. For TLS 1.3 only the
+/// AES-GCM suites and `TLS_AES_128_CCM_SHA256` are recommended.
+///
+/// ```
+/// use openssl_to_rfc::{BsiTr02102, Policy, TLSCipherSuite};
+///
+/// let assessment = BsiTr02102.assess(TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA);
+/// assert!(!assessment.is_approved());
+/// assert_eq!(assessment.reason, "SHA-1 MAC");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct BsiTr02102;
+
+impl Policy for BsiTr02102 {
+    fn name(&self) -> String {
+        "BSI TR-02102-2".to_owned()
+    }
+
+    fn assess(&self, cipher: TLSCipherSuite) -> Assessment {
+        assess_with(cipher, judge)
+    }
+}
+
+fn judge(cipher: TLSCipherSuite) -> (Verdict, &'static str, Option<&'static str>) {
+    use Verdict::*;
+
+    let c = cipher.components();
+    if cipher.is_tls13() {
+        return if TLS13.contains(&cipher) {
+            (Approved, "recommended for TLS 1.3", Some(TLS13_SECTION))
+        } else {
+            (
+                NotApproved,
+                "not recommended for TLS 1.3",
+                Some(TLS13_SECTION),
+            )
+        };
+    }
+
+    let reason = if FORWARD_SECRECY.contains(&cipher) {
+        "recommended with forward secrecy"
+    } else if STATIC_DH.contains(&cipher) {
+        "recommended without forward secrecy"
+    } else if PRE_SHARED_KEY.contains(&cipher) {
+        "recommended with pre-shared keys"
+    } else {
+        let reason = if c.authentication == Authentication::Null {
+            "anonymous key exchange"
+        } else if c.key_exchange == KeyExchange::Rsa {
+            "RSA key transport"
+        } else if c.encryption.cipher != Cipher::Aes {
+            "encryption is not AES"
+        } else if !c.encryption.is_aead() && c.mac == Mac::Sha1 {
+            "SHA-1 MAC"
+        } else {
+            "not recommended for TLS 1.2"
+        };
+        return (NotApproved, reason, Some(TLS12_SECTION));
+    };
+    (Approved, reason, Some(TLS12_SECTION))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn recommended_suites() {
+        let count = TLSCipherSuite::iter()
+            .filter(|c| !c.is_signaling() && BsiTr02102.assess(*c).is_approved())
+            .count();
+        assert_eq!(count, 54);
+
+        let assessment = BsiTr02102.assess(TLSCipherSuite::TLS_RSA_WITH_AES_128_GCM_SHA256);
+        assert!(!assessment.is_approved());
+        assert_eq!(assessment.reason, "RSA key transport");
+        assert_eq!(
+            assessment.reference.as_deref(),
+            Some("BSI TR-02102-2, Section 3.3.1")
+        );
+        assert!(!BsiTr02102
+            .assess(TLSCipherSuite::TLS_CHACHA20_POLY1305_SHA256)
+            .is_approved());
+    }
+}
@@ -0,0 +1,685 @@
+use std::fmt;
+
+use strum::IntoEnumIterator;
+
+use crate::components::{Authentication, Cipher, CipherMode, KeyExchange, Mac};
+use crate::{OpenSslVersion, ProtocolVersion, TLSCipherSuite};
+
+// Algorithm bits, mirroring the masks OpenSSL keeps for each suite.
+const K_RSA: u32 = 1 << 0;
+const K_DHE: u32 = 1 << 1;
+const K_ECDHE: u32 = 1 << 2;
+const K_PSK: u32 = 1 << 3;
+const K_RSAPSK: u32 = 1 << 4;
+const K_ECDHEPSK: u32 = 1 << 5;
+const K_DHEPSK: u32 = 1 << 6;
+const K_SRP: u32 = 1 << 7;
+const K_GOST: u32 = 1 << 8;
+const K_GOST18: u32 = 1 << 9;
+const K_ANY_PSK: u32 = K_PSK | K_RSAPSK | K_ECDHEPSK | K_DHEPSK;
+
+const A_RSA: u32 = 1 << 0;
+const A_DSS: u32 = 1 << 1;
+const A_NULL: u32 = 1 << 2;
+const A_ECDSA: u32 = 1 << 3;
+const A_PSK: u32 = 1 << 4;
+const A_GOST01: u32 = 1 << 5;
+const A_GOST12: u32 = 1 << 6;
+const A_SRP: u32 = 1 << 7;
+const A_ALL: u32 = (1 << 8) - 1;
+
+const E_NULL: u32 = 1 << 0;
+const E_RC4: u32 = 1 << 1;
+const E_3DES: u32 = 1 << 2;
+const E_IDEA: u32 = 1 << 3;
+const E_SEED: u32 = 1 << 4;
+const E_AES128: u32 = 1 << 5;
+const E_AES256: u32 = 1 << 6;
+const E_AES128GCM: u32 = 1 << 7;
+const E_AES256GCM: u32 = 1 << 8;
+const E_AES128CCM: u32 = 1 << 9;
+const E_AES256CCM: u32 = 1 << 10;
+const E_AES128CCM8: u32 = 1 << 11;
+const E_AES256CCM8: u32 = 1 << 12;
+const E_CAMELLIA128: u32 = 1 << 13;
+const E_CAMELLIA256: u32 = 1 << 14;
+const E_CHACHA20: u32 = 1 << 15;
+const E_ARIA128GCM: u32 = 1 << 16;
+const E_ARIA256GCM: u32 = 1 << 17;
+const E_GOST89: u32 = 1 << 18;
+const E_GOST89_12: u32 = 1 << 19;
+const E_KUZNYECHIK: u32 = 1 << 20;
+const E_MAGMA: u32 = 1 << 21;
+const E_ALL: u32 = (1 << 22) - 1;
+const E_AESGCM: u32 = E_AES128GCM | E_AES256GCM;
+const E_AESCCM: u32 = E_AES128CCM | E_AES256CCM | E_AES128CCM8 | E_AES256CCM8;
+const E_AES: u32 = E_AES128 | E_AES256 | E_AESGCM | E_AESCCM;
+const E_ARIA: u32 = E_ARIA128GCM | E_ARIA256GCM;
+const E_CBC: u32 = E_3DES | E_IDEA | E_AES128 | E_AES256 | E_CAMELLIA128 | E_CAMELLIA256 | E_SEED;
+
+const M_MD5: u32 = 1 << 0;
+const M_SHA1: u32 = 1 << 1;
+const M_SHA256: u32 = 1 << 2;
+const M_SHA384: u32 = 1 << 3;
+const M_AEAD: u32 = 1 << 4;
+const M_GOST89MAC: u32 = 1 << 5;
+const M_GOST94: u32 = 1 << 6;
+const M_OMAC: u32 = 1 << 7;
+
+const S_LOW: u8 = 1 << 0;
+const S_MEDIUM: u8 = 1 << 1;
+const S_HIGH: u8 = 1 << 2;
+const S_FIPS: u8 = 1 << 3;
+
+/// The criteria of a cipher string element. A zero mask matches anything.
+#[derive(Clone, Copy, Default)]
+struct Selector {
+    cipher: Option<TLSCipherSuite>,
+    mkey: u32,
+    auth: u32,
+    enc: u32,
+    mac: u32,
+    min_tls: Option<ProtocolVersion>,
+    strength: u8,
+    not_default: bool,
+}
+
+impl Selector {
+    const fn new(mkey: u32, auth: u32, enc: u32, mac: u32) -> Selector {
+        Selector {
+            cipher: None,
+            mkey,
+            auth,
+            enc,
+            mac,
+            min_tls: None,
+            strength: 0,
+            not_default: false,
+        }
+    }
+
+    const fn min_tls(version: ProtocolVersion) -> Selector {
+        let mut s = Selector::new(0, 0, 0, 0);
+        s.min_tls = Some(version);
+        s
+    }
+
+    const fn strength(strength: u8) -> Selector {
+        let mut s = Selector::new(0, 0, 0, 0);
+        s.strength = strength;
+        s
+    }
+
+    /// Narrows the selector to suites that also match `other`, as `A+B`
+    /// does. Returns `None` if nothing can match both.
+    fn and(self, other: Selector) -> Option<Selector> {
+        fn both(a: u32, b: u32) -> Option<u32> {
+            match (a, b) {
+                (0, b) => Some(b),
+                (a, 0) => Some(a),
+                (a, b) if a & b != 0 => Some(a & b),
+                _ => None,
+            }
+        }
+
+        let cipher = match (self.cipher, other.cipher) {
+            (Some(a), Some(b)) if a != b => return None,
+            (a, b) => a.or(b),
+        };
+        let min_tls = match (self.min_tls, other.min_tls) {
+            (Some(a), Some(b)) if a != b => return None,
+            (a, b) => a.or(b),
+        };
+        Some(Selector {
+            cipher,
+            mkey: both(self.mkey, other.mkey)?,
+            auth: both(self.auth, other.auth)?,
+            enc: both(self.enc, other.enc)?,
+            mac: both(self.mac, other.mac)?,
+            min_tls,
+            strength: both(u32::from(self.strength), u32::from(other.strength))? as u8,
+            not_default: self.not_default || other.not_default,
+        })
+    }
+
+    fn matches(&self, entry: &Entry) -> bool {
+        let hit = |mask: u32, bits: u32| mask == 0 || mask & bits != 0;
+        self.cipher.is_none_or(|c| c == entry.cipher)
+            && hit(self.mkey, entry.mkey)
+            && hit(self.auth, entry.auth)
+            && hit(self.enc, entry.enc)
+            && hit(self.mac, entry.mac)
+            && self.min_tls.is_none_or(|v| v == entry.min_tls)
+            && hit(u32::from(self.strength), u32::from(entry.strength))
+            && (!self.not_default || entry.not_default)
+    }
+}
+
+/// The aliases OpenSSL 3 understands in cipher strings.
+const ALIASES: &[(&str, Selector)] = &[
+    ("ALL", Selector::new(0, 0, E_ALL & !E_NULL, 0)),
+    ("COMPLEMENTOFALL", Selector::new(0, 0, E_NULL, 0)),
+    (
+        "COMPLEMENTOFDEFAULT",
+        Selector {
+            not_default: true,
+            ..Selector::new(0, 0, 0, 0)
+        },
+    ),
+    ("kRSA", Selector::new(K_RSA, 0, 0, 0)),
+    ("kEDH", Selector::new(K_DHE, 0, 0, 0)),
+    ("kDHE", Selector::new(K_DHE, 0, 0, 0)),
+    ("DH", Selector::new(K_DHE, 0, 0, 0)),
+    ("kEECDH", Selector::new(K_ECDHE, 0, 0, 0)),
+    ("kECDHE", Selector::new(K_ECDHE, 0, 0, 0)),
+    ("ECDH", Selector::new(K_ECDHE, 0, 0, 0)),
+    ("kPSK", Selector::new(K_PSK, 0, 0, 0)),
+    ("kRSAPSK", Selector::new(K_RSAPSK, 0, 0, 0)),
+    ("kECDHEPSK", Selector::new(K_ECDHEPSK, 0, 0, 0)),
+    ("kDHEPSK", Selector::new(K_DHEPSK, 0, 0, 0)),
+    ("kSRP", Selector::new(K_SRP, 0, 0, 0)),
+    ("kGOST", Selector::new(K_GOST, 0, 0, 0)),
+    ("kGOST18", Selector::new(K_GOST18, 0, 0, 0)),
+    ("aRSA", Selector::new(0, A_RSA, 0, 0)),
+    ("aDSS", Selector::new(0, A_DSS, 0, 0)),
+    ("DSS", Selector::new(0, A_DSS, 0, 0)),
+    ("aNULL", Selector::new(0, A_NULL, 0, 0)),
+    ("aECDSA", Selector::new(0, A_ECDSA, 0, 0)),
+    ("ECDSA", Selector::new(0, A_ECDSA, 0, 0)),
+    ("aPSK", Selector::new(0, A_PSK, 0, 0)),
+    ("aGOST01", Selector::new(0, A_GOST01, 0, 0)),
+    ("aGOST12", Selector::new(0, A_GOST12, 0, 0)),
+    ("aGOST", Selector::new(0, A_GOST01 | A_GOST12, 0, 0)),
+    ("aSRP", Selector::new(0, A_SRP, 0, 0)),
+    ("EDH", Selector::new(K_DHE, A_ALL & !A_NULL, 0, 0)),
+    ("DHE", Selector::new(K_DHE, A_ALL & !A_NULL, 0, 0)),
+    ("EECDH", Selector::new(K_ECDHE, A_ALL & !A_NULL, 0, 0)),
+    ("ECDHE", Selector::new(K_ECDHE, A_ALL & !A_NULL, 0, 0)),
+    ("NULL", Selector::new(0, 0, E_NULL, 0)),
+    ("RSA", Selector::new(K_RSA, A_RSA, 0, 0)),
+    ("ADH", Selector::new(K_DHE, A_NULL, 0, 0)),
+    ("AECDH", Selector::new(K_ECDHE, A_NULL, 0, 0)),
+    ("PSK", Selector::new(K_ANY_PSK, 0, 0, 0)),
+    ("SRP", Selector::new(K_SRP, 0, 0, 0)),
+    ("3DES", Selector::new(0, 0, E_3DES, 0)),
+    ("RC4", Selector::new(0, 0, E_RC4, 0)),
+    ("IDEA", Selector::new(0, 0, E_IDEA, 0)),
+    ("SEED", Selector::new(0, 0, E_SEED, 0)),
+    ("eNULL", Selector::new(0, 0, E_NULL, 0)),
+    ("GOST89", Selector::new(0, 0, E_GOST89 | E_GOST89_12, 0)),
+    (
+        "AES128",
+        Selector::new(0, 0, E_AES128 | E_AES128GCM | E_AES128CCM | E_AES128CCM8, 0),
+    ),
+    (
+        "AES256",
+        Selector::new(0, 0, E_AES256 | E_AES256GCM | E_AES256CCM | E_AES256CCM8, 0),
+    ),
+    ("AES", Selector::new(0, 0, E_AES, 0)),
+    ("AESGCM", Selector::new(0, 0, E_AESGCM, 0)),
+    ("AESCCM", Selector::new(0, 0, E_AESCCM, 0)),
+    (
+        "AESCCM8",
+        Selector::new(0, 0, E_AES128CCM8 | E_AES256CCM8, 0),
+    ),
+    ("CAMELLIA128", Selector::new(0, 0, E_CAMELLIA128, 0)),
+    ("CAMELLIA256", Selector::new(0, 0, E_CAMELLIA256, 0)),
+    (
+        "CAMELLIA",
+        Selector::new(0, 0, E_CAMELLIA128 | E_CAMELLIA256, 0),
+    ),
+    ("CHACHA20", Selector::new(0, 0, E_CHACHA20, 0)),
+    ("ARIA", Selector::new(0, 0, E_ARIA, 0)),
+    ("ARIAGCM", Selector::new(0, 0, E_ARIA, 0)),
+    ("ARIA128", Selector::new(0, 0, E_ARIA128GCM, 0)),
+    ("ARIA256", Selector::new(0, 0, E_ARIA256GCM, 0)),
+    ("CBC", Selector::new(0, 0, E_CBC, 0)),
+    ("MD5", Selector::new(0, 0, 0, M_MD5)),
+    ("SHA1", Selector::new(0, 0, 0, M_SHA1)),
+    ("SHA", Selector::new(0, 0, 0, M_SHA1)),
+    ("GOST94", Selector::new(0, 0, 0, M_GOST94)),
+    ("GOST89MAC", Selector::new(0, 0, 0, M_GOST89MAC)),
+    ("SHA256", Selector::new(0, 0, 0, M_SHA256)),
+    ("SHA384", Selector::new(0, 0, 0, M_SHA384)),
+    ("SSLv3", Selector::min_tls(ProtocolVersion::Ssl3)),
+    ("TLSv1", Selector::min_tls(ProtocolVersion::Tls10)),
+    ("TLSv1.0", Selector::min_tls(ProtocolVersion::Tls10)),
+    ("TLSv1.2", Selector::min_tls(ProtocolVersion::Tls12)),
+    ("LOW", Selector::strength(S_LOW)),
+    ("MEDIUM", Selector::strength(S_MEDIUM)),
+    ("HIGH", Selector::strength(S_HIGH)),
+    ("FIPS", Selector::strength(S_FIPS)),
+];
+
+/// OpenSSL 3's built-in `DEFAULT` list.
+const OPENSSL_DEFAULT_CIPHERS: &str = "ALL:!COMPLEMENTOFDEFAULT:!eNULL";
+
+/// A suite in the working list, with OpenSSL's view of its algorithms.
+#[derive(Clone, Copy)]
+struct Entry {
+    cipher: TLSCipherSuite,
+    active: bool,
+    mkey: u32,
+    auth: u32,
+    enc: u32,
+    mac: u32,
+    min_tls: ProtocolVersion,
+    strength: u8,
+    not_default: bool,
+    bits: u16,
+}
+
+impl Entry {
+    fn new(cipher: TLSCipherSuite) -> Entry {
+        let c = cipher.components();
+        let mkey = match c.key_exchange {
+            KeyExchange::Rsa => K_RSA,
+            KeyExchange::Dhe => K_DHE,
+            KeyExchange::Ecdhe => K_ECDHE,
+            KeyExchange::Psk => K_PSK,
+            KeyExchange::RsaPsk => K_RSAPSK,
+            KeyExchange::DhePsk => K_DHEPSK,
+            KeyExchange::EcdhePsk => K_ECDHEPSK,
+            KeyExchange::Srp => K_SRP,
+            KeyExchange::Gost if c.mac == Mac::KuznyechikOmac || c.mac == Mac::MagmaOmac => {
+                K_GOST18
+            }
+            KeyExchange::Gost => K_GOST,
+            _ => 0,
+        };
+        let auth = match c.authentication {
+            Authentication::Rsa => A_RSA,
+            Authentication::Dss => A_DSS,
+            Authentication::Null => A_NULL,
+            Authentication::Ecdsa => A_ECDSA,
+            Authentication::Psk => A_PSK,
+            Authentication::Srp => A_SRP,
+            Authentication::Gost01 => A_GOST01,
+            Authentication::Gost12 => A_GOST12,
+            _ => 0,
+        };
+        let big = c.encryption.bits > 128;
+        let enc = match (c.encryption.cipher, c.encryption.mode) {
+            (Cipher::Null, _) => E_NULL,
+            (Cipher::Rc4, _) => E_RC4,
+            (Cipher::TripleDes, _) => E_3DES,
+            (Cipher::Idea, _) => E_IDEA,
+            (Cipher::Seed, _) => E_SEED,
+            (Cipher::Aes, CipherMode::Gcm) if big => E_AES256GCM,
+            (Cipher::Aes, CipherMode::Gcm) => E_AES128GCM,
+            (Cipher::Aes, CipherMode::Ccm) if big => E_AES256CCM,
+            (Cipher::Aes, CipherMode::Ccm) => E_AES128CCM,
+            (Cipher::Aes, CipherMode::Ccm8) if big => E_AES256CCM8,
+            (Cipher::Aes, CipherMode::Ccm8) => E_AES128CCM8,
+            (Cipher::Aes, _) if big => E_AES256,
+            (Cipher::Aes, _) => E_AES128,
+            (Cipher::Camellia, _) if big => E_CAMELLIA256,
+            (Cipher::Camellia, _) => E_CAMELLIA128,
+            (Cipher::Aria, _) if big => E_ARIA256GCM,
+            (Cipher::Aria, _) => E_ARIA128GCM,
+            (Cipher::ChaCha20, _) => E_CHACHA20,
+            (Cipher::Gost28147, _) if c.authentication == Authentication::Gost12 => E_GOST89_12,
+            (Cipher::Gost28147, _) => E_GOST89,
+            (Cipher::Kuznyechik, _) => E_KUZNYECHIK,
+            (Cipher::Magma, _) => E_MAGMA,
+            _ => 0,
+        };
+        let mac = match c.mac {
+            Mac::Md5 => M_MD5,
+            Mac::Sha1 => M_SHA1,
+            Mac::Sha256 => M_SHA256,
+            Mac::Sha384 => M_SHA384,
+            Mac::Aead => M_AEAD,
+            Mac::Gost89 => M_GOST89MAC,
+            Mac::Gost94 => M_GOST94,
+            Mac::KuznyechikOmac | Mac::MagmaOmac => M_OMAC,
+            Mac::Null => 0,
+        };
+        // CCM with an 8-byte tag counts as 64 bits and MEDIUM, whatever the
+        // key size.
+        let ccm8 = c.encryption.mode == CipherMode::Ccm8;
+        let mut strength = match c.encryption.cipher {
+            Cipher::Null => 0,
+            Cipher::Rc4 | Cipher::TripleDes | Cipher::Idea | Cipher::Seed => S_MEDIUM,
+            _ if ccm8 => S_MEDIUM,
+            _ => S_HIGH,
+        };
+        let fips_mode = matches!(c.encryption.mode, CipherMode::Cbc | CipherMode::Gcm);
+        let fips_kx = !matches!(c.key_exchange, KeyExchange::Srp | KeyExchange::Gost);
+        if matches!(c.encryption.cipher, Cipher::Aes | Cipher::TripleDes) && fips_mode && fips_kx {
+            strength |= S_FIPS;
+        }
+        // The NULL ciphers stay out of DEFAULT through `!eNULL` instead.
+        let not_default = c.encryption.cipher != Cipher::Null
+            && (matches!(c.authentication, Authentication::Null | Authentication::Dss)
+                || matches!(
+                    c.encryption.cipher,
+                    Cipher::Rc4
+                        | Cipher::TripleDes
+                        | Cipher::Idea
+                        | Cipher::Seed
+                        | Cipher::Camellia
+                        | Cipher::Aria
+                )
+                || matches!(c.encryption.mode, CipherMode::Ccm | CipherMode::Ccm8));
+
+        Entry {
+            cipher,
+            active: false,
+            mkey,
+            auth,
+            enc,
+            mac,
+            min_tls: c.min_version,
+            strength,
+            not_default,
+            bits: if ccm8 {
+                64
+            } else {
+                c.encryption.strength_bits()
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Rule {
+    /// No prefix: append matching suites that are not yet in the list.
+    Add,
+    /// `+`: move matching suites in the list to its end.
+    Order,
+    /// `-`: take matching suites out of the list; they can be added again.
+    Delete,
+    /// `!`: take matching suites out of the list for good.
+    Kill,
+    /// Move matching suites in the list to its front.
+    Bump,
+}
+
+/// The working list: every suite OpenSSL knows, with the active ones making
+/// up the result. Moving entries around rather than only flipping `active`
+/// is what gives re-added suites their position.
+struct CipherList(Vec<Entry>);
+
+impl CipherList {
+    fn apply(&mut self, rule: Rule, selector: &Selector) {
+        let (hits, rest): (Vec<Entry>, Vec<Entry>) = self.0.iter().partition(|e| {
+            selector.matches(e)
+                && match rule {
+                    Rule::Add => !e.active,
+                    Rule::Kill => true,
+                    _ => e.active,
+                }
+        });
+        let hits = hits.into_iter().map(|mut e| {
+            e.active = matches!(rule, Rule::Add | Rule::Order | Rule::Bump);
+            e
+        });
+        self.0 = match rule {
+            Rule::Add | Rule::Order => rest.into_iter().chain(hits).collect(),
+            Rule::Delete | Rule::Bump => hits.chain(rest).collect(),
+            Rule::Kill => rest,
+        };
+    }
+
+    /// Stable-sorts the active suites by strength, strongest first, and
+    /// moves them to the end of the list as OpenSSL's `@STRENGTH` does.
+    fn sort_by_strength(&mut self) {
+        let mut bits: Vec<u16> = self.0.iter().filter(|e| e.active).map(|e| e.bits).collect();
+        bits.sort_unstable_by(|a, b| b.cmp(a));
+        bits.dedup();
+        for bits in bits {
+            let (hits, rest): (Vec<Entry>, Vec<Entry>) =
+                self.0.iter().partition(|e| e.active && e.bits == bits);
+            self.0 = rest.into_iter().chain(hits).collect();
+        }
+    }
+
+    fn active(&self) -> Vec<TLSCipherSuite> {
+        self.0
+            .iter()
+            .filter(|e| e.active)
+            .map(|e| e.cipher)
+            .collect()
+    }
+
+    /// Builds the list in OpenSSL's default preference order, with nothing
+    /// active yet.
+    fn openssl() -> CipherList {
+        // OpenSSL starts from its internal table, which roughly runs from low
+        // to high code points, and walks it backwards.
+        let mut entries: Vec<Entry> = TLSCipherSuite::iter()
+            .filter(|c| c.supported_in(OpenSslVersion::V3_0) && !c.is_tls13() && !c.is_signaling())
+            .map(Entry::new)
+            .collect();
+        entries.sort_by_key(|e| std::cmp::Reverse(e.cipher.as_u16()));
+        let mut list = CipherList(entries);
+
+        let all = Selector::default();
+        let ecdhe = Selector::new(K_ECDHE, 0, 0, 0);
+        let dhe = Selector::new(K_DHE | K_ECDHE, 0, 0, 0);
+        let aead = Selector::new(0, 0, 0, M_AEAD);
+        list.apply(Rule::Add, &Selector::new(K_ECDHE, A_ECDSA, 0, 0));
+        list.apply(Rule::Add, &ecdhe);
+        list.apply(Rule::Delete, &ecdhe);
+        list.apply(Rule::Add, &Selector::new(0, 0, E_AESGCM, 0));
+        list.apply(Rule::Add, &Selector::new(0, 0, E_CHACHA20, 0));
+        list.apply(Rule::Add, &Selector::new(0, 0, E_AES & !E_AESGCM, 0));
+        list.apply(Rule::Add, &all);
+        list.apply(Rule::Order, &Selector::new(0, 0, 0, M_MD5));
+        list.apply(Rule::Order, &Selector::new(0, A_NULL, 0, 0));
+        list.apply(Rule::Order, &Selector::new(K_RSA, 0, 0, 0));
+        list.apply(Rule::Order, &Selector::new(K_PSK, 0, 0, 0));
+        list.apply(Rule::Order, &Selector::new(0, 0, E_RC4, 0));
+        list.sort_by_strength();
+        list.apply(Rule::Bump, &Selector::min_tls(ProtocolVersion::Tls12));
+        list.apply(Rule::Bump, &aead);
+        list.apply(Rule::Bump, &dhe);
+        list.apply(Rule::Bump, &dhe.and(aead).unwrap());
+        list.apply(Rule::Delete, &all);
+        list
+    }
+
+    /// Applies the elements of a cipher string.
+    fn process(&mut self, rules: &str) -> Result<(), CipherStringError> {
+        for element in rules.split([':', ' ', ',', ';']) {
+            // An `@` ends an element and starts a command, as in
+            // `DEFAULT@SECLEVEL=2`.
+            let mut parts = element.split('@');
+            let element = parts.next().unwrap_or_default();
+            for command in parts {
+                match command {
+                    "STRENGTH" => self.sort_by_strength(),
+                    _ if command.starts_with("SECLEVEL=") => {}
+                    _ => return Err(CipherStringError::UnknownCommand(format!("@{}", command))),
+                }
+            }
+            if element.is_empty() {
+                continue;
+            }
+
+            let (rule, words) = match element.as_bytes()[0] {
+                b'!' => (Rule::Kill, &element[1..]),
+                b'-' => (Rule::Delete, &element[1..]),
+                b'+' => (Rule::Order, &element[1..]),
+                _ => (Rule::Add, element),
+            };
+            // Like OpenSSL, silently skip elements that name nothing known.
+            if let Some(selector) = words
+                .split('+')
+                .map(|word| self.lookup(word))
+                .try_fold(Selector::default(), |acc, s| acc.and(s?))
+            {
+                self.apply(rule, &selector);
+            }
+        }
+        Ok(())
+    }
+
+    fn lookup(&self, word: &str) -> Option<Selector> {
+        if let Some((_, selector)) = ALIASES.iter().find(|(name, _)| *name == word) {
+            return Some(*selector);
+        }
+        self.0
+            .iter()
+            .find(|e| {
+                e.cipher
+                    .as_openssl_name_for(OpenSslVersion::V3_0)
+                    .as_deref()
+                    == Some(word)
+            })
+            .map(|e| Selector {
+                cipher: Some(e.cipher),
+                ..Selector::default()
+            })
+    }
+}
+
+/// Error returned by `expand_openssl_cipher_string`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum CipherStringError {
+    /// An `@` command other than `@STRENGTH` and `@SECLEVEL=n`.
+    UnknownCommand(String),
+    /// The string selects no suites at all.
+    NoMatch,
+}
+
+impl fmt::Display for CipherStringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownCommand(command) => write!(f, "unknown command {:?}", command),
+            Self::NoMatch => write!(f, "no cipher match"),
+        }
+    }
+}
+
+impl std::error::Error for CipherStringError {}
+
+/// Expands an OpenSSL cipher string, as given to `openssl ciphers` or
+/// `SSL_CTX_set_cipher_list`, into the suites OpenSSL 3 would offer, in
+/// order.
+///
+/// The aliases, the `!`, `-` and `+` prefixes, `A+B` combinations and
+/// `@STRENGTH` behave as in OpenSSL. `DEFAULT` is only recognized at the
+/// start of the string. TLS 1.3 suites are configured separately in OpenSSL
+/// and are never part of the result.
+///
+/// The expansion assumes every suite OpenSSL 3 implements is available,
+/// including those that need the legacy provider or a GOST engine, and does
+/// not apply security levels.
+///
+/// ```
+/// use openssl_to_rfc::{expand_openssl_cipher_string, TLSCipherSuite};
+///
+/// assert_eq!(
+///     expand_openssl_cipher_string("ECDHE+AESGCM:!ECDSA").unwrap(),
+///     vec![
+///         TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+///         TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+///     ]
+/// );
+/// ```
+pub fn expand_openssl_cipher_string(
+    cipher_string: &str,
+) -> Result<Vec<TLSCipherSuite>, CipherStringError> {
+    let mut list = CipherList::openssl();
+    let rules = match cipher_string.strip_prefix("DEFAULT") {
+        Some(rest) => {
+            list.process(OPENSSL_DEFAULT_CIPHERS)?;
+            rest.strip_prefix(':').unwrap_or(rest)
+        }
+        None => cipher_string,
+    };
+    list.process(rules)?;
+
+    let ciphers = list.active();
+    if ciphers.is_empty() {
+        return Err(CipherStringError::NoMatch);
+    }
+    Ok(ciphers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(cipher_string: &str) -> Vec<String> {
+        expand_openssl_cipher_string(cipher_string)
+            .unwrap()
+            .iter()
+            .map(|c| c.as_openssl_name())
+            .collect()
+    }
+
+    // Expected lists are the output of `openssl ciphers` from OpenSSL 3.5.
+    #[test]
+    fn openssl_order() {
+        assert_eq!(
+            names("DEFAULT:!PSK:!SRP:!kRSA")[..9],
+            [
+                "ECDHE-ECDSA-AES256-GCM-SHA384",
+                "ECDHE-RSA-AES256-GCM-SHA384",
+                "DHE-RSA-AES256-GCM-SHA384",
+                "ECDHE-ECDSA-CHACHA20-POLY1305",
+                "ECDHE-RSA-CHACHA20-POLY1305",
+                "DHE-RSA-CHACHA20-POLY1305",
+                "ECDHE-ECDSA-AES128-GCM-SHA256",
+                "ECDHE-RSA-AES128-GCM-SHA256",
+                "DHE-RSA-AES128-GCM-SHA256",
+            ]
+        );
+        assert_eq!(
+            names("kRSA:!3DES:!RC4:!IDEA:!SEED:+AESCCM"),
+            [
+                "AES256-GCM-SHA384",
+                "ARIA256-GCM-SHA384",
+                "AES128-GCM-SHA256",
+                "ARIA128-GCM-SHA256",
+                "AES256-SHA256",
+                "CAMELLIA256-SHA256",
+                "AES128-SHA256",
+                "CAMELLIA128-SHA256",
+                "NULL-SHA256",
+                "AES256-SHA",
+                "CAMELLIA256-SHA",
+                "AES128-SHA",
+                "CAMELLIA128-SHA",
+                "NULL-SHA",
+                "NULL-MD5",
+                "AES256-CCM",
+                "AES128-CCM",
+                "AES256-CCM8",
+                "AES128-CCM8",
+            ]
+            .map(str::to_owned)
+        );
+    }
+
+    #[test]
+    fn rule_prefixes() {
+        assert_eq!(
+            names("ECDHE-RSA-AES128-SHA:AES256-SHA:-ECDHE-RSA-AES128-SHA:ECDHE-RSA-AES128-SHA"),
+            ["AES256-SHA", "ECDHE-RSA-AES128-SHA"]
+        );
+        assert_eq!(
+            names("ECDHE+AESGCM:!ECDHE-RSA-AES128-GCM-SHA256:ECDHE-RSA-AES128-GCM-SHA256"),
+            [
+                "ECDHE-ECDSA-AES256-GCM-SHA384",
+                "ECDHE-RSA-AES256-GCM-SHA384",
+                "ECDHE-ECDSA-AES128-GCM-SHA256",
+            ]
+        );
+        assert_eq!(
+            names("AES128-SHA:AES256-SHA:@STRENGTH"),
+            ["AES256-SHA", "AES128-SHA"]
+        );
+        assert_eq!(
+            expand_openssl_cipher_string("AESGCM+SHA256:NOTACIPHER"),
+            Err(CipherStringError::NoMatch)
+        );
+        assert_eq!(
+            expand_openssl_cipher_string("HIGH:@NOPE"),
+            Err(CipherStringError::UnknownCommand("@NOPE".to_owned()))
+        );
+    }
+}
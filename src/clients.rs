@@ -0,0 +1,648 @@
+use std::fmt;
+
+use crate::{HandshakeFailure, ProtocolVersion, ServerConfig, TLSCipherSuite};
+
+/// The cipher suites and protocol versions a client offers in its default
+/// configuration.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ClientProfile {
+    pub name: &'static str,
+    pub min_version: ProtocolVersion,
+    pub max_version: ProtocolVersion,
+    /// The offered suites in the client's order, without GREASE and
+    /// signaling values.
+    pub ciphers: &'static [TLSCipherSuite],
+}
+
+const FIREFOX_68: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_3DES_EDE_CBC_SHA,
+];
+
+const FIREFOX_115: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA,
+];
+
+const CHROME_70: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_3DES_EDE_CBC_SHA,
+];
+
+const CHROME_131: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA,
+];
+
+const SAFARI_12: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_3DES_EDE_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_3DES_EDE_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_3DES_EDE_CBC_SHA,
+];
+
+const SAFARI_17: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_3DES_EDE_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_3DES_EDE_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_3DES_EDE_CBC_SHA,
+];
+
+const ANDROID_4_4: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_RC4_128_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_RC4_128_SHA,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_DHE_DSS_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_RC4_128_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_RC4_128_MD5,
+];
+
+const ANDROID_7: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA,
+];
+
+const ANDROID_14: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA,
+];
+
+const JAVA_11: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDH_ECDSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDH_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_DHE_DSS_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDH_ECDSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDH_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_DHE_DSS_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDH_ECDSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_ECDH_RSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_CBC_SHA256,
+    TLSCipherSuite::TLS_DHE_DSS_WITH_AES_256_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDH_ECDSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDH_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_DHE_DSS_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDH_ECDSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDH_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_DHE_DSS_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDH_ECDSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDH_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_DHE_DSS_WITH_AES_128_CBC_SHA,
+];
+
+const JAVA_17: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_DHE_DSS_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_DHE_DSS_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_CBC_SHA256,
+    TLSCipherSuite::TLS_DHE_DSS_WITH_AES_256_CBC_SHA256,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_DHE_DSS_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDH_ECDSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDH_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDH_ECDSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDH_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDH_ECDSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_ECDH_RSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_ECDH_ECDSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDH_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_DHE_DSS_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_DHE_DSS_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDH_ECDSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDH_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDH_ECDSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDH_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA,
+];
+
+const OPENSSL_3: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA,
+];
+
+const IE_11_WIN7: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_DHE_DSS_WITH_AES_256_CBC_SHA256,
+    TLSCipherSuite::TLS_DHE_DSS_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_DHE_DSS_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_DHE_DSS_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_3DES_EDE_CBC_SHA,
+    TLSCipherSuite::TLS_DHE_DSS_WITH_3DES_EDE_CBC_SHA,
+];
+
+const IE_11_WIN10: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_3DES_EDE_CBC_SHA,
+];
+
+const WINDOWS_11: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA,
+];
+
+/// Common clients, from browsers to TLS libraries, as they connect out of
+/// the box. The suite lists follow the ClientHellos the clients send, the
+/// way the SSL Labs handshake simulation records them.
+pub const CLIENT_PROFILES: &[ClientProfile] = &[
+    ClientProfile {
+        name: "Firefox 68",
+        min_version: ProtocolVersion::Tls10,
+        max_version: ProtocolVersion::Tls13,
+        ciphers: FIREFOX_68,
+    },
+    ClientProfile {
+        name: "Firefox 115",
+        min_version: ProtocolVersion::Tls12,
+        max_version: ProtocolVersion::Tls13,
+        ciphers: FIREFOX_115,
+    },
+    ClientProfile {
+        name: "Chrome 70",
+        min_version: ProtocolVersion::Tls10,
+        max_version: ProtocolVersion::Tls13,
+        ciphers: CHROME_70,
+    },
+    ClientProfile {
+        name: "Chrome 131",
+        min_version: ProtocolVersion::Tls12,
+        max_version: ProtocolVersion::Tls13,
+        ciphers: CHROME_131,
+    },
+    ClientProfile {
+        name: "Safari 12 / macOS 10.14",
+        min_version: ProtocolVersion::Tls10,
+        max_version: ProtocolVersion::Tls12,
+        ciphers: SAFARI_12,
+    },
+    ClientProfile {
+        name: "Safari 17 / macOS 14",
+        min_version: ProtocolVersion::Tls10,
+        max_version: ProtocolVersion::Tls13,
+        ciphers: SAFARI_17,
+    },
+    ClientProfile {
+        name: "Android 4.4.2",
+        min_version: ProtocolVersion::Tls10,
+        max_version: ProtocolVersion::Tls12,
+        ciphers: ANDROID_4_4,
+    },
+    ClientProfile {
+        name: "Android 7.0",
+        min_version: ProtocolVersion::Tls10,
+        max_version: ProtocolVersion::Tls12,
+        ciphers: ANDROID_7,
+    },
+    ClientProfile {
+        name: "Android 14",
+        min_version: ProtocolVersion::Tls10,
+        max_version: ProtocolVersion::Tls13,
+        ciphers: ANDROID_14,
+    },
+    ClientProfile {
+        name: "Java 11",
+        min_version: ProtocolVersion::Tls10,
+        max_version: ProtocolVersion::Tls13,
+        ciphers: JAVA_11,
+    },
+    ClientProfile {
+        name: "Java 17",
+        min_version: ProtocolVersion::Tls12,
+        max_version: ProtocolVersion::Tls13,
+        ciphers: JAVA_17,
+    },
+    ClientProfile {
+        name: "curl 8 / OpenSSL 3",
+        min_version: ProtocolVersion::Tls12,
+        max_version: ProtocolVersion::Tls13,
+        ciphers: OPENSSL_3,
+    },
+    ClientProfile {
+        name: "IE 11 / Windows 7",
+        min_version: ProtocolVersion::Tls10,
+        max_version: ProtocolVersion::Tls12,
+        ciphers: IE_11_WIN7,
+    },
+    ClientProfile {
+        name: "IE 11 / Windows 10",
+        min_version: ProtocolVersion::Tls10,
+        max_version: ProtocolVersion::Tls12,
+        ciphers: IE_11_WIN10,
+    },
+    ClientProfile {
+        name: "Schannel / Windows 11",
+        min_version: ProtocolVersion::Tls12,
+        max_version: ProtocolVersion::Tls13,
+        ciphers: WINDOWS_11,
+    },
+];
+
+/// The outcome of a simulated handshake between a client and a server.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ClientResult {
+    /// The client's `ClientProfile::name`.
+    pub client: &'static str,
+    /// The negotiated version and suite, or why the handshake fails.
+    pub result: Result<(ProtocolVersion, TLSCipherSuite), HandshakeFailure>,
+}
+
+impl fmt::Display for ClientResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.result {
+            Ok((version, cipher)) => write!(f, "{:<24} {:<8} {}", self.client, version, cipher),
+            Err(e) => write!(f, "{:<24} fails: {}", self.client, e),
+        }
+    }
+}
+
+impl ClientProfile {
+    /// Simulates the client's handshake with a server that enables the
+    /// given protocol versions.
+    ///
+    /// The client gets the highest version both sides enable and then the
+    /// suite `ServerConfig::negotiate` selects. Like current clients, it
+    /// does not retry with a lower version if that fails.
+    pub fn connect(&self, server: &ServerConfig, versions: &[ProtocolVersion]) -> ClientResult {
+        let version = versions
+            .iter()
+            .copied()
+            .filter(|v| (self.min_version..=self.max_version).contains(v))
+            .max();
+        let result = match version {
+            Some(version) => server
+                .negotiate(self.ciphers, version)
+                .map(|cipher| (version, cipher)),
+            None => Err(HandshakeFailure::NoSharedVersion),
+        };
+        ClientResult {
+            client: self.name,
+            result,
+        }
+    }
+}
+
+impl ServerConfig {
+    /// Reports which of the `CLIENT_PROFILES` can connect to the server and
+    /// what they negotiate, given the protocol versions it enables.
+    ///
+    /// ```
+    /// use openssl_to_rfc::components::Authentication;
+    /// use openssl_to_rfc::{expand_openssl_cipher_string, ProtocolVersion, ServerConfig};
+    ///
+    /// let server = ServerConfig {
+    ///     ciphers: expand_openssl_cipher_string("ECDHE+AESGCM:!ECDSA").unwrap(),
+    ///     server_preference: true,
+    ///     certificate: Authentication::Rsa,
+    /// };
+    /// let results = server.simulate_clients(&[ProtocolVersion::Tls12]);
+    /// let failing: Vec<_> = results
+    ///     .iter()
+    ///     .filter(|r| r.result.is_err())
+    ///     .map(|r| r.client)
+    ///     .collect();
+    /// assert_eq!(failing, vec!["IE 11 / Windows 7"]);
+    /// ```
+    pub fn simulate_clients(&self, versions: &[ProtocolVersion]) -> Vec<ClientResult> {
+        CLIENT_PROFILES
+            .iter()
+            .map(|client| client.connect(self, versions))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::Authentication;
+
+    #[test]
+    fn profiles() {
+        for client in CLIENT_PROFILES {
+            assert!(client.min_version <= client.max_version, "{}", client.name);
+            for cipher in client.ciphers {
+                assert!(!cipher.is_signaling(), "{}", client.name);
+                assert!(!cipher.is_tls13() || client.max_version == ProtocolVersion::Tls13);
+            }
+        }
+    }
+
+    #[test]
+    fn versions_and_certificates() {
+        let server = ServerConfig {
+            ciphers: vec![
+                TLSCipherSuite::TLS_AES_128_GCM_SHA256,
+                TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+                TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA,
+            ],
+            server_preference: false,
+            certificate: Authentication::Ecdsa,
+        };
+        let result = |name, versions: &[ProtocolVersion]| {
+            let client = CLIENT_PROFILES.iter().find(|c| c.name == name).unwrap();
+            client.connect(&server, versions).result
+        };
+
+        assert_eq!(
+            result("Java 17", &[ProtocolVersion::Tls10, ProtocolVersion::Tls11]),
+            Err(HandshakeFailure::NoSharedVersion)
+        );
+        assert_eq!(
+            result("Java 17", &[ProtocolVersion::Tls12, ProtocolVersion::Tls13]),
+            Ok((
+                ProtocolVersion::Tls13,
+                TLSCipherSuite::TLS_AES_128_GCM_SHA256
+            ))
+        );
+        assert_eq!(
+            result(
+                "Android 4.4.2",
+                &[ProtocolVersion::Tls12, ProtocolVersion::Tls13]
+            ),
+            Ok((
+                ProtocolVersion::Tls12,
+                TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256
+            ))
+        );
+        assert_eq!(
+            result("Safari 12 / macOS 10.14", &[ProtocolVersion::Tls10]),
+            Ok((
+                ProtocolVersion::Tls10,
+                TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA
+            ))
+        );
+        assert_eq!(
+            result(
+                "IE 11 / Windows 10",
+                &[ProtocolVersion::Tls12, ProtocolVersion::Tls13]
+            ),
+            Ok((
+                ProtocolVersion::Tls12,
+                TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256
+            ))
+        );
+    }
+}
@@ -0,0 +1,1442 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{ProtocolVersion, TLSCipherSuite};
+
+/// The key exchange algorithm of a cipher suite.
+///
+/// Ephemeral and anonymous (EC)DH both map to `Dhe`/`Ecdhe`; `Dh` and `Ecdh`
+/// are the static variants that take their parameters from the certificate.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum KeyExchange {
+    Null,
+    Rsa,
+    Dh,
+    Dhe,
+    Ecdh,
+    Ecdhe,
+    Psk,
+    RsaPsk,
+    DhePsk,
+    EcdhePsk,
+    Srp,
+    Krb5,
+    Gost,
+    EccPwd,
+    /// TLS 1.3 suites do not fix the key exchange.
+    Any,
+}
+
+/// The server authentication algorithm of a cipher suite.
+///
+/// For static (EC)DH suites this is the algorithm that signed the
+/// certificate, e.g. `Rsa` for `TLS_ECDH_RSA_*`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Authentication {
+    Null,
+    Rsa,
+    Dss,
+    Ecdsa,
+    Psk,
+    Srp,
+    Krb5,
+    Gost94,
+    Gost01,
+    Gost12,
+    EccPwd,
+    /// TLS 1.3 suites do not fix the authentication algorithm.
+    Any,
+}
+
+/// The bulk encryption algorithm of a cipher suite.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Cipher {
+    Null,
+    Rc4,
+    Rc2,
+    Des,
+    TripleDes,
+    Idea,
+    Seed,
+    Aes,
+    Camellia,
+    Aria,
+    ChaCha20,
+    Gost28147,
+    Kuznyechik,
+    Magma,
+    Sm4,
+    Aegis256,
+    Aegis128L,
+}
+
+/// The mode the bulk encryption algorithm is used in.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CipherMode {
+    None,
+    Stream,
+    Cbc,
+    Gcm,
+    Ccm,
+    Ccm8,
+    Poly1305,
+    Cnt,
+    Ctr,
+    Mgm,
+    /// A cipher that is an AEAD by construction, such as AEGIS.
+    Aead,
+}
+
+/// The bulk encryption of a cipher suite: algorithm, mode and key size.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Encryption {
+    pub cipher: Cipher,
+    pub mode: CipherMode,
+    /// The key size in bits, as OpenSSL reports it (168 for 3DES).
+    pub bits: u16,
+}
+
+impl Encryption {
+    pub const fn is_aead(&self) -> bool {
+        matches!(
+            self.mode,
+            CipherMode::Gcm
+                | CipherMode::Ccm
+                | CipherMode::Ccm8
+                | CipherMode::Poly1305
+                | CipherMode::Mgm
+                | CipherMode::Aead
+        )
+    }
+
+    /// The effective security strength in bits. This differs from `bits`
+    /// only for 3DES, which offers 112 bits against meet-in-the-middle.
+    pub fn strength_bits(&self) -> u16 {
+        match self.cipher {
+            Cipher::TripleDes => 112,
+            _ => self.bits,
+        }
+    }
+}
+
+/// The message authentication of a cipher suite.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Mac {
+    Null,
+    Md5,
+    Sha1,
+    Sha256,
+    Sha384,
+    /// Integrity is provided by the AEAD cipher.
+    Aead,
+    Gost89,
+    Gost94,
+    KuznyechikOmac,
+    MagmaOmac,
+}
+
+/// The algorithms that make up a cipher suite.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Components {
+    pub key_exchange: KeyExchange,
+    pub authentication: Authentication,
+    pub encryption: Encryption,
+    pub mac: Mac,
+    /// The lowest protocol version the suite can be negotiated in.
+    pub min_version: ProtocolVersion,
+    pub export: bool,
+}
+
+impl Components {
+    const NULL: Components = Components {
+        key_exchange: KeyExchange::Null,
+        authentication: Authentication::Null,
+        encryption: Encryption {
+            cipher: Cipher::Null,
+            mode: CipherMode::None,
+            bits: 0,
+        },
+        mac: Mac::Null,
+        min_version: ProtocolVersion::Ssl3,
+        export: false,
+    };
+
+    const fn export(self) -> Components {
+        Components {
+            export: true,
+            ..self
+        }
+    }
+
+    /// Returns true if the key exchange provides forward secrecy.
+    pub fn forward_secrecy(&self) -> bool {
+        matches!(
+            self.key_exchange,
+            KeyExchange::Dhe
+                | KeyExchange::Ecdhe
+                | KeyExchange::DhePsk
+                | KeyExchange::EcdhePsk
+                | KeyExchange::EccPwd
+                | KeyExchange::Any
+        )
+    }
+}
+
+impl TLSCipherSuite {
+    /// Returns true for the signaling cipher suite values, which only carry
+    /// information in a ClientHello and are never negotiated.
+    pub fn is_signaling(&self) -> bool {
+        matches!(
+            self,
+            Self::TLS_EMPTY_RENEGOTIATION_INFO_SCSV | Self::TLS_FALLBACK_SCSV
+        )
+    }
+
+    /// Returns true for suites that can only be used with TLS 1.3.
+    pub fn is_tls13(&self) -> bool {
+        self.components().min_version == ProtocolVersion::Tls13
+    }
+
+    /// Returns whether the suite may be negotiated with the given version.
+    ///
+    /// TLS 1.3 suites are only valid with TLS 1.3 and vice versa, and older
+    /// suites must not need a newer version, e.g. an AES-GCM suite with
+    /// TLS 1.1. Signaling suites are never negotiated.
+    pub fn usable_in(&self, version: ProtocolVersion) -> bool {
+        if version == ProtocolVersion::Tls13 {
+            self.is_tls13()
+        } else {
+            !self.is_tls13() && !self.is_signaling() && self.components().min_version <= version
+        }
+    }
+
+    /// Decomposes the suite into its key exchange, authentication, encryption
+    /// and MAC algorithms.
+    ///
+    /// Signaling suites and `TLS_NULL_WITH_NULL_NULL` have all components set
+    /// to `Null`.
+    pub fn components(&self) -> Components {
+        use Authentication as Au;
+        use Cipher as C;
+        use CipherMode as M;
+        use KeyExchange as Kx;
+
+        match self {
+            Self::TLS_NULL_WITH_NULL_NULL
+            | Self::TLS_EMPTY_RENEGOTIATION_INFO_SCSV
+            | Self::TLS_FALLBACK_SCSV => Components::NULL,
+            Self::TLS_RSA_WITH_NULL_MD5 => suite(Kx::Rsa, Au::Rsa, C::Null, M::None, 0, Mac::Md5),
+            Self::TLS_RSA_WITH_NULL_SHA => suite(Kx::Rsa, Au::Rsa, C::Null, M::None, 0, Mac::Sha1),
+            Self::TLS_RSA_EXPORT_WITH_RC4_40_MD5 => {
+                suite(Kx::Rsa, Au::Rsa, C::Rc4, M::Stream, 40, Mac::Md5).export()
+            }
+            Self::TLS_RSA_WITH_RC4_128_MD5 => {
+                suite(Kx::Rsa, Au::Rsa, C::Rc4, M::Stream, 128, Mac::Md5)
+            }
+            Self::TLS_RSA_WITH_RC4_128_SHA => {
+                suite(Kx::Rsa, Au::Rsa, C::Rc4, M::Stream, 128, Mac::Sha1)
+            }
+            Self::TLS_RSA_EXPORT_WITH_RC2_CBC_40_MD5 => {
+                suite(Kx::Rsa, Au::Rsa, C::Rc2, M::Cbc, 40, Mac::Md5).export()
+            }
+            Self::TLS_RSA_WITH_IDEA_CBC_SHA => {
+                suite(Kx::Rsa, Au::Rsa, C::Idea, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_RSA_EXPORT_WITH_DES40_CBC_SHA => {
+                suite(Kx::Rsa, Au::Rsa, C::Des, M::Cbc, 40, Mac::Sha1).export()
+            }
+            Self::TLS_RSA_WITH_DES_CBC_SHA => {
+                suite(Kx::Rsa, Au::Rsa, C::Des, M::Cbc, 56, Mac::Sha1)
+            }
+            Self::TLS_RSA_WITH_3DES_EDE_CBC_SHA => {
+                suite(Kx::Rsa, Au::Rsa, C::TripleDes, M::Cbc, 168, Mac::Sha1)
+            }
+            Self::TLS_DH_DSS_EXPORT_WITH_DES40_CBC_SHA => {
+                suite(Kx::Dh, Au::Dss, C::Des, M::Cbc, 40, Mac::Sha1).export()
+            }
+            Self::TLS_DH_DSS_WITH_DES_CBC_SHA => {
+                suite(Kx::Dh, Au::Dss, C::Des, M::Cbc, 56, Mac::Sha1)
+            }
+            Self::TLS_DH_DSS_WITH_3DES_EDE_CBC_SHA => {
+                suite(Kx::Dh, Au::Dss, C::TripleDes, M::Cbc, 168, Mac::Sha1)
+            }
+            Self::TLS_DH_RSA_EXPORT_WITH_DES40_CBC_SHA => {
+                suite(Kx::Dh, Au::Rsa, C::Des, M::Cbc, 40, Mac::Sha1).export()
+            }
+            Self::TLS_DH_RSA_WITH_DES_CBC_SHA => {
+                suite(Kx::Dh, Au::Rsa, C::Des, M::Cbc, 56, Mac::Sha1)
+            }
+            Self::TLS_DH_RSA_WITH_3DES_EDE_CBC_SHA => {
+                suite(Kx::Dh, Au::Rsa, C::TripleDes, M::Cbc, 168, Mac::Sha1)
+            }
+            Self::TLS_DHE_DSS_EXPORT_WITH_DES40_CBC_SHA => {
+                suite(Kx::Dhe, Au::Dss, C::Des, M::Cbc, 40, Mac::Sha1).export()
+            }
+            Self::TLS_DHE_DSS_WITH_DES_CBC_SHA => {
+                suite(Kx::Dhe, Au::Dss, C::Des, M::Cbc, 56, Mac::Sha1)
+            }
+            Self::TLS_DHE_DSS_WITH_3DES_EDE_CBC_SHA => {
+                suite(Kx::Dhe, Au::Dss, C::TripleDes, M::Cbc, 168, Mac::Sha1)
+            }
+            Self::TLS_DHE_RSA_EXPORT_WITH_DES40_CBC_SHA => {
+                suite(Kx::Dhe, Au::Rsa, C::Des, M::Cbc, 40, Mac::Sha1).export()
+            }
+            Self::TLS_DHE_RSA_WITH_DES_CBC_SHA => {
+                suite(Kx::Dhe, Au::Rsa, C::Des, M::Cbc, 56, Mac::Sha1)
+            }
+            Self::TLS_DHE_RSA_WITH_3DES_EDE_CBC_SHA => {
+                suite(Kx::Dhe, Au::Rsa, C::TripleDes, M::Cbc, 168, Mac::Sha1)
+            }
+            Self::TLS_DH_anon_EXPORT_WITH_RC4_40_MD5 => {
+                suite(Kx::Dhe, Au::Null, C::Rc4, M::Stream, 40, Mac::Md5).export()
+            }
+            Self::TLS_DH_anon_WITH_RC4_128_MD5 => {
+                suite(Kx::Dhe, Au::Null, C::Rc4, M::Stream, 128, Mac::Md5)
+            }
+            Self::TLS_DH_anon_EXPORT_WITH_DES40_CBC_SHA => {
+                suite(Kx::Dhe, Au::Null, C::Des, M::Cbc, 40, Mac::Sha1).export()
+            }
+            Self::TLS_DH_anon_WITH_DES_CBC_SHA => {
+                suite(Kx::Dhe, Au::Null, C::Des, M::Cbc, 56, Mac::Sha1)
+            }
+            Self::TLS_DH_anon_WITH_3DES_EDE_CBC_SHA => {
+                suite(Kx::Dhe, Au::Null, C::TripleDes, M::Cbc, 168, Mac::Sha1)
+            }
+            Self::TLS_KRB5_WITH_DES_CBC_SHA => {
+                suite(Kx::Krb5, Au::Krb5, C::Des, M::Cbc, 56, Mac::Sha1)
+            }
+            Self::TLS_KRB5_WITH_3DES_EDE_CBC_SHA => {
+                suite(Kx::Krb5, Au::Krb5, C::TripleDes, M::Cbc, 168, Mac::Sha1)
+            }
+            Self::TLS_KRB5_WITH_RC4_128_SHA => {
+                suite(Kx::Krb5, Au::Krb5, C::Rc4, M::Stream, 128, Mac::Sha1)
+            }
+            Self::TLS_KRB5_WITH_IDEA_CBC_SHA => {
+                suite(Kx::Krb5, Au::Krb5, C::Idea, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_KRB5_WITH_DES_CBC_MD5 => {
+                suite(Kx::Krb5, Au::Krb5, C::Des, M::Cbc, 56, Mac::Md5)
+            }
+            Self::TLS_KRB5_WITH_3DES_EDE_CBC_MD5 => {
+                suite(Kx::Krb5, Au::Krb5, C::TripleDes, M::Cbc, 168, Mac::Md5)
+            }
+            Self::TLS_KRB5_WITH_RC4_128_MD5 => {
+                suite(Kx::Krb5, Au::Krb5, C::Rc4, M::Stream, 128, Mac::Md5)
+            }
+            Self::TLS_KRB5_WITH_IDEA_CBC_MD5 => {
+                suite(Kx::Krb5, Au::Krb5, C::Idea, M::Cbc, 128, Mac::Md5)
+            }
+            Self::TLS_KRB5_EXPORT_WITH_DES_CBC_40_SHA => {
+                suite(Kx::Krb5, Au::Krb5, C::Des, M::Cbc, 40, Mac::Sha1).export()
+            }
+            Self::TLS_KRB5_EXPORT_WITH_RC2_CBC_40_SHA => {
+                suite(Kx::Krb5, Au::Krb5, C::Rc2, M::Cbc, 40, Mac::Sha1).export()
+            }
+            Self::TLS_KRB5_EXPORT_WITH_RC4_40_SHA => {
+                suite(Kx::Krb5, Au::Krb5, C::Rc4, M::Stream, 40, Mac::Sha1).export()
+            }
+            Self::TLS_KRB5_EXPORT_WITH_DES_CBC_40_MD5 => {
+                suite(Kx::Krb5, Au::Krb5, C::Des, M::Cbc, 40, Mac::Md5).export()
+            }
+            Self::TLS_KRB5_EXPORT_WITH_RC2_CBC_40_MD5 => {
+                suite(Kx::Krb5, Au::Krb5, C::Rc2, M::Cbc, 40, Mac::Md5).export()
+            }
+            Self::TLS_KRB5_EXPORT_WITH_RC4_40_MD5 => {
+                suite(Kx::Krb5, Au::Krb5, C::Rc4, M::Stream, 40, Mac::Md5).export()
+            }
+            Self::TLS_RSA_WITH_AES_128_CBC_SHA => {
+                suite(Kx::Rsa, Au::Rsa, C::Aes, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_DH_DSS_WITH_AES_128_CBC_SHA => {
+                suite(Kx::Dh, Au::Dss, C::Aes, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_DH_RSA_WITH_AES_128_CBC_SHA => {
+                suite(Kx::Dh, Au::Rsa, C::Aes, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_DHE_DSS_WITH_AES_128_CBC_SHA => {
+                suite(Kx::Dhe, Au::Dss, C::Aes, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_DHE_RSA_WITH_AES_128_CBC_SHA => {
+                suite(Kx::Dhe, Au::Rsa, C::Aes, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_DH_anon_WITH_AES_128_CBC_SHA => {
+                suite(Kx::Dhe, Au::Null, C::Aes, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_RSA_WITH_AES_256_CBC_SHA => {
+                suite(Kx::Rsa, Au::Rsa, C::Aes, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_DH_DSS_WITH_AES_256_CBC_SHA => {
+                suite(Kx::Dh, Au::Dss, C::Aes, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_DH_RSA_WITH_AES_256_CBC_SHA => {
+                suite(Kx::Dh, Au::Rsa, C::Aes, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_DHE_DSS_WITH_AES_256_CBC_SHA => {
+                suite(Kx::Dhe, Au::Dss, C::Aes, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_DHE_RSA_WITH_AES_256_CBC_SHA => {
+                suite(Kx::Dhe, Au::Rsa, C::Aes, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_DH_anon_WITH_AES_256_CBC_SHA => {
+                suite(Kx::Dhe, Au::Null, C::Aes, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_RSA_WITH_NULL_SHA256 => {
+                suite(Kx::Rsa, Au::Rsa, C::Null, M::None, 0, Mac::Sha256)
+            }
+            Self::TLS_RSA_WITH_AES_128_CBC_SHA256 => {
+                suite(Kx::Rsa, Au::Rsa, C::Aes, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_RSA_WITH_AES_256_CBC_SHA256 => {
+                suite(Kx::Rsa, Au::Rsa, C::Aes, M::Cbc, 256, Mac::Sha256)
+            }
+            Self::TLS_DH_DSS_WITH_AES_128_CBC_SHA256 => {
+                suite(Kx::Dh, Au::Dss, C::Aes, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_DH_RSA_WITH_AES_128_CBC_SHA256 => {
+                suite(Kx::Dh, Au::Rsa, C::Aes, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_DHE_DSS_WITH_AES_128_CBC_SHA256 => {
+                suite(Kx::Dhe, Au::Dss, C::Aes, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_RSA_WITH_CAMELLIA_128_CBC_SHA => {
+                suite(Kx::Rsa, Au::Rsa, C::Camellia, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_DH_DSS_WITH_CAMELLIA_128_CBC_SHA => {
+                suite(Kx::Dh, Au::Dss, C::Camellia, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_DH_RSA_WITH_CAMELLIA_128_CBC_SHA => {
+                suite(Kx::Dh, Au::Rsa, C::Camellia, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_DHE_DSS_WITH_CAMELLIA_128_CBC_SHA => {
+                suite(Kx::Dhe, Au::Dss, C::Camellia, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_128_CBC_SHA => {
+                suite(Kx::Dhe, Au::Rsa, C::Camellia, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_DH_anon_WITH_CAMELLIA_128_CBC_SHA => {
+                suite(Kx::Dhe, Au::Null, C::Camellia, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_RSA_EXPORT1024_WITH_DES_CBC_SHA => {
+                suite(Kx::Rsa, Au::Rsa, C::Des, M::Cbc, 56, Mac::Sha1).export()
+            }
+            Self::TLS_DHE_DSS_EXPORT1024_WITH_DES_CBC_SHA => {
+                suite(Kx::Dhe, Au::Dss, C::Des, M::Cbc, 56, Mac::Sha1).export()
+            }
+            Self::TLS_RSA_EXPORT1024_WITH_RC4_56_SHA => {
+                suite(Kx::Rsa, Au::Rsa, C::Rc4, M::Stream, 56, Mac::Sha1).export()
+            }
+            Self::TLS_RSA_EXPORT1024_WITH_RC4_56_MD5 => {
+                suite(Kx::Rsa, Au::Rsa, C::Rc4, M::Stream, 56, Mac::Md5).export()
+            }
+            Self::TLS_RSA_EXPORT1024_WITH_RC2_CBC_56_MD5 => {
+                suite(Kx::Rsa, Au::Rsa, C::Rc2, M::Cbc, 56, Mac::Md5).export()
+            }
+            Self::TLS_DHE_DSS_EXPORT1024_WITH_RC4_56_SHA => {
+                suite(Kx::Dhe, Au::Dss, C::Rc4, M::Stream, 56, Mac::Sha1).export()
+            }
+            Self::TLS_DHE_DSS_WITH_RC4_128_SHA => {
+                suite(Kx::Dhe, Au::Dss, C::Rc4, M::Stream, 128, Mac::Sha1)
+            }
+            Self::TLS_DHE_RSA_WITH_AES_128_CBC_SHA256 => {
+                suite(Kx::Dhe, Au::Rsa, C::Aes, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_DH_DSS_WITH_AES_256_CBC_SHA256 => {
+                suite(Kx::Dh, Au::Dss, C::Aes, M::Cbc, 256, Mac::Sha256)
+            }
+            Self::TLS_DH_RSA_WITH_AES_256_CBC_SHA256 => {
+                suite(Kx::Dh, Au::Rsa, C::Aes, M::Cbc, 256, Mac::Sha256)
+            }
+            Self::TLS_DHE_DSS_WITH_AES_256_CBC_SHA256 => {
+                suite(Kx::Dhe, Au::Dss, C::Aes, M::Cbc, 256, Mac::Sha256)
+            }
+            Self::TLS_DHE_RSA_WITH_AES_256_CBC_SHA256 => {
+                suite(Kx::Dhe, Au::Rsa, C::Aes, M::Cbc, 256, Mac::Sha256)
+            }
+            Self::TLS_DH_anon_WITH_AES_128_CBC_SHA256 => {
+                suite(Kx::Dhe, Au::Null, C::Aes, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_DH_anon_WITH_AES_256_CBC_SHA256 => {
+                suite(Kx::Dhe, Au::Null, C::Aes, M::Cbc, 256, Mac::Sha256)
+            }
+            Self::TLS_GOSTR341094_WITH_28147_CNT_IMIT => {
+                suite(Kx::Gost, Au::Gost94, C::Gost28147, M::Cnt, 256, Mac::Gost89)
+            }
+            Self::TLS_GOSTR341001_WITH_28147_CNT_IMIT => {
+                suite(Kx::Gost, Au::Gost01, C::Gost28147, M::Cnt, 256, Mac::Gost89)
+            }
+            Self::TLS_RSA_WITH_CAMELLIA_256_CBC_SHA => {
+                suite(Kx::Rsa, Au::Rsa, C::Camellia, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_DH_DSS_WITH_CAMELLIA_256_CBC_SHA => {
+                suite(Kx::Dh, Au::Dss, C::Camellia, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_DH_RSA_WITH_CAMELLIA_256_CBC_SHA => {
+                suite(Kx::Dh, Au::Rsa, C::Camellia, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_DHE_DSS_WITH_CAMELLIA_256_CBC_SHA => {
+                suite(Kx::Dhe, Au::Dss, C::Camellia, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_256_CBC_SHA => {
+                suite(Kx::Dhe, Au::Rsa, C::Camellia, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_DH_anon_WITH_CAMELLIA_256_CBC_SHA => {
+                suite(Kx::Dhe, Au::Null, C::Camellia, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_PSK_WITH_RC4_128_SHA => {
+                suite(Kx::Psk, Au::Psk, C::Rc4, M::Stream, 128, Mac::Sha1)
+            }
+            Self::TLS_PSK_WITH_3DES_EDE_CBC_SHA => {
+                suite(Kx::Psk, Au::Psk, C::TripleDes, M::Cbc, 168, Mac::Sha1)
+            }
+            Self::TLS_PSK_WITH_AES_128_CBC_SHA => {
+                suite(Kx::Psk, Au::Psk, C::Aes, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_PSK_WITH_AES_256_CBC_SHA => {
+                suite(Kx::Psk, Au::Psk, C::Aes, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_RSA_PSK_WITH_RC4_128_SHA => {
+                suite(Kx::RsaPsk, Au::Rsa, C::Rc4, M::Stream, 128, Mac::Sha1)
+            }
+            Self::TLS_RSA_PSK_WITH_3DES_EDE_CBC_SHA => {
+                suite(Kx::RsaPsk, Au::Rsa, C::TripleDes, M::Cbc, 168, Mac::Sha1)
+            }
+            Self::TLS_RSA_PSK_WITH_AES_128_CBC_SHA => {
+                suite(Kx::RsaPsk, Au::Rsa, C::Aes, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_RSA_PSK_WITH_AES_256_CBC_SHA => {
+                suite(Kx::RsaPsk, Au::Rsa, C::Aes, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_RSA_WITH_SEED_CBC_SHA => {
+                suite(Kx::Rsa, Au::Rsa, C::Seed, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_DH_DSS_WITH_SEED_CBC_SHA => {
+                suite(Kx::Dh, Au::Dss, C::Seed, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_DH_RSA_WITH_SEED_CBC_SHA => {
+                suite(Kx::Dh, Au::Rsa, C::Seed, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_DHE_DSS_WITH_SEED_CBC_SHA => {
+                suite(Kx::Dhe, Au::Dss, C::Seed, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_DHE_RSA_WITH_SEED_CBC_SHA => {
+                suite(Kx::Dhe, Au::Rsa, C::Seed, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_DH_anon_WITH_SEED_CBC_SHA => {
+                suite(Kx::Dhe, Au::Null, C::Seed, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_RSA_WITH_AES_128_GCM_SHA256 => {
+                suite(Kx::Rsa, Au::Rsa, C::Aes, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_RSA_WITH_AES_256_GCM_SHA384 => {
+                suite(Kx::Rsa, Au::Rsa, C::Aes, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_DHE_RSA_WITH_AES_128_GCM_SHA256 => {
+                suite(Kx::Dhe, Au::Rsa, C::Aes, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_DHE_RSA_WITH_AES_256_GCM_SHA384 => {
+                suite(Kx::Dhe, Au::Rsa, C::Aes, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_DH_RSA_WITH_AES_128_GCM_SHA256 => {
+                suite(Kx::Dh, Au::Rsa, C::Aes, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_DH_RSA_WITH_AES_256_GCM_SHA384 => {
+                suite(Kx::Dh, Au::Rsa, C::Aes, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_DHE_DSS_WITH_AES_128_GCM_SHA256 => {
+                suite(Kx::Dhe, Au::Dss, C::Aes, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_DHE_DSS_WITH_AES_256_GCM_SHA384 => {
+                suite(Kx::Dhe, Au::Dss, C::Aes, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_DH_DSS_WITH_AES_128_GCM_SHA256 => {
+                suite(Kx::Dh, Au::Dss, C::Aes, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_DH_DSS_WITH_AES_256_GCM_SHA384 => {
+                suite(Kx::Dh, Au::Dss, C::Aes, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_DH_anon_WITH_AES_128_GCM_SHA256 => {
+                suite(Kx::Dhe, Au::Null, C::Aes, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_DH_anon_WITH_AES_256_GCM_SHA384 => {
+                suite(Kx::Dhe, Au::Null, C::Aes, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_RSA_WITH_CAMELLIA_128_CBC_SHA256 => {
+                suite(Kx::Rsa, Au::Rsa, C::Camellia, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_DH_DSS_WITH_CAMELLIA_128_CBC_SHA256 => {
+                suite(Kx::Dh, Au::Dss, C::Camellia, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_DH_RSA_WITH_CAMELLIA_128_CBC_SHA256 => {
+                suite(Kx::Dh, Au::Rsa, C::Camellia, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_DHE_DSS_WITH_CAMELLIA_128_CBC_SHA256 => {
+                suite(Kx::Dhe, Au::Dss, C::Camellia, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_128_CBC_SHA256 => {
+                suite(Kx::Dhe, Au::Rsa, C::Camellia, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_DH_anon_WITH_CAMELLIA_128_CBC_SHA256 => {
+                suite(Kx::Dhe, Au::Null, C::Camellia, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_RSA_WITH_CAMELLIA_256_CBC_SHA256 => {
+                suite(Kx::Rsa, Au::Rsa, C::Camellia, M::Cbc, 256, Mac::Sha256)
+            }
+            Self::TLS_DH_DSS_WITH_CAMELLIA_256_CBC_SHA256 => {
+                suite(Kx::Dh, Au::Dss, C::Camellia, M::Cbc, 256, Mac::Sha256)
+            }
+            Self::TLS_DH_RSA_WITH_CAMELLIA_256_CBC_SHA256 => {
+                suite(Kx::Dh, Au::Rsa, C::Camellia, M::Cbc, 256, Mac::Sha256)
+            }
+            Self::TLS_DHE_DSS_WITH_CAMELLIA_256_CBC_SHA256 => {
+                suite(Kx::Dhe, Au::Dss, C::Camellia, M::Cbc, 256, Mac::Sha256)
+            }
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_256_CBC_SHA256 => {
+                suite(Kx::Dhe, Au::Rsa, C::Camellia, M::Cbc, 256, Mac::Sha256)
+            }
+            Self::TLS_DH_anon_WITH_CAMELLIA_256_CBC_SHA256 => {
+                suite(Kx::Dhe, Au::Null, C::Camellia, M::Cbc, 256, Mac::Sha256)
+            }
+            Self::TLS_ECDH_ECDSA_WITH_NULL_SHA => {
+                suite(Kx::Ecdh, Au::Ecdsa, C::Null, M::None, 0, Mac::Sha1)
+            }
+            Self::TLS_ECDH_ECDSA_WITH_RC4_128_SHA => {
+                suite(Kx::Ecdh, Au::Ecdsa, C::Rc4, M::Stream, 128, Mac::Sha1)
+            }
+            Self::TLS_ECDH_ECDSA_WITH_3DES_EDE_CBC_SHA => {
+                suite(Kx::Ecdh, Au::Ecdsa, C::TripleDes, M::Cbc, 168, Mac::Sha1)
+            }
+            Self::TLS_ECDH_ECDSA_WITH_AES_128_CBC_SHA => {
+                suite(Kx::Ecdh, Au::Ecdsa, C::Aes, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_ECDH_ECDSA_WITH_AES_256_CBC_SHA => {
+                suite(Kx::Ecdh, Au::Ecdsa, C::Aes, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_NULL_SHA => {
+                suite(Kx::Ecdhe, Au::Ecdsa, C::Null, M::None, 0, Mac::Sha1)
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_RC4_128_SHA => {
+                suite(Kx::Ecdhe, Au::Ecdsa, C::Rc4, M::Stream, 128, Mac::Sha1)
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_3DES_EDE_CBC_SHA => {
+                suite(Kx::Ecdhe, Au::Ecdsa, C::TripleDes, M::Cbc, 168, Mac::Sha1)
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA => {
+                suite(Kx::Ecdhe, Au::Ecdsa, C::Aes, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA => {
+                suite(Kx::Ecdhe, Au::Ecdsa, C::Aes, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_ECDH_RSA_WITH_NULL_SHA => {
+                suite(Kx::Ecdh, Au::Rsa, C::Null, M::None, 0, Mac::Sha1)
+            }
+            Self::TLS_ECDH_RSA_WITH_RC4_128_SHA => {
+                suite(Kx::Ecdh, Au::Rsa, C::Rc4, M::Stream, 128, Mac::Sha1)
+            }
+            Self::TLS_ECDH_RSA_WITH_3DES_EDE_CBC_SHA => {
+                suite(Kx::Ecdh, Au::Rsa, C::TripleDes, M::Cbc, 168, Mac::Sha1)
+            }
+            Self::TLS_ECDH_RSA_WITH_AES_128_CBC_SHA => {
+                suite(Kx::Ecdh, Au::Rsa, C::Aes, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_ECDH_RSA_WITH_AES_256_CBC_SHA => {
+                suite(Kx::Ecdh, Au::Rsa, C::Aes, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_ECDHE_RSA_WITH_NULL_SHA => {
+                suite(Kx::Ecdhe, Au::Rsa, C::Null, M::None, 0, Mac::Sha1)
+            }
+            Self::TLS_ECDHE_RSA_WITH_RC4_128_SHA => {
+                suite(Kx::Ecdhe, Au::Rsa, C::Rc4, M::Stream, 128, Mac::Sha1)
+            }
+            Self::TLS_ECDHE_RSA_WITH_3DES_EDE_CBC_SHA => {
+                suite(Kx::Ecdhe, Au::Rsa, C::TripleDes, M::Cbc, 168, Mac::Sha1)
+            }
+            Self::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA => {
+                suite(Kx::Ecdhe, Au::Rsa, C::Aes, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA => {
+                suite(Kx::Ecdhe, Au::Rsa, C::Aes, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_ECDH_anon_WITH_NULL_SHA => {
+                suite(Kx::Ecdhe, Au::Null, C::Null, M::None, 0, Mac::Sha1)
+            }
+            Self::TLS_ECDH_anon_WITH_RC4_128_SHA => {
+                suite(Kx::Ecdhe, Au::Null, C::Rc4, M::Stream, 128, Mac::Sha1)
+            }
+            Self::TLS_ECDH_anon_WITH_3DES_EDE_CBC_SHA => {
+                suite(Kx::Ecdhe, Au::Null, C::TripleDes, M::Cbc, 168, Mac::Sha1)
+            }
+            Self::TLS_ECDH_anon_WITH_AES_128_CBC_SHA => {
+                suite(Kx::Ecdhe, Au::Null, C::Aes, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_ECDH_anon_WITH_AES_256_CBC_SHA => {
+                suite(Kx::Ecdhe, Au::Null, C::Aes, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_SRP_SHA_WITH_3DES_EDE_CBC_SHA => {
+                suite(Kx::Srp, Au::Srp, C::TripleDes, M::Cbc, 168, Mac::Sha1)
+            }
+            Self::TLS_SRP_SHA_RSA_WITH_3DES_EDE_CBC_SHA => {
+                suite(Kx::Srp, Au::Rsa, C::TripleDes, M::Cbc, 168, Mac::Sha1)
+            }
+            Self::TLS_SRP_SHA_DSS_WITH_3DES_EDE_CBC_SHA => {
+                suite(Kx::Srp, Au::Dss, C::TripleDes, M::Cbc, 168, Mac::Sha1)
+            }
+            Self::TLS_SRP_SHA_WITH_AES_128_CBC_SHA => {
+                suite(Kx::Srp, Au::Srp, C::Aes, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_SRP_SHA_RSA_WITH_AES_128_CBC_SHA => {
+                suite(Kx::Srp, Au::Rsa, C::Aes, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_SRP_SHA_DSS_WITH_AES_128_CBC_SHA => {
+                suite(Kx::Srp, Au::Dss, C::Aes, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_SRP_SHA_WITH_AES_256_CBC_SHA => {
+                suite(Kx::Srp, Au::Srp, C::Aes, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_SRP_SHA_RSA_WITH_AES_256_CBC_SHA => {
+                suite(Kx::Srp, Au::Rsa, C::Aes, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_SRP_SHA_DSS_WITH_AES_256_CBC_SHA => {
+                suite(Kx::Srp, Au::Dss, C::Aes, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA256 => {
+                suite(Kx::Ecdhe, Au::Ecdsa, C::Aes, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA384 => {
+                suite(Kx::Ecdhe, Au::Ecdsa, C::Aes, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_ECDH_ECDSA_WITH_AES_128_CBC_SHA256 => {
+                suite(Kx::Ecdh, Au::Ecdsa, C::Aes, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_ECDH_ECDSA_WITH_AES_256_CBC_SHA384 => {
+                suite(Kx::Ecdh, Au::Ecdsa, C::Aes, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA256 => {
+                suite(Kx::Ecdhe, Au::Rsa, C::Aes, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA384 => {
+                suite(Kx::Ecdhe, Au::Rsa, C::Aes, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_ECDH_RSA_WITH_AES_128_CBC_SHA256 => {
+                suite(Kx::Ecdh, Au::Rsa, C::Aes, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_ECDH_RSA_WITH_AES_256_CBC_SHA384 => {
+                suite(Kx::Ecdh, Au::Rsa, C::Aes, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256 => {
+                suite(Kx::Ecdhe, Au::Ecdsa, C::Aes, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384 => {
+                suite(Kx::Ecdhe, Au::Ecdsa, C::Aes, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_ECDH_ECDSA_WITH_AES_128_GCM_SHA256 => {
+                suite(Kx::Ecdh, Au::Ecdsa, C::Aes, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_ECDH_ECDSA_WITH_AES_256_GCM_SHA384 => {
+                suite(Kx::Ecdh, Au::Ecdsa, C::Aes, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256 => {
+                suite(Kx::Ecdhe, Au::Rsa, C::Aes, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384 => {
+                suite(Kx::Ecdhe, Au::Rsa, C::Aes, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_ECDH_RSA_WITH_AES_128_GCM_SHA256 => {
+                suite(Kx::Ecdh, Au::Rsa, C::Aes, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_ECDH_RSA_WITH_AES_256_GCM_SHA384 => {
+                suite(Kx::Ecdh, Au::Rsa, C::Aes, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_CAMELLIA_128_CBC_SHA256 => {
+                suite(Kx::Ecdhe, Au::Ecdsa, C::Camellia, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_CAMELLIA_256_CBC_SHA384 => {
+                suite(Kx::Ecdhe, Au::Ecdsa, C::Camellia, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_ECDH_ECDSA_WITH_CAMELLIA_128_CBC_SHA256 => {
+                suite(Kx::Ecdh, Au::Ecdsa, C::Camellia, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_ECDH_ECDSA_WITH_CAMELLIA_256_CBC_SHA384 => {
+                suite(Kx::Ecdh, Au::Ecdsa, C::Camellia, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_ECDHE_RSA_WITH_CAMELLIA_128_CBC_SHA256 => {
+                suite(Kx::Ecdhe, Au::Rsa, C::Camellia, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_ECDHE_RSA_WITH_CAMELLIA_256_CBC_SHA384 => {
+                suite(Kx::Ecdhe, Au::Rsa, C::Camellia, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_ECDH_RSA_WITH_CAMELLIA_128_CBC_SHA256 => {
+                suite(Kx::Ecdh, Au::Rsa, C::Camellia, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_ECDH_RSA_WITH_CAMELLIA_256_CBC_SHA384 => {
+                suite(Kx::Ecdh, Au::Rsa, C::Camellia, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256 => {
+                suite(Kx::Ecdhe, Au::Rsa, C::ChaCha20, M::Poly1305, 256, Mac::Aead)
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256 => suite(
+                Kx::Ecdhe,
+                Au::Ecdsa,
+                C::ChaCha20,
+                M::Poly1305,
+                256,
+                Mac::Aead,
+            ),
+            Self::TLS_DHE_RSA_WITH_CHACHA20_POLY1305_SHA256 => {
+                suite(Kx::Dhe, Au::Rsa, C::ChaCha20, M::Poly1305, 256, Mac::Aead)
+            }
+            Self::OLD_TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256 => {
+                suite(Kx::Ecdhe, Au::Rsa, C::ChaCha20, M::Poly1305, 256, Mac::Aead)
+            }
+            Self::OLD_TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256 => suite(
+                Kx::Ecdhe,
+                Au::Ecdsa,
+                C::ChaCha20,
+                M::Poly1305,
+                256,
+                Mac::Aead,
+            ),
+            Self::OLD_TLS_DHE_RSA_WITH_CHACHA20_POLY1305_SHA256 => {
+                suite(Kx::Dhe, Au::Rsa, C::ChaCha20, M::Poly1305, 256, Mac::Aead)
+            }
+            Self::TLS_RSA_WITH_AES_128_CCM => {
+                suite(Kx::Rsa, Au::Rsa, C::Aes, M::Ccm, 128, Mac::Aead)
+            }
+            Self::TLS_RSA_WITH_AES_256_CCM => {
+                suite(Kx::Rsa, Au::Rsa, C::Aes, M::Ccm, 256, Mac::Aead)
+            }
+            Self::TLS_DHE_RSA_WITH_AES_128_CCM => {
+                suite(Kx::Dhe, Au::Rsa, C::Aes, M::Ccm, 128, Mac::Aead)
+            }
+            Self::TLS_DHE_RSA_WITH_AES_256_CCM => {
+                suite(Kx::Dhe, Au::Rsa, C::Aes, M::Ccm, 256, Mac::Aead)
+            }
+            Self::TLS_RSA_WITH_AES_128_CCM_8 => {
+                suite(Kx::Rsa, Au::Rsa, C::Aes, M::Ccm8, 128, Mac::Aead)
+            }
+            Self::TLS_RSA_WITH_AES_256_CCM_8 => {
+                suite(Kx::Rsa, Au::Rsa, C::Aes, M::Ccm8, 256, Mac::Aead)
+            }
+            Self::TLS_DHE_RSA_WITH_AES_128_CCM_8 => {
+                suite(Kx::Dhe, Au::Rsa, C::Aes, M::Ccm8, 128, Mac::Aead)
+            }
+            Self::TLS_DHE_RSA_WITH_AES_256_CCM_8 => {
+                suite(Kx::Dhe, Au::Rsa, C::Aes, M::Ccm8, 256, Mac::Aead)
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_AES_128_CCM => {
+                suite(Kx::Ecdhe, Au::Ecdsa, C::Aes, M::Ccm, 128, Mac::Aead)
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_AES_256_CCM => {
+                suite(Kx::Ecdhe, Au::Ecdsa, C::Aes, M::Ccm, 256, Mac::Aead)
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_AES_128_CCM_8 => {
+                suite(Kx::Ecdhe, Au::Ecdsa, C::Aes, M::Ccm8, 128, Mac::Aead)
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_AES_256_CCM_8 => {
+                suite(Kx::Ecdhe, Au::Ecdsa, C::Aes, M::Ccm8, 256, Mac::Aead)
+            }
+            Self::TLS_RSA_WITH_ARIA_128_GCM_SHA256 => {
+                suite(Kx::Rsa, Au::Rsa, C::Aria, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_RSA_WITH_ARIA_256_GCM_SHA384 => {
+                suite(Kx::Rsa, Au::Rsa, C::Aria, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_DHE_DSS_WITH_ARIA_128_GCM_SHA256 => {
+                suite(Kx::Dhe, Au::Dss, C::Aria, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_DHE_DSS_WITH_ARIA_256_GCM_SHA384 => {
+                suite(Kx::Dhe, Au::Dss, C::Aria, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_DHE_PSK_WITH_3DES_EDE_CBC_SHA => {
+                suite(Kx::DhePsk, Au::Psk, C::TripleDes, M::Cbc, 168, Mac::Sha1)
+            }
+            Self::TLS_DHE_PSK_WITH_AES_128_CBC_SHA => {
+                suite(Kx::DhePsk, Au::Psk, C::Aes, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_DHE_PSK_WITH_AES_128_CBC_SHA256 => {
+                suite(Kx::DhePsk, Au::Psk, C::Aes, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_DHE_PSK_WITH_AES_128_CCM => {
+                suite(Kx::DhePsk, Au::Psk, C::Aes, M::Ccm, 128, Mac::Aead)
+            }
+            Self::TLS_PSK_DHE_WITH_AES_128_CCM_8 => {
+                suite(Kx::DhePsk, Au::Psk, C::Aes, M::Ccm8, 128, Mac::Aead)
+            }
+            Self::TLS_DHE_PSK_WITH_AES_128_GCM_SHA256 => {
+                suite(Kx::DhePsk, Au::Psk, C::Aes, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_DHE_PSK_WITH_AES_256_CBC_SHA => {
+                suite(Kx::DhePsk, Au::Psk, C::Aes, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_DHE_PSK_WITH_AES_256_CBC_SHA384 => {
+                suite(Kx::DhePsk, Au::Psk, C::Aes, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_DHE_PSK_WITH_AES_256_CCM => {
+                suite(Kx::DhePsk, Au::Psk, C::Aes, M::Ccm, 256, Mac::Aead)
+            }
+            Self::TLS_PSK_DHE_WITH_AES_256_CCM_8 => {
+                suite(Kx::DhePsk, Au::Psk, C::Aes, M::Ccm8, 256, Mac::Aead)
+            }
+            Self::TLS_DHE_PSK_WITH_AES_256_GCM_SHA384 => {
+                suite(Kx::DhePsk, Au::Psk, C::Aes, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_DHE_PSK_WITH_ARIA_128_GCM_SHA256 => {
+                suite(Kx::DhePsk, Au::Psk, C::Aria, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_DHE_PSK_WITH_ARIA_256_GCM_SHA384 => {
+                suite(Kx::DhePsk, Au::Psk, C::Aria, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_DHE_PSK_WITH_CAMELLIA_128_CBC_SHA256 => {
+                suite(Kx::DhePsk, Au::Psk, C::Camellia, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_DHE_PSK_WITH_CAMELLIA_256_CBC_SHA384 => {
+                suite(Kx::DhePsk, Au::Psk, C::Camellia, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_DHE_PSK_WITH_CHACHA20_POLY1305_SHA256 => suite(
+                Kx::DhePsk,
+                Au::Psk,
+                C::ChaCha20,
+                M::Poly1305,
+                256,
+                Mac::Aead,
+            ),
+            Self::TLS_DHE_PSK_WITH_NULL_SHA => {
+                suite(Kx::DhePsk, Au::Psk, C::Null, M::None, 0, Mac::Sha1)
+            }
+            Self::TLS_DHE_PSK_WITH_NULL_SHA256 => {
+                suite(Kx::DhePsk, Au::Psk, C::Null, M::None, 0, Mac::Sha256)
+            }
+            Self::TLS_DHE_PSK_WITH_NULL_SHA384 => {
+                suite(Kx::DhePsk, Au::Psk, C::Null, M::None, 0, Mac::Sha384)
+            }
+            Self::TLS_DHE_PSK_WITH_RC4_128_SHA => {
+                suite(Kx::DhePsk, Au::Psk, C::Rc4, M::Stream, 128, Mac::Sha1)
+            }
+            Self::TLS_DHE_RSA_WITH_ARIA_128_GCM_SHA256 => {
+                suite(Kx::Dhe, Au::Rsa, C::Aria, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_DHE_RSA_WITH_ARIA_256_GCM_SHA384 => {
+                suite(Kx::Dhe, Au::Rsa, C::Aria, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_ECDHE_RSA_WITH_ARIA_128_GCM_SHA256 => {
+                suite(Kx::Ecdhe, Au::Rsa, C::Aria, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_ECDHE_RSA_WITH_ARIA_256_GCM_SHA384 => {
+                suite(Kx::Ecdhe, Au::Rsa, C::Aria, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_ARIA_128_GCM_SHA256 => {
+                suite(Kx::Ecdhe, Au::Ecdsa, C::Aria, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_ARIA_256_GCM_SHA384 => {
+                suite(Kx::Ecdhe, Au::Ecdsa, C::Aria, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_ECDHE_PSK_WITH_3DES_EDE_CBC_SHA => {
+                suite(Kx::EcdhePsk, Au::Psk, C::TripleDes, M::Cbc, 168, Mac::Sha1)
+            }
+            Self::TLS_ECDHE_PSK_WITH_AES_128_CBC_SHA => {
+                suite(Kx::EcdhePsk, Au::Psk, C::Aes, M::Cbc, 128, Mac::Sha1)
+            }
+            Self::TLS_ECDHE_PSK_WITH_AES_128_CBC_SHA256 => {
+                suite(Kx::EcdhePsk, Au::Psk, C::Aes, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_ECDHE_PSK_WITH_AES_256_CBC_SHA => {
+                suite(Kx::EcdhePsk, Au::Psk, C::Aes, M::Cbc, 256, Mac::Sha1)
+            }
+            Self::TLS_ECDHE_PSK_WITH_AES_256_CBC_SHA384 => {
+                suite(Kx::EcdhePsk, Au::Psk, C::Aes, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_ECDHE_PSK_WITH_CAMELLIA_128_CBC_SHA256 => {
+                suite(Kx::EcdhePsk, Au::Psk, C::Camellia, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_ECDHE_PSK_WITH_CAMELLIA_256_CBC_SHA384 => {
+                suite(Kx::EcdhePsk, Au::Psk, C::Camellia, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_ECDHE_PSK_WITH_CHACHA20_POLY1305_SHA256 => suite(
+                Kx::EcdhePsk,
+                Au::Psk,
+                C::ChaCha20,
+                M::Poly1305,
+                256,
+                Mac::Aead,
+            ),
+            Self::TLS_ECDHE_PSK_WITH_NULL_SHA => {
+                suite(Kx::EcdhePsk, Au::Psk, C::Null, M::None, 0, Mac::Sha1)
+            }
+            Self::TLS_ECDHE_PSK_WITH_NULL_SHA256 => {
+                suite(Kx::EcdhePsk, Au::Psk, C::Null, M::None, 0, Mac::Sha256)
+            }
+            Self::TLS_ECDHE_PSK_WITH_NULL_SHA384 => {
+                suite(Kx::EcdhePsk, Au::Psk, C::Null, M::None, 0, Mac::Sha384)
+            }
+            Self::TLS_ECDHE_PSK_WITH_RC4_128_SHA => {
+                suite(Kx::EcdhePsk, Au::Psk, C::Rc4, M::Stream, 128, Mac::Sha1)
+            }
+            Self::TLS_GOSTR341001_WITH_NULL_GOSTR3411 => {
+                suite(Kx::Gost, Au::Gost01, C::Null, M::None, 0, Mac::Gost94)
+            }
+            Self::TLS_GOSTR341094_WITH_NULL_GOSTR3411 => {
+                suite(Kx::Gost, Au::Gost94, C::Null, M::None, 0, Mac::Gost94)
+            }
+            Self::TLS_PSK_WITH_AES_128_CBC_SHA256 => {
+                suite(Kx::Psk, Au::Psk, C::Aes, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_PSK_WITH_AES_128_CCM => {
+                suite(Kx::Psk, Au::Psk, C::Aes, M::Ccm, 128, Mac::Aead)
+            }
+            Self::TLS_PSK_WITH_AES_128_CCM_8 => {
+                suite(Kx::Psk, Au::Psk, C::Aes, M::Ccm8, 128, Mac::Aead)
+            }
+            Self::TLS_PSK_WITH_AES_128_GCM_SHA256 => {
+                suite(Kx::Psk, Au::Psk, C::Aes, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_PSK_WITH_AES_256_CBC_SHA384 => {
+                suite(Kx::Psk, Au::Psk, C::Aes, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_PSK_WITH_AES_256_CCM => {
+                suite(Kx::Psk, Au::Psk, C::Aes, M::Ccm, 256, Mac::Aead)
+            }
+            Self::TLS_PSK_WITH_AES_256_CCM_8 => {
+                suite(Kx::Psk, Au::Psk, C::Aes, M::Ccm8, 256, Mac::Aead)
+            }
+            Self::TLS_PSK_WITH_AES_256_GCM_SHA384 => {
+                suite(Kx::Psk, Au::Psk, C::Aes, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_PSK_WITH_ARIA_128_GCM_SHA256 => {
+                suite(Kx::Psk, Au::Psk, C::Aria, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_PSK_WITH_ARIA_256_GCM_SHA384 => {
+                suite(Kx::Psk, Au::Psk, C::Aria, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_PSK_WITH_CAMELLIA_128_CBC_SHA256 => {
+                suite(Kx::Psk, Au::Psk, C::Camellia, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_PSK_WITH_CAMELLIA_256_CBC_SHA384 => {
+                suite(Kx::Psk, Au::Psk, C::Camellia, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_PSK_WITH_CHACHA20_POLY1305_SHA256 => {
+                suite(Kx::Psk, Au::Psk, C::ChaCha20, M::Poly1305, 256, Mac::Aead)
+            }
+            Self::TLS_PSK_WITH_NULL_SHA => suite(Kx::Psk, Au::Psk, C::Null, M::None, 0, Mac::Sha1),
+            Self::TLS_PSK_WITH_NULL_SHA256 => {
+                suite(Kx::Psk, Au::Psk, C::Null, M::None, 0, Mac::Sha256)
+            }
+            Self::TLS_PSK_WITH_NULL_SHA384 => {
+                suite(Kx::Psk, Au::Psk, C::Null, M::None, 0, Mac::Sha384)
+            }
+            Self::TLS_RSA_PSK_WITH_AES_128_CBC_SHA256 => {
+                suite(Kx::RsaPsk, Au::Rsa, C::Aes, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_RSA_PSK_WITH_AES_128_GCM_SHA256 => {
+                suite(Kx::RsaPsk, Au::Rsa, C::Aes, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_RSA_PSK_WITH_AES_256_CBC_SHA384 => {
+                suite(Kx::RsaPsk, Au::Rsa, C::Aes, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_RSA_PSK_WITH_AES_256_GCM_SHA384 => {
+                suite(Kx::RsaPsk, Au::Rsa, C::Aes, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_RSA_PSK_WITH_ARIA_128_GCM_SHA256 => {
+                suite(Kx::RsaPsk, Au::Rsa, C::Aria, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_RSA_PSK_WITH_ARIA_256_GCM_SHA384 => {
+                suite(Kx::RsaPsk, Au::Rsa, C::Aria, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_RSA_PSK_WITH_CAMELLIA_128_CBC_SHA256 => {
+                suite(Kx::RsaPsk, Au::Rsa, C::Camellia, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_RSA_PSK_WITH_CAMELLIA_256_CBC_SHA384 => {
+                suite(Kx::RsaPsk, Au::Rsa, C::Camellia, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_RSA_PSK_WITH_CHACHA20_POLY1305_SHA256 => suite(
+                Kx::RsaPsk,
+                Au::Rsa,
+                C::ChaCha20,
+                M::Poly1305,
+                256,
+                Mac::Aead,
+            ),
+            Self::TLS_RSA_PSK_WITH_NULL_SHA => {
+                suite(Kx::RsaPsk, Au::Rsa, C::Null, M::None, 0, Mac::Sha1)
+            }
+            Self::TLS_RSA_PSK_WITH_NULL_SHA256 => {
+                suite(Kx::RsaPsk, Au::Rsa, C::Null, M::None, 0, Mac::Sha256)
+            }
+            Self::TLS_RSA_PSK_WITH_NULL_SHA384 => {
+                suite(Kx::RsaPsk, Au::Rsa, C::Null, M::None, 0, Mac::Sha384)
+            }
+            Self::TLS_AES_128_GCM_SHA256 => suite(Kx::Any, Au::Any, C::Aes, M::Gcm, 128, Mac::Aead),
+            Self::TLS_AES_256_GCM_SHA384 => suite(Kx::Any, Au::Any, C::Aes, M::Gcm, 256, Mac::Aead),
+            Self::TLS_CHACHA20_POLY1305_SHA256 => {
+                suite(Kx::Any, Au::Any, C::ChaCha20, M::Poly1305, 256, Mac::Aead)
+            }
+            Self::TLS_AES_128_CCM_SHA256 => suite(Kx::Any, Au::Any, C::Aes, M::Ccm, 128, Mac::Aead),
+            Self::TLS_AES_128_CCM_8_SHA256 => {
+                suite(Kx::Any, Au::Any, C::Aes, M::Ccm8, 128, Mac::Aead)
+            }
+            Self::TLS_GOSTR341112_256_WITH_KUZNYECHIK_CTR_OMAC => suite(
+                Kx::Gost,
+                Au::Gost12,
+                C::Kuznyechik,
+                M::Ctr,
+                256,
+                Mac::KuznyechikOmac,
+            ),
+            Self::TLS_GOSTR341112_256_WITH_MAGMA_CTR_OMAC => {
+                suite(Kx::Gost, Au::Gost12, C::Magma, M::Ctr, 256, Mac::MagmaOmac)
+            }
+            Self::TLS_GOSTR341112_256_WITH_28147_CNT_IMIT => {
+                suite(Kx::Gost, Au::Gost12, C::Gost28147, M::Cnt, 256, Mac::Gost89)
+            }
+            Self::TLS_SM4_GCM_SM3 => suite(Kx::Any, Au::Any, C::Sm4, M::Gcm, 128, Mac::Aead),
+            Self::TLS_SM4_CCM_SM3 => suite(Kx::Any, Au::Any, C::Sm4, M::Ccm, 128, Mac::Aead),
+            Self::TLS_AEGIS_256_SHA512 => {
+                suite(Kx::Any, Au::Any, C::Aegis256, M::Aead, 256, Mac::Aead)
+            }
+            Self::TLS_AEGIS_128L_SHA256 => {
+                suite(Kx::Any, Au::Any, C::Aegis128L, M::Aead, 128, Mac::Aead)
+            }
+            Self::TLS_RSA_WITH_ARIA_128_CBC_SHA256 => {
+                suite(Kx::Rsa, Au::Rsa, C::Aria, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_RSA_WITH_ARIA_256_CBC_SHA384 => {
+                suite(Kx::Rsa, Au::Rsa, C::Aria, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_DH_DSS_WITH_ARIA_128_CBC_SHA256 => {
+                suite(Kx::Dh, Au::Dss, C::Aria, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_DH_DSS_WITH_ARIA_256_CBC_SHA384 => {
+                suite(Kx::Dh, Au::Dss, C::Aria, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_DH_RSA_WITH_ARIA_128_CBC_SHA256 => {
+                suite(Kx::Dh, Au::Rsa, C::Aria, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_DH_RSA_WITH_ARIA_256_CBC_SHA384 => {
+                suite(Kx::Dh, Au::Rsa, C::Aria, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_DHE_DSS_WITH_ARIA_128_CBC_SHA256 => {
+                suite(Kx::Dhe, Au::Dss, C::Aria, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_DHE_DSS_WITH_ARIA_256_CBC_SHA384 => {
+                suite(Kx::Dhe, Au::Dss, C::Aria, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_DHE_RSA_WITH_ARIA_128_CBC_SHA256 => {
+                suite(Kx::Dhe, Au::Rsa, C::Aria, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_DHE_RSA_WITH_ARIA_256_CBC_SHA384 => {
+                suite(Kx::Dhe, Au::Rsa, C::Aria, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_DH_anon_WITH_ARIA_128_CBC_SHA256 => {
+                suite(Kx::Dhe, Au::Null, C::Aria, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_DH_anon_WITH_ARIA_256_CBC_SHA384 => {
+                suite(Kx::Dhe, Au::Null, C::Aria, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_ARIA_128_CBC_SHA256 => {
+                suite(Kx::Ecdhe, Au::Ecdsa, C::Aria, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_ARIA_256_CBC_SHA384 => {
+                suite(Kx::Ecdhe, Au::Ecdsa, C::Aria, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_ECDH_ECDSA_WITH_ARIA_128_CBC_SHA256 => {
+                suite(Kx::Ecdh, Au::Ecdsa, C::Aria, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_ECDH_ECDSA_WITH_ARIA_256_CBC_SHA384 => {
+                suite(Kx::Ecdh, Au::Ecdsa, C::Aria, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_ECDHE_RSA_WITH_ARIA_128_CBC_SHA256 => {
+                suite(Kx::Ecdhe, Au::Rsa, C::Aria, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_ECDHE_RSA_WITH_ARIA_256_CBC_SHA384 => {
+                suite(Kx::Ecdhe, Au::Rsa, C::Aria, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_ECDH_RSA_WITH_ARIA_128_CBC_SHA256 => {
+                suite(Kx::Ecdh, Au::Rsa, C::Aria, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_ECDH_RSA_WITH_ARIA_256_CBC_SHA384 => {
+                suite(Kx::Ecdh, Au::Rsa, C::Aria, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_DH_RSA_WITH_ARIA_128_GCM_SHA256 => {
+                suite(Kx::Dh, Au::Rsa, C::Aria, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_DH_RSA_WITH_ARIA_256_GCM_SHA384 => {
+                suite(Kx::Dh, Au::Rsa, C::Aria, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_DH_DSS_WITH_ARIA_128_GCM_SHA256 => {
+                suite(Kx::Dh, Au::Dss, C::Aria, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_DH_DSS_WITH_ARIA_256_GCM_SHA384 => {
+                suite(Kx::Dh, Au::Dss, C::Aria, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_DH_anon_WITH_ARIA_128_GCM_SHA256 => {
+                suite(Kx::Dhe, Au::Null, C::Aria, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_DH_anon_WITH_ARIA_256_GCM_SHA384 => {
+                suite(Kx::Dhe, Au::Null, C::Aria, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_ECDH_ECDSA_WITH_ARIA_128_GCM_SHA256 => {
+                suite(Kx::Ecdh, Au::Ecdsa, C::Aria, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_ECDH_ECDSA_WITH_ARIA_256_GCM_SHA384 => {
+                suite(Kx::Ecdh, Au::Ecdsa, C::Aria, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_ECDH_RSA_WITH_ARIA_128_GCM_SHA256 => {
+                suite(Kx::Ecdh, Au::Rsa, C::Aria, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_ECDH_RSA_WITH_ARIA_256_GCM_SHA384 => {
+                suite(Kx::Ecdh, Au::Rsa, C::Aria, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_PSK_WITH_ARIA_128_CBC_SHA256 => {
+                suite(Kx::Psk, Au::Psk, C::Aria, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_PSK_WITH_ARIA_256_CBC_SHA384 => {
+                suite(Kx::Psk, Au::Psk, C::Aria, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_DHE_PSK_WITH_ARIA_128_CBC_SHA256 => {
+                suite(Kx::DhePsk, Au::Psk, C::Aria, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_DHE_PSK_WITH_ARIA_256_CBC_SHA384 => {
+                suite(Kx::DhePsk, Au::Psk, C::Aria, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_RSA_PSK_WITH_ARIA_128_CBC_SHA256 => {
+                suite(Kx::RsaPsk, Au::Rsa, C::Aria, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_RSA_PSK_WITH_ARIA_256_CBC_SHA384 => {
+                suite(Kx::RsaPsk, Au::Rsa, C::Aria, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_ECDHE_PSK_WITH_ARIA_128_CBC_SHA256 => {
+                suite(Kx::EcdhePsk, Au::Psk, C::Aria, M::Cbc, 128, Mac::Sha256)
+            }
+            Self::TLS_ECDHE_PSK_WITH_ARIA_256_CBC_SHA384 => {
+                suite(Kx::EcdhePsk, Au::Psk, C::Aria, M::Cbc, 256, Mac::Sha384)
+            }
+            Self::TLS_RSA_WITH_CAMELLIA_128_GCM_SHA256 => {
+                suite(Kx::Rsa, Au::Rsa, C::Camellia, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_RSA_WITH_CAMELLIA_256_GCM_SHA384 => {
+                suite(Kx::Rsa, Au::Rsa, C::Camellia, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_128_GCM_SHA256 => {
+                suite(Kx::Dhe, Au::Rsa, C::Camellia, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_256_GCM_SHA384 => {
+                suite(Kx::Dhe, Au::Rsa, C::Camellia, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_DH_RSA_WITH_CAMELLIA_128_GCM_SHA256 => {
+                suite(Kx::Dh, Au::Rsa, C::Camellia, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_DH_RSA_WITH_CAMELLIA_256_GCM_SHA384 => {
+                suite(Kx::Dh, Au::Rsa, C::Camellia, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_DHE_DSS_WITH_CAMELLIA_128_GCM_SHA256 => {
+                suite(Kx::Dhe, Au::Dss, C::Camellia, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_DHE_DSS_WITH_CAMELLIA_256_GCM_SHA384 => {
+                suite(Kx::Dhe, Au::Dss, C::Camellia, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_DH_DSS_WITH_CAMELLIA_128_GCM_SHA256 => {
+                suite(Kx::Dh, Au::Dss, C::Camellia, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_DH_DSS_WITH_CAMELLIA_256_GCM_SHA384 => {
+                suite(Kx::Dh, Au::Dss, C::Camellia, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_DH_anon_WITH_CAMELLIA_128_GCM_SHA256 => {
+                suite(Kx::Dhe, Au::Null, C::Camellia, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_DH_anon_WITH_CAMELLIA_256_GCM_SHA384 => {
+                suite(Kx::Dhe, Au::Null, C::Camellia, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_CAMELLIA_128_GCM_SHA256 => {
+                suite(Kx::Ecdhe, Au::Ecdsa, C::Camellia, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_CAMELLIA_256_GCM_SHA384 => {
+                suite(Kx::Ecdhe, Au::Ecdsa, C::Camellia, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_ECDH_ECDSA_WITH_CAMELLIA_128_GCM_SHA256 => {
+                suite(Kx::Ecdh, Au::Ecdsa, C::Camellia, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_ECDH_ECDSA_WITH_CAMELLIA_256_GCM_SHA384 => {
+                suite(Kx::Ecdh, Au::Ecdsa, C::Camellia, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_ECDHE_RSA_WITH_CAMELLIA_128_GCM_SHA256 => {
+                suite(Kx::Ecdhe, Au::Rsa, C::Camellia, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_ECDHE_RSA_WITH_CAMELLIA_256_GCM_SHA384 => {
+                suite(Kx::Ecdhe, Au::Rsa, C::Camellia, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_ECDH_RSA_WITH_CAMELLIA_128_GCM_SHA256 => {
+                suite(Kx::Ecdh, Au::Rsa, C::Camellia, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_ECDH_RSA_WITH_CAMELLIA_256_GCM_SHA384 => {
+                suite(Kx::Ecdh, Au::Rsa, C::Camellia, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_PSK_WITH_CAMELLIA_128_GCM_SHA256 => {
+                suite(Kx::Psk, Au::Psk, C::Camellia, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_PSK_WITH_CAMELLIA_256_GCM_SHA384 => {
+                suite(Kx::Psk, Au::Psk, C::Camellia, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_DHE_PSK_WITH_CAMELLIA_128_GCM_SHA256 => {
+                suite(Kx::DhePsk, Au::Psk, C::Camellia, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_DHE_PSK_WITH_CAMELLIA_256_GCM_SHA384 => {
+                suite(Kx::DhePsk, Au::Psk, C::Camellia, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_RSA_PSK_WITH_CAMELLIA_128_GCM_SHA256 => {
+                suite(Kx::RsaPsk, Au::Rsa, C::Camellia, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_RSA_PSK_WITH_CAMELLIA_256_GCM_SHA384 => {
+                suite(Kx::RsaPsk, Au::Rsa, C::Camellia, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_ECCPWD_WITH_AES_128_GCM_SHA256 => {
+                suite(Kx::EccPwd, Au::EccPwd, C::Aes, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_ECCPWD_WITH_AES_256_GCM_SHA384 => {
+                suite(Kx::EccPwd, Au::EccPwd, C::Aes, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_ECCPWD_WITH_AES_128_CCM_SHA256 => {
+                suite(Kx::EccPwd, Au::EccPwd, C::Aes, M::Ccm, 128, Mac::Aead)
+            }
+            Self::TLS_ECCPWD_WITH_AES_256_CCM_SHA384 => {
+                suite(Kx::EccPwd, Au::EccPwd, C::Aes, M::Ccm, 256, Mac::Aead)
+            }
+            Self::TLS_SHA256_SHA256 => suite(Kx::Any, Au::Any, C::Null, M::None, 0, Mac::Sha256),
+            Self::TLS_SHA384_SHA384 => suite(Kx::Any, Au::Any, C::Null, M::None, 0, Mac::Sha384),
+            Self::TLS_GOSTR341112_256_WITH_KUZNYECHIK_MGM_L => {
+                suite(Kx::Any, Au::Any, C::Kuznyechik, M::Mgm, 256, Mac::Aead)
+            }
+            Self::TLS_GOSTR341112_256_WITH_MAGMA_MGM_L => {
+                suite(Kx::Any, Au::Any, C::Magma, M::Mgm, 256, Mac::Aead)
+            }
+            Self::TLS_GOSTR341112_256_WITH_KUZNYECHIK_MGM_S => {
+                suite(Kx::Any, Au::Any, C::Kuznyechik, M::Mgm, 256, Mac::Aead)
+            }
+            Self::TLS_GOSTR341112_256_WITH_MAGMA_MGM_S => {
+                suite(Kx::Any, Au::Any, C::Magma, M::Mgm, 256, Mac::Aead)
+            }
+            Self::TLS_ECDHE_PSK_WITH_AES_128_GCM_SHA256 => {
+                suite(Kx::EcdhePsk, Au::Psk, C::Aes, M::Gcm, 128, Mac::Aead)
+            }
+            Self::TLS_ECDHE_PSK_WITH_AES_256_GCM_SHA384 => {
+                suite(Kx::EcdhePsk, Au::Psk, C::Aes, M::Gcm, 256, Mac::Aead)
+            }
+            Self::TLS_ECDHE_PSK_WITH_AES_128_CCM_8_SHA256 => {
+                suite(Kx::EcdhePsk, Au::Psk, C::Aes, M::Ccm8, 128, Mac::Aead)
+            }
+            Self::TLS_ECDHE_PSK_WITH_AES_128_CCM_SHA256 => {
+                suite(Kx::EcdhePsk, Au::Psk, C::Aes, M::Ccm, 128, Mac::Aead)
+            }
+        }
+    }
+}
+
+/// Builds the components of a suite. The GOST MGM suites from RFC 9367 are
+/// TLS 1.3 suites despite their TLS 1.2 style names, so they are listed with
+/// `KeyExchange::Any`.
+const fn suite(
+    key_exchange: KeyExchange,
+    authentication: Authentication,
+    cipher: Cipher,
+    mode: CipherMode,
+    bits: u16,
+    mac: Mac,
+) -> Components {
+    let encryption = Encryption { cipher, mode, bits };
+    let psk = matches!(
+        key_exchange,
+        KeyExchange::Psk | KeyExchange::RsaPsk | KeyExchange::DhePsk | KeyExchange::EcdhePsk
+    );
+    let sha2 = matches!(mac, Mac::Sha256 | Mac::Sha384);
+
+    // RFC 5487 allows the PSK suites with SHA-2 MACs from TLS 1.0 onwards.
+    let min_version = if matches!(key_exchange, KeyExchange::Any) {
+        ProtocolVersion::Tls13
+    } else if encryption.is_aead()
+        || (sha2 && !psk)
+        || matches!(cipher, Cipher::Kuznyechik | Cipher::Magma)
+    {
+        ProtocolVersion::Tls12
+    } else if sha2
+        || matches!(
+            key_exchange,
+            KeyExchange::Ecdh | KeyExchange::Ecdhe | KeyExchange::EcdhePsk | KeyExchange::Gost
+        )
+    {
+        ProtocolVersion::Tls10
+    } else {
+        ProtocolVersion::Ssl3
+    };
+
+    Components {
+        key_exchange,
+        authentication,
+        encryption,
+        mac,
+        min_version,
+        export: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn decompose() {
+        let c = TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256.components();
+        assert_eq!(c.key_exchange, KeyExchange::Ecdhe);
+        assert_eq!(c.authentication, Authentication::Rsa);
+        assert_eq!(
+            c.encryption,
+            Encryption {
+                cipher: Cipher::Aes,
+                mode: CipherMode::Gcm,
+                bits: 128
+            }
+        );
+        assert_eq!(c.mac, Mac::Aead);
+        assert_eq!(c.min_version, ProtocolVersion::Tls12);
+        assert!(c.forward_secrecy());
+
+        let c = TLSCipherSuite::TLS_KRB5_EXPORT_WITH_DES_CBC_40_MD5.components();
+        assert_eq!(c.key_exchange, KeyExchange::Krb5);
+        assert_eq!(c.encryption.cipher, Cipher::Des);
+        assert_eq!(c.encryption.bits, 40);
+        assert_eq!(c.mac, Mac::Md5);
+        assert!(c.export);
+
+        let c = TLSCipherSuite::TLS_ECDHE_PSK_WITH_AES_128_CCM_8_SHA256.components();
+        assert_eq!(c.encryption.mode, CipherMode::Ccm8);
+        assert_eq!(c.encryption.bits, 128);
+
+        assert!(TLSCipherSuite::TLS_GOSTR341112_256_WITH_MAGMA_MGM_S.is_tls13());
+        assert!(TLSCipherSuite::TLS_SHA384_SHA384.is_tls13());
+        assert!(!TLSCipherSuite::TLS_RSA_WITH_3DES_EDE_CBC_SHA.is_tls13());
+    }
+
+    #[test]
+    fn table_is_consistent() {
+        for cipher in TLSCipherSuite::iter() {
+            let c = cipher.components();
+            assert_eq!(c.mac == Mac::Aead, c.encryption.is_aead(), "{}", cipher);
+            assert_eq!(
+                c.key_exchange == KeyExchange::Any,
+                c.authentication == Authentication::Any,
+                "{}",
+                cipher
+            );
+            assert_eq!(
+                c.export,
+                cipher.to_string().contains("_EXPORT"),
+                "{}",
+                cipher
+            );
+        }
+    }
+}
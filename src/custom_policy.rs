@@ -0,0 +1,336 @@
+use std::fmt;
+#[cfg(any(feature = "toml", feature = "json"))]
+use std::str::FromStr;
+
+#[cfg(any(feature = "toml", feature = "json"))]
+use serde::Deserialize;
+
+use crate::components::{Authentication, Cipher, KeyExchange, Mac};
+use crate::{Assessment, Policy, Report, TLSCipherSuite, Verdict};
+
+/// A policy assembled from rules rather than taken from a standard, such as
+/// an organisation's internal TLS policy.
+///
+/// A suite is approved only if it passes every rule: it must not be on the
+/// `deny` list, must be on the `allow` list if that list is not empty, must
+/// not use a denied component and must meet the strength, forward secrecy
+/// and AEAD requirements. The reference of a rejection names the rule, as it
+/// is spelled in a policy file.
+///
+/// `order` only affects `check`: the suites it lists must appear in the
+/// checked list in the same relative order, and a suite that comes after one
+/// the policy wants offered later is rejected.
+///
+/// With the `toml` or `json` feature, a policy can be loaded from a file:
+///
+/// ```
+/// # #[cfg(feature = "toml")]
+/// # {
+/// use openssl_to_rfc::{CustomPolicy, Policy, TLSCipherSuite};
+///
+/// let policy = CustomPolicy::from_toml_str(
+///     r#"
+///     name = "Internal TLS policy"
+///     deny = ["AES128-SHA"]
+///     deny_mac = ["Md5"]
+///     require_forward_secrecy = true
+///     min_strength = 128
+///     "#,
+/// )
+/// .unwrap();
+///
+/// let assessment = policy.assess(TLSCipherSuite::TLS_RSA_WITH_AES_256_GCM_SHA384);
+/// assert!(!assessment.is_approved());
+/// assert_eq!(assessment.reference.as_deref(), Some("require_forward_secrecy"));
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct CustomPolicy {
+    pub name: String,
+    /// If not empty, the only suites the policy approves.
+    pub allow: Vec<TLSCipherSuite>,
+    pub deny: Vec<TLSCipherSuite>,
+    pub deny_key_exchange: Vec<KeyExchange>,
+    pub deny_authentication: Vec<Authentication>,
+    pub deny_cipher: Vec<Cipher>,
+    pub deny_mac: Vec<Mac>,
+    pub require_forward_secrecy: bool,
+    pub require_aead: bool,
+    /// The lowest acceptable `Encryption::strength_bits`.
+    pub min_strength: u16,
+    /// Suites that must be offered in this relative order.
+    pub order: Vec<TLSCipherSuite>,
+}
+
+impl Policy for CustomPolicy {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn assess(&self, cipher: TLSCipherSuite) -> Assessment {
+        let (verdict, reason, reference) = match self.judge(cipher) {
+            Ok(()) => (Verdict::Approved, "passes every rule".to_owned(), None),
+            Err((reason, rule)) => (Verdict::NotApproved, reason, Some(rule.to_owned())),
+        };
+        Assessment {
+            cipher,
+            verdict,
+            reason,
+            reference,
+        }
+    }
+
+    fn check(&self, ciphers: &[TLSCipherSuite]) -> Report {
+        let mut assessments: Vec<_> = ciphers.iter().map(|c| self.assess(*c)).collect();
+
+        let rank = |cipher| self.order.iter().position(|c| *c == cipher);
+        let mut latest: Option<(usize, TLSCipherSuite)> = None;
+        for a in &mut assessments {
+            let Some(r) = rank(a.cipher) else { continue };
+            match latest {
+                Some((later, before)) if later > r => {
+                    if a.is_approved() {
+                        a.verdict = Verdict::NotApproved;
+                        a.reason = format!("must be offered before {}", before);
+                        a.reference = Some("order".to_owned());
+                    }
+                }
+                _ => latest = Some((r, a.cipher)),
+            }
+        }
+
+        Report {
+            policy: self.name(),
+            assessments,
+        }
+    }
+}
+
+impl CustomPolicy {
+    fn judge(&self, cipher: TLSCipherSuite) -> Result<(), (String, &'static str)> {
+        let reject = |reason: String, rule| Err((reason, rule));
+
+        if self.deny.contains(&cipher) {
+            return reject("denied by name".to_owned(), "deny");
+        }
+        if !self.allow.is_empty() && !self.allow.contains(&cipher) {
+            return reject("not on the allow list".to_owned(), "allow");
+        }
+        if cipher.is_signaling() {
+            return Ok(());
+        }
+
+        let c = cipher.components();
+        if self.deny_key_exchange.contains(&c.key_exchange) {
+            return reject(
+                format!("{:?} key exchange", c.key_exchange),
+                "deny_key_exchange",
+            );
+        }
+        if self.deny_authentication.contains(&c.authentication) {
+            return reject(
+                format!("{:?} authentication", c.authentication),
+                "deny_authentication",
+            );
+        }
+        if self.deny_cipher.contains(&c.encryption.cipher) {
+            return reject(
+                format!("{:?} encryption", c.encryption.cipher),
+                "deny_cipher",
+            );
+        }
+        if self.deny_mac.contains(&c.mac) {
+            return reject(format!("{:?} MAC", c.mac), "deny_mac");
+        }
+        if self.require_forward_secrecy && !c.forward_secrecy() {
+            return reject("no forward secrecy".to_owned(), "require_forward_secrecy");
+        }
+        if self.require_aead && !c.encryption.is_aead() {
+            return reject("not an AEAD cipher".to_owned(), "require_aead");
+        }
+        if c.encryption.strength_bits() < self.min_strength {
+            return reject(
+                format!(
+                    "{}-bit encryption is weaker than {} bits",
+                    c.encryption.strength_bits(),
+                    self.min_strength
+                ),
+                "min_strength",
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(any(feature = "toml", feature = "json"))]
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+struct PolicyFile {
+    name: String,
+    allow: Vec<String>,
+    deny: Vec<String>,
+    deny_key_exchange: Vec<KeyExchange>,
+    deny_authentication: Vec<Authentication>,
+    deny_cipher: Vec<Cipher>,
+    deny_mac: Vec<Mac>,
+    require_forward_secrecy: bool,
+    require_aead: bool,
+    min_strength: u16,
+    order: Vec<String>,
+}
+
+#[cfg(any(feature = "toml", feature = "json"))]
+impl CustomPolicy {
+    /// Loads a policy from TOML.
+    ///
+    /// Suites are referenced by their OpenSSL or RFC name and components by
+    /// their variant name, e.g. `deny_cipher = ["Rc4", "TripleDes"]`. Every
+    /// key is optional.
+    #[cfg(feature = "toml")]
+    pub fn from_toml_str(s: &str) -> Result<CustomPolicy, PolicyFileError> {
+        let file = toml::from_str(s).map_err(|e| PolicyFileError::Syntax(e.to_string()))?;
+        CustomPolicy::from_file(file)
+    }
+
+    /// Loads a policy from JSON, with the same keys as `from_toml_str`.
+    #[cfg(feature = "json")]
+    pub fn from_json_str(s: &str) -> Result<CustomPolicy, PolicyFileError> {
+        let file = serde_json::from_str(s).map_err(|e| PolicyFileError::Syntax(e.to_string()))?;
+        CustomPolicy::from_file(file)
+    }
+
+    fn from_file(file: PolicyFile) -> Result<CustomPolicy, PolicyFileError> {
+        let mut unknown = Vec::new();
+        let mut resolve = |names: Vec<String>| -> Vec<TLSCipherSuite> {
+            names
+                .into_iter()
+                .filter_map(|name| {
+                    let cipher = TLSCipherSuite::from_openssl_name(&name)
+                        .or_else(|| TLSCipherSuite::from_str(&name).ok());
+                    if cipher.is_none() && !unknown.contains(&name) {
+                        unknown.push(name);
+                    }
+                    cipher
+                })
+                .collect()
+        };
+
+        let policy = CustomPolicy {
+            name: file.name,
+            allow: resolve(file.allow),
+            deny: resolve(file.deny),
+            deny_key_exchange: file.deny_key_exchange,
+            deny_authentication: file.deny_authentication,
+            deny_cipher: file.deny_cipher,
+            deny_mac: file.deny_mac,
+            require_forward_secrecy: file.require_forward_secrecy,
+            require_aead: file.require_aead,
+            min_strength: file.min_strength,
+            order: resolve(file.order),
+        };
+        if !unknown.is_empty() {
+            return Err(PolicyFileError::UnknownCipherSuites(unknown));
+        }
+        Ok(policy)
+    }
+}
+
+/// Error returned when loading a `CustomPolicy` from a file.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum PolicyFileError {
+    /// The file is not valid TOML or JSON, has an unknown key or names an
+    /// unknown component.
+    Syntax(String),
+    /// Names that are neither an OpenSSL nor an RFC cipher suite name, in
+    /// the order they first appear.
+    UnknownCipherSuites(Vec<String>),
+}
+
+impl fmt::Display for PolicyFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax(e) => write!(f, "invalid policy file: {}", e),
+            Self::UnknownCipherSuites(names) => {
+                write!(f, "unknown cipher suites: {}", names.join(", "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for PolicyFileError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn required_order() {
+        let policy = CustomPolicy {
+            order: vec![
+                TLSCipherSuite::TLS_AES_256_GCM_SHA384,
+                TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+                TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+            ],
+            ..Default::default()
+        };
+        let report = policy.check(&[
+            TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+            TLSCipherSuite::TLS_CHACHA20_POLY1305_SHA256,
+            TLSCipherSuite::TLS_AES_256_GCM_SHA384,
+            TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+        ]);
+        let violations: Vec<_> = report.violations().collect();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].cipher, TLSCipherSuite::TLS_AES_256_GCM_SHA384);
+        assert_eq!(
+            violations[0].reason,
+            "must be offered before TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384"
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn load_json() {
+        let policy = CustomPolicy::from_json_str(
+            r#"{
+                "name": "strict",
+                "allow": ["ECDHE-ECDSA-AES128-GCM-SHA256", "TLS_RSA_WITH_AES_128_CBC_SHA"],
+                "require_aead": true
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(policy.allow.len(), 2);
+        assert!(policy
+            .assess(TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256)
+            .is_approved());
+        assert_eq!(
+            policy
+                .assess(TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA)
+                .reference
+                .as_deref(),
+            Some("require_aead")
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn unknown_names() {
+        let err = CustomPolicy::from_toml_str(
+            r#"
+            deny = ["RC4-SHA", "RC4-SHA1", "AES128-SHA"]
+            order = ["TLS_AES_128_GCM_SHA512", "RC4-SHA1"]
+            "#,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            PolicyFileError::UnknownCipherSuites(vec![
+                "RC4-SHA1".to_owned(),
+                "TLS_AES_128_GCM_SHA512".to_owned(),
+            ])
+        );
+
+        let err = CustomPolicy::from_toml_str(r#"deny_mac = ["MD5"]"#).unwrap_err();
+        assert!(matches!(err, PolicyFileError::Syntax(_)));
+    }
+}
@@ -0,0 +1,221 @@
+use std::fmt;
+
+use crate::{grade_cipher_suites, Grading, TLSCipherSuite};
+
+/// A suite that both lists contain but that changed its place relative to
+/// the others.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Move {
+    pub cipher: TLSCipherSuite,
+    /// The suite's index in the old list.
+    pub from: usize,
+    /// The suite's index in the new list.
+    pub to: usize,
+}
+
+/// The difference between two cipher suite lists, as returned by
+/// `diff_cipher_suites`.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct CipherDiff {
+    /// Suites only the new list has, in its order.
+    pub added: Vec<TLSCipherSuite>,
+    /// Suites only the old list has, in its order.
+    pub removed: Vec<TLSCipherSuite>,
+    /// The fewest suites that have to move to turn the old order of the
+    /// common suites into the new one, in the new list's order.
+    pub moved: Vec<Move>,
+    /// The grade of each list, see `grade_cipher_suites`.
+    pub before: Grading,
+    pub after: Grading,
+}
+
+impl CipherDiff {
+    /// Returns true if both lists offer the same suites in the same order.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.moved.is_empty()
+    }
+}
+
+impl fmt::Display for CipherDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for cipher in &self.added {
+            writeln!(f, "+ {} ({})", cipher, cipher.as_openssl_name())?;
+        }
+        for cipher in &self.removed {
+            writeln!(f, "- {} ({})", cipher, cipher.as_openssl_name())?;
+        }
+        for m in &self.moved {
+            writeln!(
+                f,
+                "~ {} ({}) moved from position {} to {}",
+                m.cipher,
+                m.cipher.as_openssl_name(),
+                m.from + 1,
+                m.to + 1
+            )?;
+        }
+        if self.before.grade == self.after.grade {
+            writeln!(f, "grade: {} (unchanged)", self.after.grade)
+        } else {
+            writeln!(f, "grade: {} -> {}", self.before.grade, self.after.grade)?;
+            for cap in &self.after.caps {
+                if !self.before.caps.iter().any(|c| c.reason == cap.reason) {
+                    writeln!(f, "  now capped at {}: {}", cap.grade, cap.reason)?;
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Compares two cipher suite lists, for example the expansion of a cipher
+/// string before and after an upgrade.
+///
+/// A suite that appears more than once counts at its first position.
+///
+/// ```
+/// use openssl_to_rfc::{diff_cipher_suites, expand_openssl_cipher_string, Grade, TLSCipherSuite};
+///
+/// let old = expand_openssl_cipher_string("ECDHE+AESGCM:ECDHE+3DES:!ECDSA").unwrap();
+/// let new = expand_openssl_cipher_string("ECDHE+AES128:ECDHE+AES256:!ECDSA:!SHA1").unwrap();
+/// let diff = diff_cipher_suites(&old, &new);
+///
+/// assert_eq!(diff.removed, vec![TLSCipherSuite::TLS_ECDHE_RSA_WITH_3DES_EDE_CBC_SHA]);
+/// assert_eq!(
+///     diff.moved[0].cipher,
+///     TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384
+/// );
+/// assert_eq!((diff.before.grade, diff.after.grade), (Grade::C, Grade::A));
+/// ```
+pub fn diff_cipher_suites(old: &[TLSCipherSuite], new: &[TLSCipherSuite]) -> CipherDiff {
+    let old = dedup(old);
+    let new = dedup(new);
+    let position = |list: &[TLSCipherSuite], cipher| list.iter().position(|c| *c == cipher);
+
+    let added = new.iter().copied().filter(|c| !old.contains(c)).collect();
+    let removed = old.iter().copied().filter(|c| !new.contains(c)).collect();
+
+    // The common suites in new order, with their index in both lists. The
+    // ones on a longest run of increasing old indexes stay in place.
+    let common: Vec<_> = new
+        .iter()
+        .enumerate()
+        .filter_map(|(to, c)| {
+            position(&old, *c).map(|from| Move {
+                cipher: *c,
+                from,
+                to,
+            })
+        })
+        .collect();
+    let stay = longest_increasing(&common.iter().map(|m| m.from).collect::<Vec<_>>());
+    let moved = common
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !stay.contains(i))
+        .map(|(_, m)| *m)
+        .collect();
+
+    CipherDiff {
+        added,
+        removed,
+        moved,
+        before: grade_cipher_suites(&old),
+        after: grade_cipher_suites(&new),
+    }
+}
+
+fn dedup(ciphers: &[TLSCipherSuite]) -> Vec<TLSCipherSuite> {
+    let mut unique = Vec::with_capacity(ciphers.len());
+    for cipher in ciphers {
+        if !unique.contains(cipher) {
+            unique.push(*cipher);
+        }
+    }
+    unique
+}
+
+/// Returns the indexes of a longest strictly increasing subsequence.
+fn longest_increasing(values: &[usize]) -> Vec<usize> {
+    // length[i] is the length of the longest run ending at i, and previous[i]
+    // the index before i on that run.
+    let mut length = vec![1; values.len()];
+    let mut previous = vec![None; values.len()];
+    for i in 0..values.len() {
+        for j in 0..i {
+            if values[j] < values[i] && length[j] + 1 > length[i] {
+                length[i] = length[j] + 1;
+                previous[i] = Some(j);
+            }
+        }
+    }
+
+    let mut run = Vec::new();
+    let mut next = (0..values.len()).max_by_key(|i| (length[*i], std::cmp::Reverse(*i)));
+    while let Some(i) = next {
+        run.push(i);
+        next = previous[i];
+    }
+    run.reverse();
+    run
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn order_changes() {
+        use TLSCipherSuite::*;
+
+        let old = [
+            TLS_AES_256_GCM_SHA384,
+            TLS_CHACHA20_POLY1305_SHA256,
+            TLS_AES_128_GCM_SHA256,
+            TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+            TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+        ];
+        let new = [
+            TLS_AES_128_GCM_SHA256,
+            TLS_AES_256_GCM_SHA384,
+            TLS_CHACHA20_POLY1305_SHA256,
+            TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+            TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+            TLS_AES_256_GCM_SHA384,
+        ];
+        let diff = diff_cipher_suites(&old, &new);
+        assert!(diff.added.is_empty() && diff.removed.is_empty());
+        assert_eq!(
+            diff.moved,
+            vec![Move {
+                cipher: TLS_AES_128_GCM_SHA256,
+                from: 2,
+                to: 0
+            }]
+        );
+        assert!(!diff.is_empty());
+        assert_eq!(
+            diff.to_string(),
+            "~ TLS_AES_128_GCM_SHA256 (TLS_AES_128_GCM_SHA256) moved from position 3 to 1\n\
+             grade: A (unchanged)\n"
+        );
+
+        assert!(diff_cipher_suites(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn grade_change() {
+        let diff = diff_cipher_suites(
+            &[TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256],
+            &[
+                TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+                TLSCipherSuite::TLS_RSA_WITH_RC4_128_SHA,
+            ],
+        );
+        assert_eq!(
+            diff.to_string(),
+            "+ TLS_RSA_WITH_RC4_128_SHA (RC4-SHA)\n\
+             grade: A -> C\n  now capped at C: RC4 is insecure\n"
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use strum_macros::{Display, EnumIter, EnumString, IntoStaticStr};
+
+/// A TLS extension type, named as in the IANA TLS ExtensionType Values
+/// registry.
+///
+/// `Extension::known_type` resolves the raw type of a parsed extension.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Display, EnumIter, EnumString, IntoStaticStr)]
+pub enum ExtensionType {
+    server_name,
+    max_fragment_length,
+    client_certificate_url,
+    trusted_ca_keys,
+    truncated_hmac,
+    status_request,
+    user_mapping,
+    client_authz,
+    server_authz,
+    cert_type,
+    supported_groups,
+    ec_point_formats,
+    srp,
+    signature_algorithms,
+    use_srtp,
+    heartbeat,
+    application_layer_protocol_negotiation,
+    status_request_v2,
+    signed_certificate_timestamp,
+    client_certificate_type,
+    server_certificate_type,
+    padding,
+    encrypt_then_mac,
+    extended_master_secret,
+    token_binding,
+    cached_info,
+    tls_lts,
+    compress_certificate,
+    record_size_limit,
+    pwd_protect,
+    pwd_clear,
+    password_salt,
+    ticket_pinning,
+    tls_cert_with_extern_psk,
+    delegated_credential,
+    session_ticket,
+    TLMSP,
+    TLMSP_proxying,
+    TLMSP_delegate,
+    supported_ekt_ciphers,
+    pre_shared_key,
+    early_data,
+    supported_versions,
+    cookie,
+    psk_key_exchange_modes,
+    certificate_authorities,
+    oid_filters,
+    post_handshake_auth,
+    signature_algorithms_cert,
+    key_share,
+    transparency_info,
+    connection_id,
+    external_id_hash,
+    external_session_id,
+    quic_transport_parameters,
+    ticket_request,
+    dnssec_chain,
+    sequence_number_encryption_algorithms,
+    rrc,
+    ech_outer_extensions,
+    encrypted_client_hello,
+    renegotiation_info,
+}
+
+impl ExtensionType {
+    pub fn from_u16(value: u16) -> Option<ExtensionType> {
+        match value {
+            0x0000 => Some(Self::server_name),
+            0x0001 => Some(Self::max_fragment_length),
+            0x0002 => Some(Self::client_certificate_url),
+            0x0003 => Some(Self::trusted_ca_keys),
+            0x0004 => Some(Self::truncated_hmac),
+            0x0005 => Some(Self::status_request),
+            0x0006 => Some(Self::user_mapping),
+            0x0007 => Some(Self::client_authz),
+            0x0008 => Some(Self::server_authz),
+            0x0009 => Some(Self::cert_type),
+            0x000A => Some(Self::supported_groups),
+            0x000B => Some(Self::ec_point_formats),
+            0x000C => Some(Self::srp),
+            0x000D => Some(Self::signature_algorithms),
+            0x000E => Some(Self::use_srtp),
+            0x000F => Some(Self::heartbeat),
+            0x0010 => Some(Self::application_layer_protocol_negotiation),
+            0x0011 => Some(Self::status_request_v2),
+            0x0012 => Some(Self::signed_certificate_timestamp),
+            0x0013 => Some(Self::client_certificate_type),
+            0x0014 => Some(Self::server_certificate_type),
+            0x0015 => Some(Self::padding),
+            0x0016 => Some(Self::encrypt_then_mac),
+            0x0017 => Some(Self::extended_master_secret),
+            0x0018 => Some(Self::token_binding),
+            0x0019 => Some(Self::cached_info),
+            0x001A => Some(Self::tls_lts),
+            0x001B => Some(Self::compress_certificate),
+            0x001C => Some(Self::record_size_limit),
+            0x001D => Some(Self::pwd_protect),
+            0x001E => Some(Self::pwd_clear),
+            0x001F => Some(Self::password_salt),
+            0x0020 => Some(Self::ticket_pinning),
+            0x0021 => Some(Self::tls_cert_with_extern_psk),
+            0x0022 => Some(Self::delegated_credential),
+            0x0023 => Some(Self::session_ticket),
+            0x0024 => Some(Self::TLMSP),
+            0x0025 => Some(Self::TLMSP_proxying),
+            0x0026 => Some(Self::TLMSP_delegate),
+            0x0027 => Some(Self::supported_ekt_ciphers),
+            0x0029 => Some(Self::pre_shared_key),
+            0x002A => Some(Self::early_data),
+            0x002B => Some(Self::supported_versions),
+            0x002C => Some(Self::cookie),
+            0x002D => Some(Self::psk_key_exchange_modes),
+            0x002F => Some(Self::certificate_authorities),
+            0x0030 => Some(Self::oid_filters),
+            0x0031 => Some(Self::post_handshake_auth),
+            0x0032 => Some(Self::signature_algorithms_cert),
+            0x0033 => Some(Self::key_share),
+            0x0034 => Some(Self::transparency_info),
+            0x0036 => Some(Self::connection_id),
+            0x0037 => Some(Self::external_id_hash),
+            0x0038 => Some(Self::external_session_id),
+            0x0039 => Some(Self::quic_transport_parameters),
+            0x003A => Some(Self::ticket_request),
+            0x003B => Some(Self::dnssec_chain),
+            0x003C => Some(Self::sequence_number_encryption_algorithms),
+            0x003D => Some(Self::rrc),
+            0xFD00 => Some(Self::ech_outer_extensions),
+            0xFE0D => Some(Self::encrypted_client_hello),
+            0xFF01 => Some(Self::renegotiation_info),
+            _ => None,
+        }
+    }
+
+    pub fn as_u16(&self) -> u16 {
+        match self {
+            Self::server_name => 0x0000,
+            Self::max_fragment_length => 0x0001,
+            Self::client_certificate_url => 0x0002,
+            Self::trusted_ca_keys => 0x0003,
+            Self::truncated_hmac => 0x0004,
+            Self::status_request => 0x0005,
+            Self::user_mapping => 0x0006,
+            Self::client_authz => 0x0007,
+            Self::server_authz => 0x0008,
+            Self::cert_type => 0x0009,
+            Self::supported_groups => 0x000A,
+            Self::ec_point_formats => 0x000B,
+            Self::srp => 0x000C,
+            Self::signature_algorithms => 0x000D,
+            Self::use_srtp => 0x000E,
+            Self::heartbeat => 0x000F,
+            Self::application_layer_protocol_negotiation => 0x0010,
+            Self::status_request_v2 => 0x0011,
+            Self::signed_certificate_timestamp => 0x0012,
+            Self::client_certificate_type => 0x0013,
+            Self::server_certificate_type => 0x0014,
+            Self::padding => 0x0015,
+            Self::encrypt_then_mac => 0x0016,
+            Self::extended_master_secret => 0x0017,
+            Self::token_binding => 0x0018,
+            Self::cached_info => 0x0019,
+            Self::tls_lts => 0x001A,
+            Self::compress_certificate => 0x001B,
+            Self::record_size_limit => 0x001C,
+            Self::pwd_protect => 0x001D,
+            Self::pwd_clear => 0x001E,
+            Self::password_salt => 0x001F,
+            Self::ticket_pinning => 0x0020,
+            Self::tls_cert_with_extern_psk => 0x0021,
+            Self::delegated_credential => 0x0022,
+            Self::session_ticket => 0x0023,
+            Self::TLMSP => 0x0024,
+            Self::TLMSP_proxying => 0x0025,
+            Self::TLMSP_delegate => 0x0026,
+            Self::supported_ekt_ciphers => 0x0027,
+            Self::pre_shared_key => 0x0029,
+            Self::early_data => 0x002A,
+            Self::supported_versions => 0x002B,
+            Self::cookie => 0x002C,
+            Self::psk_key_exchange_modes => 0x002D,
+            Self::certificate_authorities => 0x002F,
+            Self::oid_filters => 0x0030,
+            Self::post_handshake_auth => 0x0031,
+            Self::signature_algorithms_cert => 0x0032,
+            Self::key_share => 0x0033,
+            Self::transparency_info => 0x0034,
+            Self::connection_id => 0x0036,
+            Self::external_id_hash => 0x0037,
+            Self::external_session_id => 0x0038,
+            Self::quic_transport_parameters => 0x0039,
+            Self::ticket_request => 0x003A,
+            Self::dnssec_chain => 0x003B,
+            Self::sequence_number_encryption_algorithms => 0x003C,
+            Self::rrc => 0x003D,
+            Self::ech_outer_extensions => 0xFD00,
+            Self::encrypted_client_hello => 0xFE0D,
+            Self::renegotiation_info => 0xFF01,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn registry_names() {
+        assert_eq!(
+            ExtensionType::from_u16(0x002B),
+            Some(ExtensionType::supported_versions)
+        );
+        assert_eq!(
+            ExtensionType::from_u16(0xFF01).unwrap().to_string(),
+            "renegotiation_info"
+        );
+        assert_eq!(
+            ExtensionType::from_str("application_layer_protocol_negotiation"),
+            Ok(ExtensionType::application_layer_protocol_negotiation)
+        );
+        // GREASE and unassigned values are not known.
+        assert_eq!(ExtensionType::from_u16(0x0A0A), None);
+        assert_eq!(ExtensionType::from_u16(0x0035), None);
+    }
+}
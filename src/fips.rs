@@ -0,0 +1,100 @@
+use crate::components::{Authentication, Cipher, CipherMode, KeyExchange, Mac};
+use crate::TLSCipherSuite;
+
+impl TLSCipherSuite {
+    /// Returns whether every algorithm of the suite is FIPS 140 approved.
+    ///
+    /// That means AES in GCM, CCM or CBC mode, with a SHA-2 MAC for CBC, and
+    /// ephemeral (EC)DH key agreement authenticated by RSA or ECDSA. TLS 1.3
+    /// suites only need an AES cipher. Key transport with RSA, DSA
+    /// signatures and ciphers such as ChaCha20, Camellia, ARIA, SEED, GOST
+    /// and RC4 are not approved.
+    ///
+    /// Whether a connection is FIPS compliant also depends on the group and
+    /// certificate it ends up using, which the suite does not determine.
+    ///
+    /// ```
+    /// use openssl_to_rfc::TLSCipherSuite;
+    ///
+    /// assert!(TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256.is_fips_approved());
+    /// assert!(!TLSCipherSuite::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256.is_fips_approved());
+    /// ```
+    pub fn is_fips_approved(&self) -> bool {
+        if self.is_signaling() {
+            return false;
+        }
+
+        let c = self.components();
+        let cipher = c.encryption.cipher == Cipher::Aes
+            && matches!(
+                c.encryption.mode,
+                CipherMode::Gcm | CipherMode::Ccm | CipherMode::Ccm8 | CipherMode::Cbc
+            );
+        let mac = matches!(c.mac, Mac::Aead | Mac::Sha256 | Mac::Sha384);
+        let key_exchange = matches!(
+            (c.key_exchange, c.authentication),
+            (KeyExchange::Any, _)
+                | (
+                    KeyExchange::Dhe | KeyExchange::Ecdhe,
+                    Authentication::Rsa | Authentication::Ecdsa
+                )
+        );
+        cipher && mac && key_exchange
+    }
+}
+
+/// Returns the FIPS approved suites of a list, keeping their order.
+///
+/// Combined with `expand_openssl_cipher_string`, this narrows an OpenSSL
+/// cipher string down to what a FIPS deployment may offer:
+///
+/// ```
+/// use openssl_to_rfc::{expand_openssl_cipher_string, fips_approved, TLSCipherSuite};
+///
+/// let ciphers = expand_openssl_cipher_string("ECDHE+AESGCM:ECDHE+CHACHA20").unwrap();
+/// assert_eq!(
+///     fips_approved(&ciphers),
+///     vec![
+///         TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+///         TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+///         TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+///         TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+///     ]
+/// );
+/// ```
+pub fn fips_approved(ciphers: &[TLSCipherSuite]) -> Vec<TLSCipherSuite> {
+    ciphers
+        .iter()
+        .copied()
+        .filter(TLSCipherSuite::is_fips_approved)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn approved_suites() {
+        let approved: Vec<_> = TLSCipherSuite::iter()
+            .filter(TLSCipherSuite::is_fips_approved)
+            .collect();
+        assert!(approved.contains(&TLSCipherSuite::TLS_AES_128_CCM_8_SHA256));
+        assert!(approved.contains(&TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_CCM));
+        assert!(approved.contains(&TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA256));
+
+        for cipher in [
+            TLSCipherSuite::TLS_CHACHA20_POLY1305_SHA256,
+            TLSCipherSuite::TLS_RSA_WITH_AES_128_GCM_SHA256,
+            TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA,
+            TLSCipherSuite::TLS_DHE_DSS_WITH_AES_128_GCM_SHA256,
+            TLSCipherSuite::TLS_DH_anon_WITH_AES_128_GCM_SHA256,
+            TLSCipherSuite::TLS_ECDHE_RSA_WITH_ARIA_128_GCM_SHA256,
+            TLSCipherSuite::TLS_ECDHE_PSK_WITH_AES_128_CBC_SHA256,
+            TLSCipherSuite::TLS_EMPTY_RENEGOTIATION_INFO_SCSV,
+        ] {
+            assert!(!approved.contains(&cipher), "{}", cipher);
+        }
+    }
+}
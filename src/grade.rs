@@ -0,0 +1,268 @@
+use std::fmt;
+
+use crate::components::{Authentication, Cipher, KeyExchange};
+use crate::TLSCipherSuite;
+
+/// A letter grade, `A` being the best.
+///
+/// Grades order from best to worst, so the worse of two grades is the
+/// greater one.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Grade {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+}
+
+impl Grade {
+    /// The grade the SSL Labs rating guide gives a numerical score.
+    pub fn from_score(score: u8) -> Grade {
+        match score {
+            80.. => Grade::A,
+            65..=79 => Grade::B,
+            50..=64 => Grade::C,
+            35..=49 => Grade::D,
+            20..=34 => Grade::E,
+            _ => Grade::F,
+        }
+    }
+}
+
+impl fmt::Display for Grade {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::A => "A",
+            Self::B => "B",
+            Self::C => "C",
+            Self::D => "D",
+            Self::E => "E",
+            Self::F => "F",
+        };
+        f.pad(s)
+    }
+}
+
+/// A rule that limits the grade regardless of the score.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GradeCap {
+    /// The best grade the configuration can get.
+    pub grade: Grade,
+    pub reason: &'static str,
+    /// The suites that trigger the cap. This is empty for caps that are
+    /// about something missing from the list.
+    pub ciphers: Vec<TLSCipherSuite>,
+}
+
+/// The result of `grade_cipher_suites`.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grading {
+    /// The security strength of the strongest and the weakest suite, in
+    /// bits.
+    pub strongest_bits: u16,
+    pub weakest_bits: u16,
+    /// The cipher strength score from 0 to 100, the average of the scores
+    /// of the strongest and the weakest suite.
+    pub score: u8,
+    /// The caps that apply, in the order the rating guide lists them.
+    pub caps: Vec<GradeCap>,
+    /// The grade of the score, lowered by the worst cap.
+    pub grade: Grade,
+}
+
+impl fmt::Display for Grading {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} (cipher strength score {}, {} to {} bits)",
+            self.grade, self.score, self.weakest_bits, self.strongest_bits
+        )?;
+        for cap in &self.caps {
+            write!(f, "  capped at {}: {}", cap.grade, cap.reason)?;
+            for (i, cipher) in cap.ciphers.iter().enumerate() {
+                f.write_str(if i == 0 { " (" } else { ", " })?;
+                write!(f, "{}", cipher)?;
+            }
+            if !cap.ciphers.is_empty() {
+                f.write_str(")")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// The score the rating guide gives a suite of the given strength.
+fn strength_score(bits: u16) -> u8 {
+    match bits {
+        0 => 0,
+        1..=127 => 20,
+        128..=255 => 80,
+        _ => 100,
+    }
+}
+
+/// Grades a server's cipher suites the way the cipher strength part of the
+/// SSL Labs rating guide does.
+///
+/// The score is the average of the scores of the strongest and the weakest
+/// suite: 0 for no encryption, 20 below 128 bits, 80 below 256 bits and 100
+/// otherwise. The grade of the score is then capped:
+///
+/// - at F for NULL encryption, anonymous key exchange or export suites,
+/// - at C for RC4, for 3DES and for other ciphers weaker than 112 bits,
+/// - at B if no suite offers forward secrecy.
+///
+/// The protocol and key exchange parts of the guide depend on the server's
+/// protocol versions and certificate, which a suite list does not show. The
+/// RC4 and 3DES caps assume the server also enables TLS 1.1 or later. An
+/// empty list grades F.
+///
+/// ```
+/// use openssl_to_rfc::{grade_cipher_suites, Grade, TLSCipherSuite};
+///
+/// let grading = grade_cipher_suites(&[
+///     TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+///     TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+///     TLSCipherSuite::TLS_RSA_WITH_3DES_EDE_CBC_SHA,
+/// ]);
+/// assert_eq!(grading.score, 60);
+/// assert_eq!(grading.grade, Grade::C);
+/// assert_eq!(grading.caps[0].reason, "3DES is vulnerable to Sweet32");
+/// ```
+pub fn grade_cipher_suites(ciphers: &[TLSCipherSuite]) -> Grading {
+    let ciphers: Vec<_> = ciphers
+        .iter()
+        .copied()
+        .filter(|c| !c.is_signaling())
+        .collect();
+
+    let bits = ciphers
+        .iter()
+        .map(|c| c.components().encryption.strength_bits());
+    let strongest_bits = bits.clone().max().unwrap_or(0);
+    let weakest_bits = bits.min().unwrap_or(0);
+    let score = ((u16::from(strength_score(strongest_bits))
+        + u16::from(strength_score(weakest_bits)))
+        / 2) as u8;
+
+    let mut caps = Vec::new();
+    let mut cap = |grade, reason, matches: &dyn Fn(TLSCipherSuite) -> bool| {
+        let matching: Vec<_> = ciphers.iter().copied().filter(|c| matches(*c)).collect();
+        if !matching.is_empty() {
+            caps.push(GradeCap {
+                grade,
+                reason,
+                ciphers: matching,
+            });
+        }
+    };
+    cap(Grade::F, "no encryption", &|c| {
+        c.components().encryption.cipher == Cipher::Null
+    });
+    cap(Grade::F, "anonymous key exchange", &|c| {
+        let c = c.components();
+        c.authentication == Authentication::Null && c.key_exchange != KeyExchange::Null
+    });
+    cap(Grade::F, "export-grade suite", &|c| c.components().export);
+    cap(Grade::C, "RC4 is insecure", &|c| {
+        c.components().encryption.cipher == Cipher::Rc4
+    });
+    cap(Grade::C, "3DES is vulnerable to Sweet32", &|c| {
+        c.components().encryption.cipher == Cipher::TripleDes
+    });
+    cap(Grade::C, "cipher weaker than 112 bits", &|c| {
+        let c = c.components();
+        !c.export
+            && !matches!(c.encryption.cipher, Cipher::Null | Cipher::Rc4)
+            && c.encryption.strength_bits() < 112
+    });
+    if !ciphers.is_empty() && !ciphers.iter().any(|c| c.components().forward_secrecy()) {
+        caps.push(GradeCap {
+            grade: Grade::B,
+            reason: "no forward secrecy",
+            ciphers: Vec::new(),
+        });
+    }
+
+    let grade = caps
+        .iter()
+        .map(|cap| cap.grade)
+        .fold(Grade::from_score(score), Grade::max);
+    Grading {
+        strongest_bits,
+        weakest_bits,
+        score,
+        caps,
+        grade,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grades() {
+        let grade = |ciphers: &[TLSCipherSuite]| grade_cipher_suites(ciphers).grade;
+        assert_eq!(grade(&[]), Grade::F);
+        assert_eq!(
+            grade(&[
+                TLSCipherSuite::TLS_AES_256_GCM_SHA384,
+                TLSCipherSuite::TLS_AES_128_GCM_SHA256,
+                TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+            ]),
+            Grade::A
+        );
+        assert_eq!(
+            grade(&[
+                TLSCipherSuite::TLS_RSA_WITH_AES_256_GCM_SHA384,
+                TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA,
+            ]),
+            Grade::B
+        );
+        assert_eq!(
+            grade(&[
+                TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+                TLSCipherSuite::TLS_RSA_WITH_RC4_128_SHA,
+            ]),
+            Grade::C
+        );
+        assert_eq!(
+            grade(&[
+                TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+                TLSCipherSuite::TLS_RSA_EXPORT_WITH_RC4_40_MD5,
+            ]),
+            Grade::F
+        );
+        assert_eq!(
+            grade(&[TLSCipherSuite::TLS_EMPTY_RENEGOTIATION_INFO_SCSV]),
+            Grade::F
+        );
+    }
+
+    #[test]
+    fn breakdown() {
+        let grading = grade_cipher_suites(&[
+            TLSCipherSuite::TLS_AES_256_GCM_SHA384,
+            TLSCipherSuite::TLS_DHE_RSA_WITH_DES_CBC_SHA,
+            TLSCipherSuite::TLS_DH_anon_WITH_AES_128_CBC_SHA,
+        ]);
+        assert_eq!((grading.strongest_bits, grading.weakest_bits), (256, 56));
+        assert_eq!(grading.score, 60);
+        assert_eq!(
+            grading
+                .caps
+                .iter()
+                .map(|cap| (cap.grade, cap.reason))
+                .collect::<Vec<_>>(),
+            vec![
+                (Grade::F, "anonymous key exchange"),
+                (Grade::C, "cipher weaker than 112 bits"),
+            ]
+        );
+        assert_eq!(grading.grade, Grade::F);
+    }
+}
@@ -0,0 +1,489 @@
+use std::fmt;
+
+use strum::IntoEnumIterator;
+use strum_macros::{Display, EnumIter, EnumString, IntoStaticStr};
+
+/// A TLS named group (formerly "elliptic curve"), named as in the IANA TLS
+/// Supported Groups registry.
+///
+/// `as_openssl_name` returns OpenSSL's short name for the group, e.g.
+/// `prime256v1` for `secp256r1`. `from_openssl_name` also accepts the NIST
+/// names (`P-256`) and the IANA names that OpenSSL 3 uses in `-groups`.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Display, EnumIter, EnumString, IntoStaticStr)]
+pub enum NamedGroup {
+    sect163k1,
+    sect163r1,
+    sect163r2,
+    sect193r1,
+    sect193r2,
+    sect233k1,
+    sect233r1,
+    sect239k1,
+    sect283k1,
+    sect283r1,
+    sect409k1,
+    sect409r1,
+    sect571k1,
+    sect571r1,
+    secp160k1,
+    secp160r1,
+    secp160r2,
+    secp192k1,
+    secp192r1,
+    secp224k1,
+    secp224r1,
+    secp256k1,
+    secp256r1,
+    secp384r1,
+    secp521r1,
+    brainpoolP256r1,
+    brainpoolP384r1,
+    brainpoolP512r1,
+    x25519,
+    x448,
+    brainpoolP256r1tls13,
+    brainpoolP384r1tls13,
+    brainpoolP512r1tls13,
+    GC256A,
+    GC256B,
+    GC256C,
+    GC256D,
+    GC512A,
+    GC512B,
+    GC512C,
+    curveSM2,
+    ffdhe2048,
+    ffdhe3072,
+    ffdhe4096,
+    ffdhe6144,
+    ffdhe8192,
+    MLKEM512,
+    MLKEM768,
+    MLKEM1024,
+    SecP256r1MLKEM768,
+    X25519MLKEM768,
+    SecP384r1MLKEM1024,
+    X25519Kyber768Draft00,
+    SecP256r1Kyber768Draft00,
+    arbitrary_explicit_prime_curves,
+    arbitrary_explicit_char2_curves,
+}
+
+impl NamedGroup {
+    pub fn from_openssl_name(name: &str) -> Option<NamedGroup> {
+        match name {
+            "sect163k1" | "K-163" => Some(Self::sect163k1),
+            "sect163r1" => Some(Self::sect163r1),
+            "sect163r2" | "B-163" => Some(Self::sect163r2),
+            "sect193r1" => Some(Self::sect193r1),
+            "sect193r2" => Some(Self::sect193r2),
+            "sect233k1" | "K-233" => Some(Self::sect233k1),
+            "sect233r1" | "B-233" => Some(Self::sect233r1),
+            "sect239k1" => Some(Self::sect239k1),
+            "sect283k1" | "K-283" => Some(Self::sect283k1),
+            "sect283r1" | "B-283" => Some(Self::sect283r1),
+            "sect409k1" | "K-409" => Some(Self::sect409k1),
+            "sect409r1" | "B-409" => Some(Self::sect409r1),
+            "sect571k1" | "K-571" => Some(Self::sect571k1),
+            "sect571r1" | "B-571" => Some(Self::sect571r1),
+            "secp160k1" => Some(Self::secp160k1),
+            "secp160r1" => Some(Self::secp160r1),
+            "secp160r2" => Some(Self::secp160r2),
+            "secp192k1" => Some(Self::secp192k1),
+            "prime192v1" | "P-192" | "secp192r1" => Some(Self::secp192r1),
+            "secp224k1" => Some(Self::secp224k1),
+            "secp224r1" | "P-224" => Some(Self::secp224r1),
+            "secp256k1" => Some(Self::secp256k1),
+            "prime256v1" | "P-256" | "secp256r1" => Some(Self::secp256r1),
+            "secp384r1" | "P-384" => Some(Self::secp384r1),
+            "secp521r1" | "P-521" => Some(Self::secp521r1),
+            "brainpoolP256r1" => Some(Self::brainpoolP256r1),
+            "brainpoolP384r1" => Some(Self::brainpoolP384r1),
+            "brainpoolP512r1" => Some(Self::brainpoolP512r1),
+            "X25519" | "x25519" => Some(Self::x25519),
+            "X448" | "x448" => Some(Self::x448),
+            "brainpoolP256r1tls13" => Some(Self::brainpoolP256r1tls13),
+            "brainpoolP384r1tls13" => Some(Self::brainpoolP384r1tls13),
+            "brainpoolP512r1tls13" => Some(Self::brainpoolP512r1tls13),
+            "id-tc26-gost-3410-2012-256-paramSetA" | "GC256A" => Some(Self::GC256A),
+            "id-tc26-gost-3410-2012-256-paramSetB" | "GC256B" => Some(Self::GC256B),
+            "id-tc26-gost-3410-2012-256-paramSetC" | "GC256C" => Some(Self::GC256C),
+            "id-tc26-gost-3410-2012-256-paramSetD" | "GC256D" => Some(Self::GC256D),
+            "id-tc26-gost-3410-2012-512-paramSetA" | "GC512A" => Some(Self::GC512A),
+            "id-tc26-gost-3410-2012-512-paramSetB" | "GC512B" => Some(Self::GC512B),
+            "id-tc26-gost-3410-2012-512-paramSetC" | "GC512C" => Some(Self::GC512C),
+            "SM2" | "curveSM2" => Some(Self::curveSM2),
+            "ffdhe2048" => Some(Self::ffdhe2048),
+            "ffdhe3072" => Some(Self::ffdhe3072),
+            "ffdhe4096" => Some(Self::ffdhe4096),
+            "ffdhe6144" => Some(Self::ffdhe6144),
+            "ffdhe8192" => Some(Self::ffdhe8192),
+            "MLKEM512" => Some(Self::MLKEM512),
+            "MLKEM768" => Some(Self::MLKEM768),
+            "MLKEM1024" => Some(Self::MLKEM1024),
+            "SecP256r1MLKEM768" => Some(Self::SecP256r1MLKEM768),
+            "X25519MLKEM768" => Some(Self::X25519MLKEM768),
+            "SecP384r1MLKEM1024" => Some(Self::SecP384r1MLKEM1024),
+            "X25519Kyber768Draft00" => Some(Self::X25519Kyber768Draft00),
+            "SecP256r1Kyber768Draft00" => Some(Self::SecP256r1Kyber768Draft00),
+            "arbitrary_explicit_prime_curves" => Some(Self::arbitrary_explicit_prime_curves),
+            "arbitrary_explicit_char2_curves" => Some(Self::arbitrary_explicit_char2_curves),
+            _ => None,
+        }
+    }
+
+    pub fn as_openssl_name(&self) -> String {
+        let s = match self {
+            Self::sect163k1 => "sect163k1",
+            Self::sect163r1 => "sect163r1",
+            Self::sect163r2 => "sect163r2",
+            Self::sect193r1 => "sect193r1",
+            Self::sect193r2 => "sect193r2",
+            Self::sect233k1 => "sect233k1",
+            Self::sect233r1 => "sect233r1",
+            Self::sect239k1 => "sect239k1",
+            Self::sect283k1 => "sect283k1",
+            Self::sect283r1 => "sect283r1",
+            Self::sect409k1 => "sect409k1",
+            Self::sect409r1 => "sect409r1",
+            Self::sect571k1 => "sect571k1",
+            Self::sect571r1 => "sect571r1",
+            Self::secp160k1 => "secp160k1",
+            Self::secp160r1 => "secp160r1",
+            Self::secp160r2 => "secp160r2",
+            Self::secp192k1 => "secp192k1",
+            Self::secp192r1 => "prime192v1",
+            Self::secp224k1 => "secp224k1",
+            Self::secp224r1 => "secp224r1",
+            Self::secp256k1 => "secp256k1",
+            Self::secp256r1 => "prime256v1",
+            Self::secp384r1 => "secp384r1",
+            Self::secp521r1 => "secp521r1",
+            Self::brainpoolP256r1 => "brainpoolP256r1",
+            Self::brainpoolP384r1 => "brainpoolP384r1",
+            Self::brainpoolP512r1 => "brainpoolP512r1",
+            Self::x25519 => "X25519",
+            Self::x448 => "X448",
+            Self::brainpoolP256r1tls13 => "brainpoolP256r1tls13",
+            Self::brainpoolP384r1tls13 => "brainpoolP384r1tls13",
+            Self::brainpoolP512r1tls13 => "brainpoolP512r1tls13",
+            Self::GC256A => "id-tc26-gost-3410-2012-256-paramSetA",
+            Self::GC256B => "id-tc26-gost-3410-2012-256-paramSetB",
+            Self::GC256C => "id-tc26-gost-3410-2012-256-paramSetC",
+            Self::GC256D => "id-tc26-gost-3410-2012-256-paramSetD",
+            Self::GC512A => "id-tc26-gost-3410-2012-512-paramSetA",
+            Self::GC512B => "id-tc26-gost-3410-2012-512-paramSetB",
+            Self::GC512C => "id-tc26-gost-3410-2012-512-paramSetC",
+            Self::curveSM2 => "SM2",
+            Self::ffdhe2048 => "ffdhe2048",
+            Self::ffdhe3072 => "ffdhe3072",
+            Self::ffdhe4096 => "ffdhe4096",
+            Self::ffdhe6144 => "ffdhe6144",
+            Self::ffdhe8192 => "ffdhe8192",
+            Self::MLKEM512 => "MLKEM512",
+            Self::MLKEM768 => "MLKEM768",
+            Self::MLKEM1024 => "MLKEM1024",
+            Self::SecP256r1MLKEM768 => "SecP256r1MLKEM768",
+            Self::X25519MLKEM768 => "X25519MLKEM768",
+            Self::SecP384r1MLKEM1024 => "SecP384r1MLKEM1024",
+            Self::X25519Kyber768Draft00 => "X25519Kyber768Draft00",
+            Self::SecP256r1Kyber768Draft00 => "SecP256r1Kyber768Draft00",
+            Self::arbitrary_explicit_prime_curves => "arbitrary_explicit_prime_curves",
+            Self::arbitrary_explicit_char2_curves => "arbitrary_explicit_char2_curves",
+        };
+
+        s.to_owned()
+    }
+
+    pub fn from_u16(value: u16) -> Option<NamedGroup> {
+        match value {
+            0x0001 => Some(Self::sect163k1),
+            0x0002 => Some(Self::sect163r1),
+            0x0003 => Some(Self::sect163r2),
+            0x0004 => Some(Self::sect193r1),
+            0x0005 => Some(Self::sect193r2),
+            0x0006 => Some(Self::sect233k1),
+            0x0007 => Some(Self::sect233r1),
+            0x0008 => Some(Self::sect239k1),
+            0x0009 => Some(Self::sect283k1),
+            0x000A => Some(Self::sect283r1),
+            0x000B => Some(Self::sect409k1),
+            0x000C => Some(Self::sect409r1),
+            0x000D => Some(Self::sect571k1),
+            0x000E => Some(Self::sect571r1),
+            0x000F => Some(Self::secp160k1),
+            0x0010 => Some(Self::secp160r1),
+            0x0011 => Some(Self::secp160r2),
+            0x0012 => Some(Self::secp192k1),
+            0x0013 => Some(Self::secp192r1),
+            0x0014 => Some(Self::secp224k1),
+            0x0015 => Some(Self::secp224r1),
+            0x0016 => Some(Self::secp256k1),
+            0x0017 => Some(Self::secp256r1),
+            0x0018 => Some(Self::secp384r1),
+            0x0019 => Some(Self::secp521r1),
+            0x001A => Some(Self::brainpoolP256r1),
+            0x001B => Some(Self::brainpoolP384r1),
+            0x001C => Some(Self::brainpoolP512r1),
+            0x001D => Some(Self::x25519),
+            0x001E => Some(Self::x448),
+            0x001F => Some(Self::brainpoolP256r1tls13),
+            0x0020 => Some(Self::brainpoolP384r1tls13),
+            0x0021 => Some(Self::brainpoolP512r1tls13),
+            0x0022 => Some(Self::GC256A),
+            0x0023 => Some(Self::GC256B),
+            0x0024 => Some(Self::GC256C),
+            0x0025 => Some(Self::GC256D),
+            0x0026 => Some(Self::GC512A),
+            0x0027 => Some(Self::GC512B),
+            0x0028 => Some(Self::GC512C),
+            0x0029 => Some(Self::curveSM2),
+            0x0100 => Some(Self::ffdhe2048),
+            0x0101 => Some(Self::ffdhe3072),
+            0x0102 => Some(Self::ffdhe4096),
+            0x0103 => Some(Self::ffdhe6144),
+            0x0104 => Some(Self::ffdhe8192),
+            0x0200 => Some(Self::MLKEM512),
+            0x0201 => Some(Self::MLKEM768),
+            0x0202 => Some(Self::MLKEM1024),
+            0x11EB => Some(Self::SecP256r1MLKEM768),
+            0x11EC => Some(Self::X25519MLKEM768),
+            0x11ED => Some(Self::SecP384r1MLKEM1024),
+            0x6399 => Some(Self::X25519Kyber768Draft00),
+            0x639A => Some(Self::SecP256r1Kyber768Draft00),
+            0xFF01 => Some(Self::arbitrary_explicit_prime_curves),
+            0xFF02 => Some(Self::arbitrary_explicit_char2_curves),
+            _ => None,
+        }
+    }
+
+    pub fn as_u16(&self) -> u16 {
+        match self {
+            Self::sect163k1 => 0x0001,
+            Self::sect163r1 => 0x0002,
+            Self::sect163r2 => 0x0003,
+            Self::sect193r1 => 0x0004,
+            Self::sect193r2 => 0x0005,
+            Self::sect233k1 => 0x0006,
+            Self::sect233r1 => 0x0007,
+            Self::sect239k1 => 0x0008,
+            Self::sect283k1 => 0x0009,
+            Self::sect283r1 => 0x000A,
+            Self::sect409k1 => 0x000B,
+            Self::sect409r1 => 0x000C,
+            Self::sect571k1 => 0x000D,
+            Self::sect571r1 => 0x000E,
+            Self::secp160k1 => 0x000F,
+            Self::secp160r1 => 0x0010,
+            Self::secp160r2 => 0x0011,
+            Self::secp192k1 => 0x0012,
+            Self::secp192r1 => 0x0013,
+            Self::secp224k1 => 0x0014,
+            Self::secp224r1 => 0x0015,
+            Self::secp256k1 => 0x0016,
+            Self::secp256r1 => 0x0017,
+            Self::secp384r1 => 0x0018,
+            Self::secp521r1 => 0x0019,
+            Self::brainpoolP256r1 => 0x001A,
+            Self::brainpoolP384r1 => 0x001B,
+            Self::brainpoolP512r1 => 0x001C,
+            Self::x25519 => 0x001D,
+            Self::x448 => 0x001E,
+            Self::brainpoolP256r1tls13 => 0x001F,
+            Self::brainpoolP384r1tls13 => 0x0020,
+            Self::brainpoolP512r1tls13 => 0x0021,
+            Self::GC256A => 0x0022,
+            Self::GC256B => 0x0023,
+            Self::GC256C => 0x0024,
+            Self::GC256D => 0x0025,
+            Self::GC512A => 0x0026,
+            Self::GC512B => 0x0027,
+            Self::GC512C => 0x0028,
+            Self::curveSM2 => 0x0029,
+            Self::ffdhe2048 => 0x0100,
+            Self::ffdhe3072 => 0x0101,
+            Self::ffdhe4096 => 0x0102,
+            Self::ffdhe6144 => 0x0103,
+            Self::ffdhe8192 => 0x0104,
+            Self::MLKEM512 => 0x0200,
+            Self::MLKEM768 => 0x0201,
+            Self::MLKEM1024 => 0x0202,
+            Self::SecP256r1MLKEM768 => 0x11EB,
+            Self::X25519MLKEM768 => 0x11EC,
+            Self::SecP384r1MLKEM1024 => 0x11ED,
+            Self::X25519Kyber768Draft00 => 0x6399,
+            Self::SecP256r1Kyber768Draft00 => 0x639A,
+            Self::arbitrary_explicit_prime_curves => 0xFF01,
+            Self::arbitrary_explicit_char2_curves => 0xFF02,
+        }
+    }
+}
+
+/// The group list OpenSSL 3.5 and later use when none is configured.
+const OPENSSL_DEFAULT_GROUPS: &str = "?*X25519MLKEM768 / ?*X25519:?secp256r1 / \
+                                      ?X448:?secp384r1:?secp521r1 / ?ffdhe2048:?ffdhe3072";
+
+/// Error returned by `parse_openssl_groups` for a group it does not know.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseGroupsError {
+    pub group: String,
+}
+
+impl fmt::Display for ParseGroupsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown group: {:?}", self.group)
+    }
+}
+
+impl std::error::Error for ParseGroupsError {}
+
+/// Parses a group list as given to OpenSSL's `-curves`/`-groups` options,
+/// `SSL_CTX_set1_groups_list` or nginx's `ssl_ecdh_curve`, and returns the
+/// groups in preference order.
+///
+/// Names are matched case-insensitively. The OpenSSL 3.5 syntax is
+/// understood too: `/` separates tuples, a `*` prefix marks a group for a
+/// key share, a `?` prefix ignores the group if it is unknown, a `-` prefix
+/// removes it again and `DEFAULT` stands for OpenSSL's default list.
+///
+/// ```
+/// use openssl_to_rfc::{parse_openssl_groups, NamedGroup};
+///
+/// assert_eq!(
+///     parse_openssl_groups("X25519:P-256:prime256v1:?nonsense").unwrap(),
+///     vec![NamedGroup::x25519, NamedGroup::secp256r1]
+/// );
+/// ```
+pub fn parse_openssl_groups(list: &str) -> Result<Vec<NamedGroup>, ParseGroupsError> {
+    let mut groups = Vec::new();
+    let mut removed = Vec::new();
+
+    for token in list.split([':', '/']) {
+        let token = token.trim();
+        if token.is_empty() {
+            continue;
+        }
+        if token.eq_ignore_ascii_case("DEFAULT") {
+            for group in parse_openssl_groups(OPENSSL_DEFAULT_GROUPS)? {
+                if !groups.contains(&group) {
+                    groups.push(group);
+                }
+            }
+            continue;
+        }
+
+        let (remove, token) = match token.strip_prefix('-') {
+            Some(token) => (true, token),
+            None => (false, token),
+        };
+        let mut optional = false;
+        let mut name = token;
+        while let Some(rest) = name.strip_prefix(['?', '*']) {
+            optional |= name.starts_with('?');
+            name = rest;
+        }
+
+        let group = match find_group(name) {
+            Some(group) => group,
+            None if optional || remove => continue,
+            None => {
+                return Err(ParseGroupsError {
+                    group: name.to_owned(),
+                })
+            }
+        };
+        if remove {
+            removed.push(group);
+        } else if !groups.contains(&group) {
+            groups.push(group);
+        }
+    }
+
+    groups.retain(|group| !removed.contains(group));
+    Ok(groups)
+}
+
+fn find_group(name: &str) -> Option<NamedGroup> {
+    NamedGroup::from_openssl_name(name)
+        .or_else(|| NamedGroup::from_openssl_name(&name.to_ascii_uppercase()))
+        .or_else(|| {
+            NamedGroup::iter().find(|group| {
+                let iana: &str = group.into();
+                name.eq_ignore_ascii_case(iana)
+                    || name.eq_ignore_ascii_case(&group.as_openssl_name())
+            })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn group_lists() {
+        assert_eq!(
+            parse_openssl_groups("secp384r1:x25519").unwrap(),
+            vec![NamedGroup::secp384r1, NamedGroup::x25519]
+        );
+        assert_eq!(
+            parse_openssl_groups("p-521:FFDHE2048").unwrap(),
+            vec![NamedGroup::secp521r1, NamedGroup::ffdhe2048]
+        );
+        assert_eq!(
+            parse_openssl_groups("DEFAULT:-?X448:-ffdhe3072").unwrap(),
+            vec![
+                NamedGroup::X25519MLKEM768,
+                NamedGroup::x25519,
+                NamedGroup::secp256r1,
+                NamedGroup::secp384r1,
+                NamedGroup::secp521r1,
+                NamedGroup::ffdhe2048,
+            ]
+        );
+        assert_eq!(
+            parse_openssl_groups("X25519:bogus"),
+            Err(ParseGroupsError {
+                group: "bogus".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn openssl_aliases() {
+        for name in ["prime256v1", "P-256", "secp256r1"] {
+            assert_eq!(
+                NamedGroup::from_openssl_name(name),
+                Some(NamedGroup::secp256r1)
+            );
+        }
+        assert_eq!(NamedGroup::secp256r1.as_openssl_name(), "prime256v1");
+        assert_eq!(NamedGroup::x25519.as_u16(), 0x001D);
+        assert_eq!(NamedGroup::X25519MLKEM768.as_u16(), 0x11EC);
+    }
+
+    #[test]
+    fn gost_groups() {
+        for (group, code, name) in [
+            (
+                NamedGroup::GC256B,
+                0x0023,
+                "id-tc26-gost-3410-2012-256-paramSetB",
+            ),
+            (
+                NamedGroup::GC256C,
+                0x0024,
+                "id-tc26-gost-3410-2012-256-paramSetC",
+            ),
+            (
+                NamedGroup::GC256D,
+                0x0025,
+                "id-tc26-gost-3410-2012-256-paramSetD",
+            ),
+        ] {
+            assert_eq!(group.as_u16(), code);
+            assert_eq!(group.as_openssl_name(), name);
+            assert_eq!(NamedGroup::from_openssl_name(name), Some(group));
+        }
+    }
+}
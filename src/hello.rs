@@ -0,0 +1,444 @@
+use std::fmt;
+
+use crate::wire::{
+    decode_sslv2_cipher_specs, read_bytes, read_cipher_suites, read_u16, read_u8, read_vec16,
+    read_vec8,
+};
+use crate::{CipherSpec, CipherSuiteId, ExtensionType, ProtocolVersion, TLSCipherSuite, WireError};
+
+const CONTENT_TYPE_HANDSHAKE: u8 = 22;
+const HANDSHAKE_CLIENT_HELLO: u8 = 1;
+const HANDSHAKE_SERVER_HELLO: u8 = 2;
+const EXTENSION_SUPPORTED_VERSIONS: u16 = 0x002B;
+
+/// The `random` of a ServerHello that is really a HelloRetryRequest, the
+/// SHA-256 of "HelloRetryRequest" (RFC 8446, section 4.1.3).
+const HELLO_RETRY_REQUEST_RANDOM: [u8; 32] = [
+    0xCF, 0x21, 0xAD, 0x74, 0xE5, 0x9A, 0x61, 0x11, 0xBE, 0x1D, 0x8C, 0x02, 0x1E, 0x65, 0xB8, 0x91,
+    0xC2, 0xA2, 0x11, 0x16, 0x7A, 0xBB, 0x8C, 0x5E, 0x07, 0x9E, 0x09, 0xE2, 0xC8, 0xA8, 0x33, 0x9C,
+];
+
+/// A TLS extension as carried in a hello message.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Extension {
+    pub extension_type: u16,
+    pub data: Vec<u8>,
+}
+
+impl Extension {
+    /// Returns the `ExtensionType` for this extension, if it is known.
+    pub fn known_type(&self) -> Option<ExtensionType> {
+        ExtensionType::from_u16(self.extension_type)
+    }
+}
+
+/// A parsed ClientHello.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ClientHello {
+    pub legacy_version: u16,
+    pub random: [u8; 32],
+    pub session_id: Vec<u8>,
+    /// The offered suites in preference order, including any GREASE values.
+    pub cipher_suites: Vec<CipherSuiteId>,
+    pub compression_methods: Vec<u8>,
+    pub extensions: Vec<Extension>,
+}
+
+impl ClientHello {
+    /// Parses the body of a ClientHello handshake message, without the
+    /// four-byte handshake header.
+    pub fn parse(body: &[u8]) -> Result<ClientHello, WireError> {
+        let (legacy_version, rest) = read_u16(body)?;
+        let (random, rest) = read_bytes(rest, 32)?;
+        let (session_id, rest) = read_vec8(rest)?;
+        let (cipher_suites, rest) = read_cipher_suites(rest)?;
+        let (compression_methods, rest) = read_vec8(rest)?;
+        let extensions = read_extensions(rest)?;
+
+        let mut hello = ClientHello {
+            legacy_version,
+            random: [0; 32],
+            session_id: session_id.to_vec(),
+            cipher_suites,
+            compression_methods: compression_methods.to_vec(),
+            extensions,
+        };
+        hello.random.copy_from_slice(random);
+        Ok(hello)
+    }
+
+    /// Parses the ClientHello at the start of the bytes a client sent on a
+    /// connection, as TLS records or as an SSLv2-compatible CLIENT-HELLO.
+    ///
+    /// For SSLv2-compatible hellos only the TLS suites are kept, the
+    /// challenge is right-aligned in `random` and there are no extensions.
+    pub fn from_stream(stream: &[u8]) -> Result<ClientHello, WireError> {
+        match stream.first() {
+            Some(b) if b & 0x80 != 0 => parse_sslv2(stream),
+            _ => {
+                let (msg_type, body) = read_handshake(stream)?;
+                if msg_type != HANDSHAKE_CLIENT_HELLO {
+                    return Err(WireError::UnexpectedType(msg_type));
+                }
+                ClientHello::parse(&body)
+            }
+        }
+    }
+
+    /// Returns the extension of the given type, if present.
+    pub fn extension(&self, extension_type: u16) -> Option<&Extension> {
+        self.extensions
+            .iter()
+            .find(|e| e.extension_type == extension_type)
+    }
+}
+
+/// A parsed ServerHello or HelloRetryRequest.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ServerHello {
+    pub legacy_version: u16,
+    pub random: [u8; 32],
+    pub session_id: Vec<u8>,
+    pub cipher_suite: CipherSuiteId,
+    pub compression_method: u8,
+    pub extensions: Vec<Extension>,
+}
+
+impl ServerHello {
+    /// Parses the body of a ServerHello handshake message, without the
+    /// four-byte handshake header.
+    pub fn parse(body: &[u8]) -> Result<ServerHello, WireError> {
+        let (legacy_version, rest) = read_u16(body)?;
+        let (random, rest) = read_bytes(rest, 32)?;
+        let (session_id, rest) = read_vec8(rest)?;
+        let (cipher_suite, rest) = read_u16(rest)?;
+        let (compression_method, rest) = read_u8(rest)?;
+        let extensions = read_extensions(rest)?;
+
+        let mut hello = ServerHello {
+            legacy_version,
+            random: [0; 32],
+            session_id: session_id.to_vec(),
+            cipher_suite: CipherSuiteId(cipher_suite),
+            compression_method,
+            extensions,
+        };
+        hello.random.copy_from_slice(random);
+        Ok(hello)
+    }
+
+    /// Parses the ServerHello at the start of the bytes a server sent on a
+    /// connection.
+    pub fn from_stream(stream: &[u8]) -> Result<ServerHello, WireError> {
+        let (msg_type, body) = read_handshake(stream)?;
+        if msg_type != HANDSHAKE_SERVER_HELLO {
+            return Err(WireError::UnexpectedType(msg_type));
+        }
+        ServerHello::parse(&body)
+    }
+
+    /// Returns the extension of the given type, if present.
+    pub fn extension(&self, extension_type: u16) -> Option<&Extension> {
+        self.extensions
+            .iter()
+            .find(|e| e.extension_type == extension_type)
+    }
+
+    /// Returns true if this is a HelloRetryRequest, which TLS 1.3 sends in
+    /// the shape of a ServerHello with a fixed `random`.
+    pub fn is_hello_retry_request(&self) -> bool {
+        self.random == HELLO_RETRY_REQUEST_RANDOM
+    }
+
+    /// Returns the protocol version the server selected: the
+    /// `supported_versions` extension if present, otherwise
+    /// `legacy_version`.
+    pub fn selected_version(&self) -> u16 {
+        match self.extension(EXTENSION_SUPPORTED_VERSIONS) {
+            Some(Extension { data, .. }) if data.len() == 2 => {
+                u16::from_be_bytes([data[0], data[1]])
+            }
+            _ => self.legacy_version,
+        }
+    }
+
+    /// Resolves the negotiated suite and protocol version, checking that the
+    /// suite may be used with that version, see `TLSCipherSuite::usable_in`.
+    pub fn negotiated(&self) -> Result<Negotiated, NegotiationError> {
+        let version = self.selected_version();
+        let version =
+            ProtocolVersion::from_u16(version).ok_or(NegotiationError::UnknownVersion(version))?;
+        let cipher_suite = self
+            .cipher_suite
+            .known()
+            .ok_or(NegotiationError::UnknownCipherSuite(self.cipher_suite))?;
+
+        if !cipher_suite.usable_in(version) {
+            return Err(NegotiationError::NotAllowed {
+                cipher_suite,
+                version,
+            });
+        }
+
+        Ok(Negotiated {
+            cipher_suite,
+            version,
+            hello_retry_request: self.is_hello_retry_request(),
+        })
+    }
+}
+
+/// The outcome of a ServerHello, as returned by `ServerHello::negotiated`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Negotiated {
+    pub cipher_suite: TLSCipherSuite,
+    pub version: ProtocolVersion,
+    /// The message was a HelloRetryRequest, so the handshake is not
+    /// finished: the client will send a second ClientHello.
+    pub hello_retry_request: bool,
+}
+
+/// Error returned by `ServerHello::negotiated`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum NegotiationError {
+    UnknownCipherSuite(CipherSuiteId),
+    UnknownVersion(u16),
+    /// The suite cannot be used with the selected version.
+    NotAllowed {
+        cipher_suite: TLSCipherSuite,
+        version: ProtocolVersion,
+    },
+}
+
+impl fmt::Display for NegotiationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownCipherSuite(id) => write!(f, "unknown cipher suite {}", id),
+            Self::UnknownVersion(v) => write!(f, "unknown protocol version {:#06x}", v),
+            Self::NotAllowed {
+                cipher_suite,
+                version,
+            } => write!(f, "{} cannot be used with {}", cipher_suite, version),
+        }
+    }
+}
+
+impl std::error::Error for NegotiationError {}
+
+/// Reads the extensions block that ends a hello message. Hellos from before
+/// extensions existed simply stop, so an empty input yields no extensions.
+pub(crate) fn read_extensions(input: &[u8]) -> Result<Vec<Extension>, WireError> {
+    if input.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let (mut block, rest) = read_vec16(input)?;
+    if !rest.is_empty() {
+        return Err(WireError::TrailingData(rest.len()));
+    }
+
+    let mut extensions = Vec::new();
+    while !block.is_empty() {
+        let (extension_type, rest) = read_u16(block)?;
+        let (data, rest) = read_vec16(rest)?;
+        extensions.push(Extension {
+            extension_type,
+            data: data.to_vec(),
+        });
+        block = rest;
+    }
+    Ok(extensions)
+}
+
+/// Reassembles the first handshake message from a stream of TLS records and
+/// returns its type and body.
+pub(crate) fn read_handshake(mut stream: &[u8]) -> Result<(u8, Vec<u8>), WireError> {
+    let mut handshake = Vec::new();
+    loop {
+        let (content_type, rest) = read_u8(stream)?;
+        if content_type != CONTENT_TYPE_HANDSHAKE {
+            return Err(WireError::UnexpectedType(content_type));
+        }
+        let (_version, rest) = read_u16(rest)?;
+        let (fragment, rest) = read_vec16(rest)?;
+        handshake.extend_from_slice(fragment);
+        stream = rest;
+
+        if let [msg_type, a, b, c, body @ ..] = handshake.as_slice() {
+            let length = u32::from_be_bytes([0, *a, *b, *c]) as usize;
+            if body.len() >= length {
+                return Ok((*msg_type, body[..length].to_vec()));
+            }
+        }
+    }
+}
+
+fn parse_sslv2(stream: &[u8]) -> Result<ClientHello, WireError> {
+    let (length, rest) = read_u16(stream)?;
+    let (record, _) = read_bytes(rest, usize::from(length & 0x7FFF))?;
+
+    let (msg_type, rest) = read_u8(record)?;
+    if msg_type != HANDSHAKE_CLIENT_HELLO {
+        return Err(WireError::UnexpectedType(msg_type));
+    }
+    let (legacy_version, rest) = read_u16(rest)?;
+    let (specs_length, rest) = read_u16(rest)?;
+    let (session_id_length, rest) = read_u16(rest)?;
+    let (challenge_length, rest) = read_u16(rest)?;
+    let (specs, rest) = read_bytes(rest, usize::from(specs_length))?;
+    let (session_id, rest) = read_bytes(rest, usize::from(session_id_length))?;
+    let (challenge, _) = read_bytes(rest, usize::from(challenge_length))?;
+
+    let cipher_suites = decode_sslv2_cipher_specs(specs)?
+        .into_iter()
+        .filter_map(|spec| match spec {
+            CipherSpec::Tls(id) => Some(id),
+            _ => None,
+        })
+        .collect();
+
+    let mut random = [0; 32];
+    let challenge = &challenge[challenge.len().saturating_sub(32)..];
+    random[32 - challenge.len()..].copy_from_slice(challenge);
+
+    Ok(ClientHello {
+        legacy_version,
+        random,
+        session_id: session_id.to_vec(),
+        cipher_suites,
+        compression_methods: vec![0],
+        extensions: Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{encode_cipher_suites, SSLV2CipherSuite, TLSCipherSuite};
+
+    fn record(fragment: &[u8]) -> Vec<u8> {
+        let mut out = vec![CONTENT_TYPE_HANDSHAKE, 0x03, 0x01];
+        out.extend_from_slice(&(fragment.len() as u16).to_be_bytes());
+        out.extend_from_slice(fragment);
+        out
+    }
+
+    #[test]
+    fn client_hello_across_records() {
+        let mut body = vec![0x03, 0x03];
+        body.extend_from_slice(&[0x11; 32]);
+        body.push(0);
+        body.extend(encode_cipher_suites(&[CipherSuiteId(0x2A2A), CipherSuiteId(0x1301)]).unwrap());
+        body.extend_from_slice(&[0x01, 0x00]);
+        body.extend_from_slice(&[0x00, 0x07, 0x00, 0x2B, 0x00, 0x03, 0x02, 0x03, 0x04]);
+
+        let mut message = vec![HANDSHAKE_CLIENT_HELLO, 0, 0, body.len() as u8];
+        message.extend_from_slice(&body);
+        let (a, b) = message.split_at(10);
+        let mut stream = record(a);
+        stream.extend(record(b));
+
+        let hello = ClientHello::from_stream(&stream).unwrap();
+        assert_eq!(hello.legacy_version, 0x0303);
+        assert_eq!(
+            hello.cipher_suites,
+            [CipherSuiteId(0x2A2A), CipherSuiteId(0x1301)]
+        );
+        assert_eq!(hello.extension(0x002B).unwrap().data, [0x02, 0x03, 0x04]);
+
+        assert!(matches!(
+            ClientHello::from_stream(&stream[..stream.len() - 1]),
+            Err(WireError::Truncated { .. })
+        ));
+    }
+
+    #[test]
+    fn sslv2_compatible_client_hello() {
+        let specs = crate::encode_sslv2_cipher_specs(&[
+            CipherSpec::Ssl2(SSLV2CipherSuite::SSL_CK_RC4_128_WITH_MD5),
+            CipherSpec::Tls(TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA.into()),
+        ])
+        .unwrap();
+        let mut record = vec![HANDSHAKE_CLIENT_HELLO, 0x03, 0x01, 0x00, 0x06, 0x00, 0x00];
+        record.extend_from_slice(&[0x00, 0x10]);
+        record.extend_from_slice(&specs);
+        record.extend_from_slice(&[0x22; 16]);
+        let mut stream = vec![0x80 | (record.len() >> 8) as u8, record.len() as u8];
+        stream.extend_from_slice(&record);
+
+        let hello = ClientHello::from_stream(&stream).unwrap();
+        assert_eq!(hello.legacy_version, 0x0301);
+        assert_eq!(hello.cipher_suites, [CipherSuiteId(0x002F)]);
+        assert_eq!(hello.random[..16], [0; 16]);
+        assert_eq!(hello.random[16..], [0x22; 16]);
+    }
+
+    fn server_hello(random: [u8; 32], suite: u16, extensions: &[u8]) -> Vec<u8> {
+        let mut body = vec![0x03, 0x03];
+        body.extend_from_slice(&random);
+        body.push(0);
+        body.extend_from_slice(&suite.to_be_bytes());
+        body.push(0);
+        body.extend_from_slice(&(extensions.len() as u16).to_be_bytes());
+        body.extend_from_slice(extensions);
+        body
+    }
+
+    #[test]
+    fn server_hello_versions() {
+        let supported_versions = [0x00, 0x2B, 0x00, 0x02, 0x03, 0x04];
+
+        let hello =
+            ServerHello::parse(&server_hello([1; 32], 0x1301, &supported_versions)).unwrap();
+        assert_eq!(
+            hello.negotiated(),
+            Ok(Negotiated {
+                cipher_suite: TLSCipherSuite::TLS_AES_128_GCM_SHA256,
+                version: ProtocolVersion::Tls13,
+                hello_retry_request: false,
+            })
+        );
+
+        let hello = ServerHello::parse(&server_hello(
+            HELLO_RETRY_REQUEST_RANDOM,
+            0x1302,
+            &supported_versions,
+        ))
+        .unwrap();
+        assert!(hello.negotiated().unwrap().hello_retry_request);
+
+        let hello = ServerHello::parse(&server_hello([1; 32], 0xC02F, &[])).unwrap();
+        assert_eq!(hello.negotiated().unwrap().version, ProtocolVersion::Tls12);
+    }
+
+    #[test]
+    fn server_hello_mismatch() {
+        let hello = ServerHello::parse(&server_hello([1; 32], 0x1301, &[])).unwrap();
+        assert_eq!(
+            hello.negotiated(),
+            Err(NegotiationError::NotAllowed {
+                cipher_suite: TLSCipherSuite::TLS_AES_128_GCM_SHA256,
+                version: ProtocolVersion::Tls12,
+            })
+        );
+
+        let supported_versions = [0x00, 0x2B, 0x00, 0x02, 0x03, 0x04];
+        let hello =
+            ServerHello::parse(&server_hello([1; 32], 0xC02F, &supported_versions)).unwrap();
+        assert!(hello.negotiated().is_err());
+
+        let mut body = server_hello([1; 32], 0x009C, &[]);
+        body[1] = 0x02;
+        let hello = ServerHello::parse(&body).unwrap();
+        assert_eq!(
+            hello.negotiated().unwrap_err().to_string(),
+            "TLS_RSA_WITH_AES_128_GCM_SHA256 cannot be used with TLSv1.1"
+        );
+
+        let hello = ServerHello::parse(&server_hello([1; 32], 0x0A0A, &[])).unwrap();
+        assert_eq!(
+            hello.negotiated(),
+            Err(NegotiationError::UnknownCipherSuite(CipherSuiteId(0x0A0A)))
+        );
+    }
+}
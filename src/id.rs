@@ -0,0 +1,166 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::TLSCipherSuite;
+
+/// A raw cipher suite code point as it appears on the wire.
+///
+/// Unlike `TLSCipherSuite`, a `CipherSuiteId` can hold any 16-bit value,
+/// including GREASE values, private-use code points and assignments this
+/// crate does not know about yet. It formats the same way IANA and OpenSSL
+/// do, e.g. `0xC0,0x2F`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+pub struct CipherSuiteId(pub u16);
+
+impl CipherSuiteId {
+    /// Returns the `TLSCipherSuite` for this code point, if it is known.
+    pub fn known(&self) -> Option<TLSCipherSuite> {
+        TLSCipherSuite::from_u16(self.0)
+    }
+
+    /// Returns true for the GREASE values reserved by RFC 8701.
+    pub fn is_grease(&self) -> bool {
+        let [hi, lo] = self.0.to_be_bytes();
+        hi == lo && hi & 0x0F == 0x0A
+    }
+
+    /// Returns true for code points the IANA registry marks as reserved,
+    /// including GREASE values but excluding the private-use range.
+    ///
+    /// The registry also reserves `0x00,0x60-66`, but those are the
+    /// EXPORT1024 suites OpenSSL shipped, which `TLSCipherSuite` defines, so
+    /// they are not counted as reserved.
+    pub fn is_reserved(&self) -> bool {
+        self.is_grease()
+            || matches!(
+                self.0,
+                0x001C..=0x001D | 0x0047..=0x005C | 0xFEFE..=0xFEFF
+            )
+    }
+
+    /// Returns true for the `0xFF,0x00-FF` private-use range.
+    pub fn is_private_use(&self) -> bool {
+        self.0 >= 0xFF00
+    }
+
+    /// Returns true if the code point is neither known to this crate nor
+    /// reserved or set aside for private use.
+    pub fn is_unassigned(&self) -> bool {
+        self.known().is_none() && !self.is_reserved() && !self.is_private_use()
+    }
+}
+
+impl From<u16> for CipherSuiteId {
+    fn from(value: u16) -> Self {
+        CipherSuiteId(value)
+    }
+}
+
+impl From<CipherSuiteId> for u16 {
+    fn from(id: CipherSuiteId) -> Self {
+        id.0
+    }
+}
+
+impl From<TLSCipherSuite> for CipherSuiteId {
+    fn from(cipher: TLSCipherSuite) -> Self {
+        CipherSuiteId(cipher.as_u16())
+    }
+}
+
+impl fmt::Display for CipherSuiteId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [hi, lo] = self.0.to_be_bytes();
+        write!(f, "0x{:02X},0x{:02X}", hi, lo)
+    }
+}
+
+/// Error returned when a string is not a valid `0xHH,0xHH` code point.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseCipherSuiteIdError(String);
+
+impl fmt::Display for ParseCipherSuiteIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid cipher suite code point: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseCipherSuiteIdError {}
+
+impl FromStr for CipherSuiteId {
+    type Err = ParseCipherSuiteIdError;
+
+    /// Parses the `0xC0,0x2F` form produced by `Display`. The single-value
+    /// `0xC02F` form is accepted as well.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseCipherSuiteIdError(s.to_owned());
+        let hex_byte = |b: &str| {
+            let b = b.trim();
+            let digits = b.strip_prefix("0x").or_else(|| b.strip_prefix("0X"))?;
+            if digits.is_empty() || digits.len() > 2 {
+                return None;
+            }
+            u8::from_str_radix(digits, 16).ok()
+        };
+
+        match s.split_once(',') {
+            Some((hi, lo)) => {
+                let hi = hex_byte(hi).ok_or_else(err)?;
+                let lo = hex_byte(lo).ok_or_else(err)?;
+                Ok(CipherSuiteId(u16::from_be_bytes([hi, lo])))
+            }
+            None => {
+                let s = s.trim();
+                let digits = s
+                    .strip_prefix("0x")
+                    .or_else(|| s.strip_prefix("0X"))
+                    .ok_or_else(err)?;
+                if digits.is_empty() || digits.len() > 4 {
+                    return Err(err());
+                }
+                u16::from_str_radix(digits, 16)
+                    .map(CipherSuiteId)
+                    .map_err(|_| err())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_and_parse() {
+        let id = CipherSuiteId::from(TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256);
+        assert_eq!(id.to_string(), "0xC0,0x2F");
+        assert_eq!("0xC0,0x2F".parse::<CipherSuiteId>().unwrap(), id);
+        assert_eq!("0xc02f".parse::<CipherSuiteId>().unwrap(), id);
+        assert!("0xC0,0x2F0".parse::<CipherSuiteId>().is_err());
+        assert!("C02F".parse::<CipherSuiteId>().is_err());
+    }
+
+    #[test]
+    fn classification() {
+        let grease = CipherSuiteId(0x3A3A);
+        assert!(grease.is_grease());
+        assert!(grease.is_reserved());
+        assert!(!grease.is_unassigned());
+        assert_eq!(grease.known(), None);
+
+        assert!(CipherSuiteId(0xFF85).is_private_use());
+        assert!(CipherSuiteId(0x0050).is_reserved());
+        assert!(CipherSuiteId(0x1308).is_unassigned());
+        assert!(!CipherSuiteId(0xC02F).is_unassigned());
+
+        for code in 0x0060..=0x0066 {
+            let id = CipherSuiteId(code);
+            assert!(id.known().is_some(), "{}", id);
+            assert!(!id.is_reserved(), "{}", id);
+        }
+    }
+}
@@ -11,20 +11,198 @@
 //! assert_eq!(cipher.as_openssl_name(), openssl_name);
 //! ```
 //!
+//! `TLSCipherSuite` covers the full IANA TLS Cipher Suites registry. Suites that
+//! OpenSSL does not implement use their RFC name as the OpenSSL name, the same
+//! convention OpenSSL follows for TLS 1.3 suites, so every variant round-trips.
+//!
+//! `TLSCipherSuite::components` breaks a suite down into its key exchange,
+//! authentication, encryption and MAC algorithms, and
+//! `TLSCipherSuite::as_openssl_description` formats it the way
+//! `openssl ciphers -V -v` does.
+//!
+//! `TLSCipherSuite::from_openssl_alias` additionally accepts the names that
+//! other OpenSSL releases and LibreSSL use, such as `DHE-RSA-DES-CBC3-SHA` or
+//! `AEAD-AES128-GCM-SHA256`. `as_openssl_name` always returns the canonical
+//! name.
+//!
+//! Names and availability differ between OpenSSL releases.
+//! `TLSCipherSuite::as_openssl_name_for` and `TLSCipherSuite::supported_in`
+//! answer both for a given `OpenSslVersion`.
+//!
+//! `TLSCipherSuite::supported_by` and `TLSCipherSuite::as_name_in` do the same
+//! for LibreSSL and BoringSSL, see `TlsLibrary`.
+//!
+//! Embedded stacks are covered too: `TLSCipherSuite::as_mbedtls_name` and
+//! `TLSCipherSuite::as_wolfssl_name` give mbedTLS and wolfSSL names for the
+//! suites those libraries implement, and the matching `from_*` functions
+//! parse them.
+//!
+//! `translate` converts between any two `NameScheme`s, such as `OpenSslNames`,
+//! `RfcNames`, `MbedTlsNames` and `WolfSslNames`. Other naming conventions can
+//! be plugged in by implementing `NameScheme`.
+//!
+//! `encode_cipher_suites` and `decode_cipher_suites` convert suite lists to
+//! and from the ClientHello wire format, with SSLv2 counterparts for
+//! `cipher_specs`.
+//!
+//! Named groups have the same naming problem. `NamedGroup` translates between
+//! IANA and OpenSSL names and `parse_openssl_groups` reads `-groups` lists.
+//! `SignatureScheme` does the same for `-sigalgs` names.
+//!
+//! `ClientHello` parses the suites a client offers and `ServerHello` the
+//! suite and version a server picks. With the `pcap` feature,
+//! the `pcap` module reads them, along with the suite each server chose, out
+//! of pcap and pcapng captures. `ExtensionType` and `AlertDescription` name
+//! the other code points that show up in a handshake.
+//!
+//! The `Policy` trait checks suite lists against compliance rules and
+//! produces a `Report` with a verdict for every suite. `Nist80052r2`,
+//! `PciDss4` and `BsiTr02102` implement NIST SP 800-52 Revision 2, PCI DSS
+//! v4.0 and BSI TR-02102-2. A `CustomPolicy` holds an organisation's own
+//! rules and, with the `toml` or `json` feature, can be loaded from a file.
+//!
+//! `expand_openssl_cipher_string` expands an OpenSSL cipher string, such as
+//! `HIGH:!aNULL:!MD5`, into the suites OpenSSL would offer, and
+//! `fips_approved` narrows such a list down to its FIPS approved suites.
+//!
+//! `diff_cipher_suites` compares two suite lists and reports the suites
+//! that were added, removed or moved and how the grade changed.
+//!
+//! `lint_config` finds the cipher suite directives of nginx, Apache httpd
+//! and HAProxy configuration files, expands them and checks the result
+//! against a policy.
+//!
+//! In the other direction, `generate_config` and
+//! `generate_config_for_policy` write the directives that configure nginx,
+//! Apache httpd, HAProxy, Postfix, Dovecot, lighttpd or Caddy to offer a list
+//! of suites or the suites a policy approves.
+//!
+//! `ServerConfig::negotiate` predicts the suite a server selects for a
+//! client, or why the handshake fails, from both suite lists, the protocol
+//! version and the server's certificate. `ServerConfig::simulate_clients`
+//! runs that for the common browsers and libraries in `CLIENT_PROFILES` to
+//! show which clients can connect.
+//!
+//! `grade_cipher_suites` gives a list an A to F grade following the cipher
+//! strength part of the SSL Labs rating guide.
+//!
+//! `TLSCipherSuite::lookup` is a forgiving alternative to `from_openssl_name`
+//! for user input: it accepts either kind of name regardless of case or
+//! separator, and suggests the closest suites when nothing matches.
+//!
+//! Code points seen on the wire, including GREASE and values that are not in
+//! the registry, can be carried around as a `CipherSuiteId`.
+//!
 //! For SSLv2 cipher suites, use the `SSLV2CipherSuite` enum instead.
 
+mod alert;
+pub use alert::AlertDescription;
+
+mod alias;
+
+mod bsi;
+pub use bsi::BsiTr02102;
+
+mod cipher_string;
+pub use cipher_string::{expand_openssl_cipher_string, CipherStringError};
+
+mod clients;
+pub use clients::{ClientProfile, ClientResult, CLIENT_PROFILES};
+
+pub mod components;
+
+mod custom_policy;
+pub use custom_policy::{CustomPolicy, PolicyFileError};
+
+mod diff;
+pub use diff::{diff_cipher_suites, CipherDiff, Move};
+
+mod extension;
+pub use extension::ExtensionType;
+
+mod fips;
+pub use fips::fips_approved;
+
+mod grade;
+pub use grade::{grade_cipher_suites, Grade, GradeCap, Grading};
+
+mod group;
+pub use group::{parse_openssl_groups, NamedGroup, ParseGroupsError};
+
+mod hello;
+pub use hello::{ClientHello, Extension, Negotiated, NegotiationError, ServerHello};
+
+mod id;
+pub use id::{CipherSuiteId, ParseCipherSuiteIdError};
+
+mod library;
+pub use library::TlsLibrary;
+
+mod lint;
+pub use lint::{
+    find_cipher_directives, lint_config, CipherDirective, DirectiveError, DirectiveLint,
+};
+
+mod listing;
+pub use listing::{format_openssl_ciphers, parse_openssl_ciphers, ParseCiphersError};
+
+mod lookup;
+pub use lookup::UnknownCipherSuite;
+
+mod mbedtls;
+
+mod nist;
+pub use nist::Nist80052r2;
+
+mod openssl_version;
+pub use openssl_version::OpenSslVersion;
+
+#[cfg(feature = "pcap")]
+pub mod pcap;
+
+mod pci;
+pub use pci::PciDss4;
+
+mod policy;
+pub use policy::{Assessment, Policy, Report, Verdict};
+
+mod scheme;
+pub use scheme::{translate, MbedTlsNames, NameScheme, OpenSslNames, RfcNames, WolfSslNames};
+
+mod signature;
+pub use signature::SignatureScheme;
+
+mod simulate;
+pub use simulate::{HandshakeFailure, ServerConfig};
+
+mod snippet;
+pub use snippet::{generate_config, generate_config_for_policy, ServerSoftware};
+
 mod ssl2;
 pub use ssl2::SSLV2CipherSuite;
 
 mod tls;
 pub use tls::TLSCipherSuite;
 
+mod version;
+pub use version::ProtocolVersion;
+
+mod wire;
+pub use wire::{
+    decode_cipher_suites, decode_sslv2_cipher_specs, encode_cipher_suites,
+    encode_sslv2_cipher_specs, CipherSpec, WireError,
+};
+
+mod wolfssl;
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::str::FromStr;
     use strum::IntoEnumIterator;
 
     #[test]
+    #[allow(clippy::needless_borrow)]
     fn round_trip_openssl() {
         for cipher in TLSCipherSuite::iter() {
             let openssl_name = openssl::ssl::cipher_name(&cipher.to_string());
@@ -50,6 +228,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn round_trip_tls_code_points() {
+        for cipher in TLSCipherSuite::iter() {
+            let c = TLSCipherSuite::from_u16(cipher.as_u16()).unwrap();
+            assert_eq!(c, cipher);
+        }
+    }
+
+    #[test]
+    fn round_trip_registries() {
+        for extension in ExtensionType::iter() {
+            let c = ExtensionType::from_u16(extension.as_u16()).unwrap();
+            assert_eq!(c, extension);
+            let c = ExtensionType::from_str(&extension.to_string()).unwrap();
+            assert_eq!(c, extension);
+        }
+        for alert in AlertDescription::iter() {
+            let c = AlertDescription::from_u8(alert.as_u8()).unwrap();
+            assert_eq!(c, alert);
+            let c = AlertDescription::from_str(&alert.to_string()).unwrap();
+            assert_eq!(c, alert);
+        }
+    }
+
+    #[test]
+    fn round_trip_groups() {
+        for group in NamedGroup::iter() {
+            let c = NamedGroup::from_openssl_name(&group.as_openssl_name()).unwrap();
+            assert_eq!(c, group);
+            let c = NamedGroup::from_u16(group.as_u16()).unwrap();
+            assert_eq!(c, group);
+        }
+    }
+
+    #[test]
+    fn round_trip_signature_schemes() {
+        for scheme in SignatureScheme::iter() {
+            if let Some(name) = scheme.as_openssl_name() {
+                let c = SignatureScheme::from_openssl_name(&name).unwrap();
+                assert_eq!(c, scheme);
+            }
+            if let Some(name) = scheme.as_openssl_legacy_name() {
+                let c = SignatureScheme::from_openssl_name(&name).unwrap();
+                assert_eq!(c, scheme);
+            }
+            let c = SignatureScheme::from_u16(scheme.as_u16()).unwrap();
+            assert_eq!(c, scheme);
+        }
+    }
+
     #[test]
     fn round_trip_ssl2_names() {
         for cipher in SSLV2CipherSuite::iter() {
@@ -58,4 +286,12 @@ mod tests {
             assert_eq!(c, cipher);
         }
     }
+
+    #[test]
+    fn round_trip_ssl2_code_points() {
+        for cipher in SSLV2CipherSuite::iter() {
+            let c = SSLV2CipherSuite::from_u32(cipher.as_u32()).unwrap();
+            assert_eq!(c, cipher);
+        }
+    }
 }
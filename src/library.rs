@@ -0,0 +1,224 @@
+use std::fmt;
+
+use strum::IntoEnumIterator;
+
+use crate::components::{Authentication, Cipher, CipherMode, KeyExchange, Mac};
+use crate::{OpenSslVersion, TLSCipherSuite};
+
+/// A TLS library whose cipher suite names this crate understands.
+///
+/// `OpenSsl` means the current 3.x releases; use `OpenSslVersion` to ask about
+/// older ones. `LibreSsl` and `BoringSsl` likewise describe current releases.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum TlsLibrary {
+    OpenSsl,
+    LibreSsl,
+    BoringSsl,
+}
+
+impl fmt::Display for TlsLibrary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::OpenSsl => "OpenSSL",
+            Self::LibreSsl => "LibreSSL",
+            Self::BoringSsl => "BoringSSL",
+        };
+        f.pad(s)
+    }
+}
+
+impl TLSCipherSuite {
+    /// Returns whether the library can negotiate the suite.
+    pub fn supported_by(&self, library: TlsLibrary) -> bool {
+        match library {
+            TlsLibrary::OpenSsl => self.supported_in(OpenSslVersion::V3_0),
+            TlsLibrary::LibreSsl => self.supported_by_libressl(),
+            TlsLibrary::BoringSsl => self.supported_by_boringssl(),
+        }
+    }
+
+    /// Returns the name the library uses for the suite in cipher strings and
+    /// `SSL_CIPHER_get_name`, or `None` if the library does not implement it.
+    ///
+    /// BoringSSL uses OpenSSL's names here; its `SSL_CIPHER_standard_name`
+    /// is the RFC name that `to_string` returns.
+    ///
+    /// ```
+    /// use openssl_to_rfc::{TLSCipherSuite, TlsLibrary};
+    ///
+    /// let cipher = TLSCipherSuite::TLS_AES_128_GCM_SHA256;
+    /// assert_eq!(
+    ///     cipher.as_name_in(TlsLibrary::LibreSsl).unwrap(),
+    ///     "AEAD-AES128-GCM-SHA256"
+    /// );
+    /// assert_eq!(
+    ///     cipher.as_name_in(TlsLibrary::OpenSsl).unwrap(),
+    ///     "TLS_AES_128_GCM_SHA256"
+    /// );
+    /// ```
+    pub fn as_name_in(&self, library: TlsLibrary) -> Option<String> {
+        match library {
+            TlsLibrary::OpenSsl => self.as_openssl_name_for(OpenSslVersion::V3_0),
+            _ if !self.supported_by(library) => None,
+            TlsLibrary::LibreSsl => {
+                let name = match self {
+                    Self::TLS_AES_128_GCM_SHA256 => "AEAD-AES128-GCM-SHA256".to_owned(),
+                    Self::TLS_AES_256_GCM_SHA384 => "AEAD-AES256-GCM-SHA384".to_owned(),
+                    Self::TLS_CHACHA20_POLY1305_SHA256 => {
+                        "AEAD-CHACHA20-POLY1305-SHA256".to_owned()
+                    }
+                    _ => self.as_openssl_name(),
+                };
+                Some(name)
+            }
+            TlsLibrary::BoringSsl => Some(self.as_openssl_name()),
+        }
+    }
+
+    /// Resolves a name as the library spells it. Only suites the library
+    /// implements are returned. BoringSSL also accepts RFC names.
+    pub fn from_name_in(name: &str, library: TlsLibrary) -> Option<TLSCipherSuite> {
+        TLSCipherSuite::iter().find(|cipher| {
+            cipher.as_name_in(library).as_deref() == Some(name)
+                || (library == TlsLibrary::BoringSsl
+                    && cipher.supported_by(library)
+                    && cipher.to_string() == name)
+        })
+    }
+
+    fn supported_by_libressl(&self) -> bool {
+        if self.is_signaling() {
+            return true;
+        }
+        match self {
+            Self::TLS_AES_128_GCM_SHA256
+            | Self::TLS_AES_256_GCM_SHA384
+            | Self::TLS_CHACHA20_POLY1305_SHA256 => return true,
+            Self::TLS_GOSTR341001_WITH_28147_CNT_IMIT
+            | Self::TLS_GOSTR341001_WITH_NULL_GOSTR3411 => return true,
+            _ => {}
+        }
+        if !self.supported_in(OpenSslVersion::V1_0_2) && !self.supported_in(OpenSslVersion::V3_0) {
+            return false;
+        }
+
+        // LibreSSL forked from 1.0.1 and has since removed export, single
+        // DES, IDEA, SEED, static (EC)DH, Kerberos, PSK and SRP suites. It
+        // never picked up CCM, ARIA or Camellia with ECDHE.
+        let c = self.components();
+        let kx = matches!(
+            c.key_exchange,
+            KeyExchange::Null | KeyExchange::Rsa | KeyExchange::Dhe | KeyExchange::Ecdhe
+        );
+        let cipher = match (c.encryption.cipher, c.encryption.mode) {
+            (Cipher::Null | Cipher::Rc4 | Cipher::TripleDes, _) => true,
+            (Cipher::Aes, CipherMode::Cbc | CipherMode::Gcm) => true,
+            (Cipher::ChaCha20, _) => true,
+            (Cipher::Camellia, CipherMode::Cbc) => c.key_exchange != KeyExchange::Ecdhe,
+            _ => false,
+        };
+        kx && cipher && !c.export && c.authentication != Authentication::Gost94
+    }
+
+    fn supported_by_boringssl(&self) -> bool {
+        if self.is_signaling() {
+            return true;
+        }
+
+        // BoringSSL keeps a deliberately small table: AES and ChaCha20 with
+        // RSA or ECDHE, plus PSK and ECDHE-PSK for the few users that need
+        // them, and 3DES for legacy clients.
+        let c = self.components();
+        match (c.key_exchange, c.authentication) {
+            (KeyExchange::Any, _) => matches!(
+                self,
+                Self::TLS_AES_128_GCM_SHA256
+                    | Self::TLS_AES_256_GCM_SHA384
+                    | Self::TLS_CHACHA20_POLY1305_SHA256
+            ),
+            (KeyExchange::Rsa, _) => matches!(
+                (c.encryption.cipher, c.encryption.mode, c.mac),
+                (Cipher::TripleDes, _, Mac::Sha1)
+                    | (Cipher::Aes, CipherMode::Cbc, Mac::Sha1)
+                    | (Cipher::Aes, CipherMode::Gcm, _)
+            ),
+            (KeyExchange::Ecdhe, Authentication::Rsa | Authentication::Ecdsa) => {
+                match (c.encryption.cipher, c.encryption.mode, c.mac) {
+                    (Cipher::Aes, CipherMode::Cbc, Mac::Sha1) => true,
+                    (Cipher::Aes, CipherMode::Gcm, _) => true,
+                    (Cipher::ChaCha20, _, _) => !self.to_string().starts_with("OLD_"),
+                    (Cipher::Aes, CipherMode::Cbc, Mac::Sha256) => {
+                        c.authentication == Authentication::Rsa
+                    }
+                    _ => false,
+                }
+            }
+            (KeyExchange::Psk, _) => {
+                c.encryption.cipher == Cipher::Aes
+                    && c.encryption.mode == CipherMode::Cbc
+                    && c.mac == Mac::Sha1
+            }
+            (KeyExchange::EcdhePsk, _) => matches!(
+                (c.encryption.cipher, c.encryption.mode, c.mac),
+                (Cipher::Aes, CipherMode::Cbc, Mac::Sha1) | (Cipher::ChaCha20, _, _)
+            ),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn boringssl_table() {
+        let mut ids: Vec<u16> = TLSCipherSuite::iter()
+            .filter(|c| c.supported_by(TlsLibrary::BoringSsl) && !c.is_signaling())
+            .map(|c| c.as_u16())
+            .collect();
+        ids.sort_unstable();
+        assert_eq!(
+            ids,
+            vec![
+                0x000A, 0x002F, 0x0035, 0x008C, 0x008D, 0x009C, 0x009D, 0x1301, 0x1302, 0x1303,
+                0xC009, 0xC00A, 0xC013, 0xC014, 0xC027, 0xC02B, 0xC02C, 0xC02F, 0xC030, 0xC035,
+                0xC036, 0xCCA8, 0xCCA9, 0xCCAC,
+            ]
+        );
+    }
+
+    #[test]
+    fn dialect_names() {
+        let cipher = TLSCipherSuite::TLS_DHE_RSA_WITH_3DES_EDE_CBC_SHA;
+        assert_eq!(
+            cipher.as_name_in(TlsLibrary::OpenSsl).unwrap(),
+            "DHE-RSA-DES-CBC3-SHA"
+        );
+        assert_eq!(
+            cipher.as_name_in(TlsLibrary::LibreSsl).unwrap(),
+            "EDH-RSA-DES-CBC3-SHA"
+        );
+        assert_eq!(cipher.as_name_in(TlsLibrary::BoringSsl), None);
+
+        assert_eq!(
+            TLSCipherSuite::from_name_in("AEAD-AES256-GCM-SHA384", TlsLibrary::LibreSsl),
+            Some(TLSCipherSuite::TLS_AES_256_GCM_SHA384)
+        );
+        assert_eq!(
+            TLSCipherSuite::from_name_in("AEAD-AES256-GCM-SHA384", TlsLibrary::OpenSsl),
+            None
+        );
+        assert_eq!(
+            TLSCipherSuite::from_name_in(
+                "TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256",
+                TlsLibrary::BoringSsl
+            ),
+            Some(TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256)
+        );
+        assert_eq!(
+            TLSCipherSuite::from_name_in("PSK-AES128-CCM", TlsLibrary::LibreSsl),
+            None
+        );
+    }
+}
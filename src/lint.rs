@@ -0,0 +1,345 @@
+use std::fmt;
+
+use crate::{expand_openssl_cipher_string, CipherStringError, Policy, Report, TLSCipherSuite};
+
+/// A directive that configures cipher suites, found in a server
+/// configuration file.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct CipherDirective {
+    /// The 1-based line the directive starts on.
+    pub line: usize,
+    /// The directive or option name as written, e.g. `ssl_ciphers`.
+    pub name: String,
+    /// The value without quotes.
+    pub value: String,
+    /// True if the value is a TLS 1.3 suite list rather than an OpenSSL
+    /// cipher string.
+    pub tls13: bool,
+}
+
+impl CipherDirective {
+    /// Returns the suites the directive configures, in order.
+    ///
+    /// Cipher strings are expanded with `expand_openssl_cipher_string`.
+    /// TLS 1.3 lists are colon-separated suite names, which must all be
+    /// known TLS 1.3 suites.
+    pub fn ciphers(&self) -> Result<Vec<TLSCipherSuite>, DirectiveError> {
+        if !self.tls13 {
+            return expand_openssl_cipher_string(&self.value).map_err(DirectiveError::CipherString);
+        }
+
+        let mut ciphers = Vec::new();
+        for name in self.value.split(':').filter(|name| !name.is_empty()) {
+            match TLSCipherSuite::from_openssl_name(name) {
+                Some(cipher) if cipher.is_tls13() => ciphers.push(cipher),
+                _ => return Err(DirectiveError::UnknownCipherSuite(name.to_owned())),
+            }
+        }
+        if ciphers.is_empty() {
+            return Err(DirectiveError::CipherString(CipherStringError::NoMatch));
+        }
+        Ok(ciphers)
+    }
+}
+
+/// Error returned by `CipherDirective::ciphers`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum DirectiveError {
+    CipherString(CipherStringError),
+    /// A name in a TLS 1.3 suite list that is not a TLS 1.3 suite.
+    UnknownCipherSuite(String),
+}
+
+impl fmt::Display for DirectiveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CipherString(e) => e.fmt(f),
+            Self::UnknownCipherSuite(name) => write!(f, "unknown TLS 1.3 cipher suite {:?}", name),
+        }
+    }
+}
+
+impl std::error::Error for DirectiveError {}
+
+/// Finds the cipher suite directives of an nginx, Apache httpd or HAProxy
+/// configuration file.
+///
+/// The recognized directives are:
+///
+/// - nginx: `ssl_ciphers`, `proxy_ssl_ciphers`, `grpc_ssl_ciphers`,
+///   `uwsgi_ssl_ciphers` and `ssl_conf_command Ciphersuites`,
+/// - Apache httpd: `SSLCipherSuite`, `SSLProxyCipherSuite`, both with an
+///   optional `SSL` or `TLSv1.3` protocol argument, and
+///   `SSLOpenSSLConfCmd CipherString` or `Ciphersuites`,
+/// - HAProxy: `ssl-default-bind-ciphers`, `ssl-default-server-ciphers`,
+///   their `ciphersuites` counterparts, and the `ciphers` and
+///   `ciphersuites` options of `bind`, `server` and `default-server` lines.
+///
+/// Comments are skipped and lines ending in a backslash are joined with the
+/// next one. A directive must otherwise fit on one line.
+///
+/// ```
+/// use openssl_to_rfc::find_cipher_directives;
+///
+/// let directives = find_cipher_directives(
+///     "server {\n    ssl_ciphers 'ECDHE+AESGCM:!aNULL';\n}\n",
+/// );
+/// assert_eq!(directives[0].line, 2);
+/// assert_eq!(directives[0].name, "ssl_ciphers");
+/// assert_eq!(directives[0].value, "ECDHE+AESGCM:!aNULL");
+/// ```
+pub fn find_cipher_directives(config: &str) -> Vec<CipherDirective> {
+    let mut directives = Vec::new();
+    let mut logical = String::new();
+    let mut start = 0;
+
+    for (i, line) in config.lines().enumerate() {
+        if logical.is_empty() {
+            start = i + 1;
+        }
+        match line.strip_suffix('\\') {
+            Some(continued) => {
+                logical.push_str(continued);
+                logical.push(' ');
+            }
+            None => {
+                logical.push_str(line);
+                scan_line(&logical, start, &mut directives);
+                logical.clear();
+            }
+        }
+    }
+    if !logical.is_empty() {
+        scan_line(&logical, start, &mut directives);
+    }
+    directives
+}
+
+fn scan_line(line: &str, number: usize, directives: &mut Vec<CipherDirective>) {
+    let words = split_words(line);
+    let Some(first) = words.first() else { return };
+    let mut push = |name: &str, value: &str, tls13| {
+        directives.push(CipherDirective {
+            line: number,
+            name: name.to_owned(),
+            value: value.to_owned(),
+            tls13,
+        })
+    };
+    let word = |i: usize| words.get(i).map(String::as_str);
+
+    match first.to_ascii_lowercase().as_str() {
+        "ssl_ciphers"
+        | "proxy_ssl_ciphers"
+        | "grpc_ssl_ciphers"
+        | "uwsgi_ssl_ciphers"
+        | "ssl-default-bind-ciphers"
+        | "ssl-default-server-ciphers" => {
+            if let Some(value) = word(1) {
+                push(first, value, false);
+            }
+        }
+        "ssl-default-bind-ciphersuites" | "ssl-default-server-ciphersuites" => {
+            if let Some(value) = word(1) {
+                push(first, value, true);
+            }
+        }
+        "sslciphersuite" | "sslproxyciphersuite" => match (word(1), word(2)) {
+            (Some(protocol), Some(value)) => {
+                push(first, value, protocol.eq_ignore_ascii_case("TLSv1.3"))
+            }
+            (Some(value), None) => push(first, value, false),
+            _ => {}
+        },
+        "ssl_conf_command"
+        | "proxy_ssl_conf_command"
+        | "sslopensslconfcmd"
+        | "sslproxyopensslconfcmd" => {
+            if let (Some(command), Some(value)) = (word(1), word(2)) {
+                if command.eq_ignore_ascii_case("CipherString") {
+                    push(first, value, false);
+                } else if command.eq_ignore_ascii_case("Ciphersuites") {
+                    push(first, value, true);
+                }
+            }
+        }
+        "bind" | "server" | "default-server" => {
+            for (i, option) in words.iter().enumerate() {
+                let tls13 = match option.as_str() {
+                    "ciphers" => false,
+                    "ciphersuites" => true,
+                    _ => continue,
+                };
+                if let Some(value) = word(i + 1) {
+                    push(option, value, tls13);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Splits a line into words, removing quotes, a trailing comment and
+/// nginx's terminating semicolon.
+fn split_words(line: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut quote = None;
+
+    for c in line.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => word.push(c),
+            None if c == '"' || c == '\'' => {
+                quote = Some(c);
+                in_word = true;
+            }
+            None if c == '#' && !in_word => break,
+            None if c.is_whitespace() || c == ';' => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+                if c == ';' {
+                    break;
+                }
+            }
+            None => {
+                word.push(c);
+                in_word = true;
+            }
+        }
+    }
+    if in_word {
+        words.push(word);
+    }
+    words
+}
+
+/// A cipher suite directive together with the policy's verdict on the
+/// suites it configures.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DirectiveLint {
+    pub directive: CipherDirective,
+    pub result: Result<Report, DirectiveError>,
+}
+
+impl fmt::Display for DirectiveLint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = &self.directive;
+        writeln!(f, "line {}: {} {}", d.line, d.name, d.value)?;
+        match &self.result {
+            Ok(report) => {
+                for a in &report.assessments {
+                    writeln!(f, "  {:<12} {}: {}", a.verdict, a.cipher, a.reason)?;
+                }
+                Ok(())
+            }
+            Err(e) => writeln!(f, "  error: {}", e),
+        }
+    }
+}
+
+/// Finds the cipher suite directives of a configuration file, as
+/// `find_cipher_directives` does, and checks the suites each one
+/// configures against a policy.
+///
+/// ```
+/// use openssl_to_rfc::{lint_config, PciDss4, TLSCipherSuite};
+///
+/// let lints = lint_config("SSLCipherSuite ECDHE+AESGCM:ECDHE+3DES:!aNULL\n", &PciDss4);
+/// let report = lints[0].result.as_ref().unwrap();
+/// let violations: Vec<_> = report.violations().map(|a| a.cipher).collect();
+/// assert_eq!(
+///     violations,
+///     vec![
+///         TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_3DES_EDE_CBC_SHA,
+///         TLSCipherSuite::TLS_ECDHE_RSA_WITH_3DES_EDE_CBC_SHA,
+///     ]
+/// );
+/// ```
+pub fn lint_config(config: &str, policy: &dyn Policy) -> Vec<DirectiveLint> {
+    find_cipher_directives(config)
+        .into_iter()
+        .map(|directive| {
+            let result = directive.ciphers().map(|ciphers| policy.check(&ciphers));
+            DirectiveLint { directive, result }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directives() {
+        let config = "\
+global
+    ssl-default-bind-ciphers ECDHE+AESGCM # modern clients only
+    ssl-default-bind-ciphersuites TLS_AES_128_GCM_SHA256:TLS_AES_256_GCM_SHA384
+
+frontend https
+    bind :443 ssl crt /etc/haproxy/site.pem ciphers AES128-SHA ciphersuites TLS_AES_128_GCM_SHA256
+
+<VirtualHost *:443>
+    SSLCipherSuite TLSv1.3 TLS_CHACHA20_POLY1305_SHA256
+    SSLProxyCipherSuite \\
+        \"HIGH:!aNULL\"
+    # SSLCipherSuite ALL
+</VirtualHost>
+
+ssl_conf_command Ciphersuites TLS_AES_256_GCM_SHA384;
+ssl_conf_command Options PrioritizeChaCha;
+";
+        let found: Vec<_> = find_cipher_directives(config)
+            .into_iter()
+            .map(|d| (d.line, d.name, d.value, d.tls13))
+            .collect();
+        let expected = [
+            (2, "ssl-default-bind-ciphers", "ECDHE+AESGCM", false),
+            (
+                3,
+                "ssl-default-bind-ciphersuites",
+                "TLS_AES_128_GCM_SHA256:TLS_AES_256_GCM_SHA384",
+                true,
+            ),
+            (6, "ciphers", "AES128-SHA", false),
+            (6, "ciphersuites", "TLS_AES_128_GCM_SHA256", true),
+            (9, "SSLCipherSuite", "TLS_CHACHA20_POLY1305_SHA256", true),
+            (10, "SSLProxyCipherSuite", "HIGH:!aNULL", false),
+            (15, "ssl_conf_command", "TLS_AES_256_GCM_SHA384", true),
+        ];
+        assert_eq!(found.len(), expected.len());
+        for (found, expected) in found.iter().zip(expected) {
+            assert_eq!(
+                (found.0, found.1.as_str(), found.2.as_str(), found.3),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn tls13_lists() {
+        let directive = |value: &str| CipherDirective {
+            line: 1,
+            name: "ssl-default-bind-ciphersuites".to_owned(),
+            value: value.to_owned(),
+            tls13: true,
+        };
+        assert_eq!(
+            directive("TLS_AES_256_GCM_SHA384:TLS_CHACHA20_POLY1305_SHA256").ciphers(),
+            Ok(vec![
+                TLSCipherSuite::TLS_AES_256_GCM_SHA384,
+                TLSCipherSuite::TLS_CHACHA20_POLY1305_SHA256,
+            ])
+        );
+        assert_eq!(
+            directive("TLS_AES_256_GCM_SHA384:ECDHE-RSA-AES128-GCM-SHA256").ciphers(),
+            Err(DirectiveError::UnknownCipherSuite(
+                "ECDHE-RSA-AES128-GCM-SHA256".to_owned()
+            ))
+        );
+    }
+}
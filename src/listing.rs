@@ -0,0 +1,257 @@
+use std::fmt;
+
+use crate::components::{
+    Authentication, Cipher, CipherMode, Components, Encryption, KeyExchange, Mac,
+};
+use crate::{CipherSuiteId, TLSCipherSuite};
+
+impl TLSCipherSuite {
+    /// Formats the suite the way `openssl ciphers -V -v` prints it:
+    ///
+    /// ```
+    /// use openssl_to_rfc::TLSCipherSuite;
+    ///
+    /// let cipher = TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256;
+    /// assert_eq!(
+    ///     cipher.as_openssl_description(),
+    ///     "          0xC0,0x2F - ECDHE-RSA-AES128-GCM-SHA256    TLSv1.2 Kx=ECDH     \
+    ///      Au=RSA   Enc=AESGCM(128)            Mac=AEAD"
+    /// );
+    /// ```
+    ///
+    /// The column widths are those of OpenSSL 3.x. Export suites, which
+    /// 1.1.0 and later no longer list, get the ` export` marker OpenSSL 1.0.2
+    /// appended to them.
+    pub fn as_openssl_description(&self) -> String {
+        let c = self.components();
+        let mut line = format!(
+            "          {} - {:<30} {:<7} Kx={:<8} Au={:<5} Enc={:<22} Mac={:<4}",
+            CipherSuiteId::from(*self),
+            self.as_openssl_name(),
+            c.min_version,
+            kx_label(&c),
+            au_label(&c),
+            enc_label(&c.encryption),
+            mac_label(c.mac),
+        );
+        if c.export {
+            line.push_str(" export");
+        }
+        line
+    }
+}
+
+/// Formats a list of suites as `openssl ciphers -V -v` would, one per line.
+pub fn format_openssl_ciphers(ciphers: &[TLSCipherSuite]) -> String {
+    let mut out = String::new();
+    for cipher in ciphers {
+        out.push_str(&cipher.as_openssl_description());
+        out.push('\n');
+    }
+    out
+}
+
+/// Error returned by `parse_openssl_ciphers` for a line it cannot resolve.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseCiphersError {
+    /// The 1-based line number.
+    pub line: usize,
+    pub text: String,
+}
+
+impl fmt::Display for ParseCiphersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: unrecognized cipher suite: {:?}",
+            self.line, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseCiphersError {}
+
+/// Parses the output of `openssl ciphers -v`, `-V -v` or `-V -v -stdname`
+/// back into cipher suites.
+///
+/// When a line carries a code point it takes precedence over the name, so
+/// output from releases that used different names still resolves. Lines
+/// without one are resolved with `TLSCipherSuite::from_openssl_alias`, so the
+/// names older releases and LibreSSL print are accepted too. Blank lines
+/// and SSLv2 suites, which 0.9.8 and 1.0.x list with three-byte code points,
+/// are skipped.
+pub fn parse_openssl_ciphers(output: &str) -> Result<Vec<TLSCipherSuite>, ParseCiphersError> {
+    let mut ciphers = Vec::new();
+
+    for (i, line) in output.lines().enumerate() {
+        let err = || ParseCiphersError {
+            line: i + 1,
+            text: line.to_owned(),
+        };
+
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.is_empty() {
+            continue;
+        }
+
+        let (id, rest) = match fields.get(1) {
+            Some(&"-") => (Some(fields[0]), &fields[2..]),
+            _ => (None, &fields[..]),
+        };
+        // With -stdname the RFC name comes first, followed by another "-".
+        let rest = match rest.get(1) {
+            Some(&"-") => &rest[2..],
+            _ => rest,
+        };
+        let (name, version) = match rest {
+            [name, version, ..] => (*name, *version),
+            _ => return Err(err()),
+        };
+
+        if version == "SSLv2" {
+            continue;
+        }
+
+        let cipher = match id {
+            Some(id) => id.parse::<CipherSuiteId>().ok().and_then(|id| id.known()),
+            None => TLSCipherSuite::from_openssl_alias(name),
+        };
+        ciphers.push(cipher.ok_or_else(err)?);
+    }
+
+    Ok(ciphers)
+}
+
+fn kx_label(c: &Components) -> &'static str {
+    match (c.key_exchange, c.authentication, c.export) {
+        (KeyExchange::Rsa, _, true) if c.encryption.bits == 56 => "RSA(1024)",
+        (KeyExchange::Rsa, _, true) => "RSA(512)",
+        (KeyExchange::Dh | KeyExchange::Dhe, _, true) if c.encryption.bits == 56 => "DH(1024)",
+        (KeyExchange::Dh | KeyExchange::Dhe, _, true) => "DH(512)",
+        (KeyExchange::Null, _, _) => "None",
+        (KeyExchange::Rsa, _, _) => "RSA",
+        (KeyExchange::Dh, Authentication::Dss, _) => "DH/DSS",
+        (KeyExchange::Dh, _, _) => "DH/RSA",
+        (KeyExchange::Dhe, _, _) => "DH",
+        (KeyExchange::Ecdh, Authentication::Ecdsa, _) => "ECDH/ECDSA",
+        (KeyExchange::Ecdh, _, _) => "ECDH/RSA",
+        (KeyExchange::Ecdhe, _, _) => "ECDH",
+        (KeyExchange::Psk, _, _) => "PSK",
+        (KeyExchange::RsaPsk, _, _) => "RSAPSK",
+        (KeyExchange::DhePsk, _, _) => "DHEPSK",
+        (KeyExchange::EcdhePsk, _, _) => "ECDHEPSK",
+        (KeyExchange::Srp, _, _) => "SRP",
+        (KeyExchange::Krb5, _, _) => "KRB5",
+        (KeyExchange::Gost, _, _) => "GOST",
+        (KeyExchange::EccPwd, _, _) => "ECCPWD",
+        (KeyExchange::Any, _, _) => "any",
+    }
+}
+
+fn au_label(c: &Components) -> &'static str {
+    match (c.key_exchange, c.authentication) {
+        (KeyExchange::Dh, _) => "DH",
+        (KeyExchange::Ecdh, _) => "ECDH",
+        (_, Authentication::Null) => "None",
+        (_, Authentication::Rsa) => "RSA",
+        (_, Authentication::Dss) => "DSS",
+        (_, Authentication::Ecdsa) => "ECDSA",
+        (_, Authentication::Psk) => "PSK",
+        (_, Authentication::Srp) => "SRP",
+        (_, Authentication::Krb5) => "KRB5",
+        (_, Authentication::Gost94) => "GOST94",
+        (_, Authentication::Gost01) => "GOST01",
+        (_, Authentication::Gost12) => "GOST12",
+        (_, Authentication::EccPwd) => "ECCPWD",
+        (_, Authentication::Any) => "any",
+    }
+}
+
+fn enc_label(enc: &Encryption) -> String {
+    let name = match (enc.cipher, enc.mode) {
+        (Cipher::Null, _) => return "None".to_owned(),
+        (Cipher::Kuznyechik, CipherMode::Mgm) => return "KUZNYECHIKMGM".to_owned(),
+        (Cipher::Kuznyechik, _) => return "KUZNYECHIK".to_owned(),
+        (Cipher::Magma, CipherMode::Mgm) => return "MAGMAMGM".to_owned(),
+        (Cipher::Magma, _) => return "MAGMA".to_owned(),
+        (Cipher::Rc4, _) => "RC4",
+        (Cipher::Rc2, _) => "RC2",
+        (Cipher::Des, _) => "DES",
+        (Cipher::TripleDes, _) => "3DES",
+        (Cipher::Idea, _) => "IDEA",
+        (Cipher::Seed, _) => "SEED",
+        (Cipher::Aes, CipherMode::Gcm) => "AESGCM",
+        (Cipher::Aes, CipherMode::Ccm) => "AESCCM",
+        (Cipher::Aes, CipherMode::Ccm8) => "AESCCM8",
+        (Cipher::Aes, _) => "AES",
+        (Cipher::Camellia, CipherMode::Gcm) => "CamelliaGCM",
+        (Cipher::Camellia, _) => "Camellia",
+        (Cipher::Aria, CipherMode::Gcm) => "ARIAGCM",
+        (Cipher::Aria, _) => "ARIA",
+        (Cipher::ChaCha20, _) => "CHACHA20/POLY1305",
+        (Cipher::Gost28147, _) => "GOST89",
+        (Cipher::Sm4, CipherMode::Ccm) => "SM4CCM",
+        (Cipher::Sm4, _) => "SM4GCM",
+        (Cipher::Aegis256, _) => "AEGIS256",
+        (Cipher::Aegis128L, _) => "AEGIS128L",
+    };
+    format!("{}({})", name, enc.bits)
+}
+
+fn mac_label(mac: Mac) -> &'static str {
+    match mac {
+        Mac::Null => "None",
+        Mac::Md5 => "MD5",
+        Mac::Sha1 => "SHA1",
+        Mac::Sha256 => "SHA256",
+        Mac::Sha384 => "SHA384",
+        Mac::Aead => "AEAD",
+        Mac::Gost89 => "GOST89",
+        Mac::Gost94 => "GOST94",
+        Mac::KuznyechikOmac => "KUZNYECHIKOMAC",
+        Mac::MagmaOmac => "MAGMAOMAC",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn round_trip_listing() {
+        let ciphers: Vec<_> = TLSCipherSuite::iter()
+            .filter(|c| !c.is_signaling())
+            .collect();
+        let listing = format_openssl_ciphers(&ciphers);
+        assert_eq!(parse_openssl_ciphers(&listing).unwrap(), ciphers);
+    }
+
+    #[test]
+    fn parse_archived_output() {
+        let output = "\
+ECDHE-RSA-AES256-GCM-SHA384 TLSv1.2 Kx=ECDH     Au=RSA  Enc=AESGCM(256) Mac=AEAD
+EXP-RC4-MD5             SSLv3 Kx=RSA(512) Au=RSA  Enc=RC4(40)   Mac=MD5  export
+0x07,0x00,0xC0 - DES-CBC3-MD5           SSLv2 Kx=RSA      Au=RSA  Enc=3DES(168) Mac=MD5
+
+          0x13,0x01 - TLS_AES_128_GCM_SHA256 - TLS_AES_128_GCM_SHA256 TLSv1.3 Kx=any Au=any Enc=AESGCM(128) Mac=AEAD
+          0x00,0x16 - EDH-RSA-DES-CBC3-SHA    SSLv3 Kx=DH       Au=RSA  Enc=3DES(168) Mac=SHA1
+AEAD-AES128-GCM-SHA256  TLSv1.3 Kx=unknown  Au=unknown Enc=AESGCM(128) Mac=AEAD
+EXP-DHE-RSA-DES-CBC-SHA SSLv3 Kx=DH(512)  Au=RSA  Enc=DES(40)   Mac=SHA1 export
+";
+        assert_eq!(
+            parse_openssl_ciphers(output).unwrap(),
+            vec![
+                TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+                TLSCipherSuite::TLS_RSA_EXPORT_WITH_RC4_40_MD5,
+                TLSCipherSuite::TLS_AES_128_GCM_SHA256,
+                TLSCipherSuite::TLS_DHE_RSA_WITH_3DES_EDE_CBC_SHA,
+                TLSCipherSuite::TLS_AES_128_GCM_SHA256,
+                TLSCipherSuite::TLS_DHE_RSA_EXPORT_WITH_DES40_CBC_SHA,
+            ]
+        );
+
+        let err = parse_openssl_ciphers("FOO-BAR TLSv1.2 Kx=any").unwrap_err();
+        assert_eq!(err.line, 1);
+    }
+}
@@ -0,0 +1,188 @@
+use std::fmt;
+use std::str::FromStr;
+
+use strum::IntoEnumIterator;
+
+use crate::TLSCipherSuite;
+
+/// The most suggestions `UnknownCipherSuite` carries.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Error returned by `TLSCipherSuite::lookup` for a name that matches no
+/// cipher suite, with the closest known suites as suggestions.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct UnknownCipherSuite {
+    name: String,
+    suggestions: Vec<TLSCipherSuite>,
+}
+
+impl UnknownCipherSuite {
+    /// The name as it was passed to `lookup`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The closest known suites by edit distance, best match first. This is
+    /// empty if nothing is reasonably close.
+    pub fn suggestions(&self) -> &[TLSCipherSuite] {
+        &self.suggestions
+    }
+}
+
+impl fmt::Display for UnknownCipherSuite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown cipher suite {:?}", self.name)?;
+
+        for (i, cipher) in self.suggestions.iter().enumerate() {
+            let sep = if i == 0 { "; did you mean" } else { " or" };
+            write!(f, "{} {} ({})", sep, cipher.as_openssl_name(), cipher)?;
+        }
+        if !self.suggestions.is_empty() {
+            f.write_str("?")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for UnknownCipherSuite {}
+
+impl TLSCipherSuite {
+    /// Looks up a suite by its OpenSSL or RFC name, or by any of its
+    /// `openssl_aliases`.
+    ///
+    /// Unlike `from_openssl_name`, this ignores case and surrounding
+    /// whitespace and treats `_` and `-` as the same separator. If nothing
+    /// matches, the error suggests the closest OpenSSL and RFC names.
+    ///
+    /// ```
+    /// use openssl_to_rfc::TLSCipherSuite;
+    ///
+    /// let cipher = TLSCipherSuite::lookup(" aes128_gcm_sha256 ").unwrap();
+    /// assert_eq!(cipher, TLSCipherSuite::TLS_RSA_WITH_AES_128_GCM_SHA256);
+    ///
+    /// let err = TLSCipherSuite::lookup("ECDHE-RSA-AES128-GCM-SHA265").unwrap_err();
+    /// assert_eq!(
+    ///     err.suggestions()[0],
+    ///     TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256
+    /// );
+    /// ```
+    pub fn lookup(name: &str) -> Result<TLSCipherSuite, UnknownCipherSuite> {
+        if let Some(cipher) = TLSCipherSuite::from_openssl_alias(name) {
+            return Ok(cipher);
+        }
+        if let Ok(cipher) = TLSCipherSuite::from_str(name) {
+            return Ok(cipher);
+        }
+
+        let wanted = normalize(name);
+        let mut candidates = Vec::new();
+        for cipher in TLSCipherSuite::iter() {
+            let openssl = normalize(&cipher.as_openssl_name());
+            let rfc = normalize(&cipher.to_string());
+            if wanted == openssl || wanted == rfc {
+                return Ok(cipher);
+            }
+
+            let distance = edit_distance(&wanted, &openssl).min(edit_distance(&wanted, &rfc));
+            candidates.push((distance, cipher));
+        }
+
+        // Allow roughly one typo per five characters, but at least two so
+        // that a swapped pair of digits is still caught.
+        let threshold = (wanted.len() / 5).max(2);
+        candidates.retain(|(distance, _)| *distance <= threshold);
+        candidates.sort_by_key(|(distance, _)| *distance);
+
+        Err(UnknownCipherSuite {
+            name: name.to_owned(),
+            suggestions: candidates
+                .into_iter()
+                .take(MAX_SUGGESTIONS)
+                .map(|(_, cipher)| cipher)
+                .collect(),
+        })
+    }
+}
+
+/// Normalizes a cipher suite name for loose comparison: surrounding
+/// whitespace is dropped, letters are uppercased and `_` becomes `-`.
+pub(crate) fn normalize(name: &str) -> String {
+    name.trim()
+        .chars()
+        .map(|c| match c {
+            '_' => '-',
+            c => c.to_ascii_uppercase(),
+        })
+        .collect()
+}
+
+/// Levenshtein distance between two ASCII strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.as_bytes();
+    let b = b.as_bytes();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        cur[0] = i;
+        for j in 1..=b.len() {
+            let substitution = prev[j - 1] + usize::from(a[i - 1] != b[j - 1]);
+            cur[j] = substitution.min(prev[j] + 1).min(cur[j - 1] + 1);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_normalizes() {
+        assert_eq!(
+            TLSCipherSuite::lookup("AES128-GCM-SHA256 ").unwrap(),
+            TLSCipherSuite::TLS_RSA_WITH_AES_128_GCM_SHA256
+        );
+        assert_eq!(
+            TLSCipherSuite::lookup("tls-ecdhe-rsa-with-aes-256-gcm-sha384").unwrap(),
+            TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384
+        );
+        assert_eq!(
+            TLSCipherSuite::lookup("TLS_DH_anon_WITH_AES_128_CBC_SHA").unwrap(),
+            TLSCipherSuite::TLS_DH_anon_WITH_AES_128_CBC_SHA
+        );
+    }
+
+    #[test]
+    fn lookup_suggests() {
+        let err = TLSCipherSuite::lookup("ECDHE-RSA-AES128-GCM-SHA265").unwrap_err();
+        assert_eq!(err.name(), "ECDHE-RSA-AES128-GCM-SHA265");
+        assert_eq!(
+            err.suggestions()[0],
+            TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256
+        );
+        assert!(err
+            .to_string()
+            .contains("did you mean ECDHE-RSA-AES128-GCM-SHA256"));
+
+        let err = TLSCipherSuite::lookup("TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA").unwrap_err();
+        assert_eq!(
+            err.suggestions()[0],
+            TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA
+        );
+
+        let err = TLSCipherSuite::lookup("nonsense").unwrap_err();
+        assert!(err.suggestions().is_empty());
+        assert_eq!(err.to_string(), "unknown cipher suite \"nonsense\"");
+    }
+
+    #[test]
+    fn distance() {
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("SHA256", "SHA265"), 2);
+    }
+}
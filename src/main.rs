@@ -0,0 +1,127 @@
+use std::env;
+use std::fs;
+use std::process;
+
+#[cfg(feature = "pcap")]
+use openssl_to_rfc::pcap::{format_flows, read_capture};
+use openssl_to_rfc::{diff_cipher_suites, expand_openssl_cipher_string, TLSCipherSuite};
+
+const USAGE: &str = "\
+usage: openssl-to-rfc diff <old|@file> <new|@file>
+       openssl-to-rfc pcap <capture.pcap|capture.pcapng>";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        ["diff", old, new] => diff(old, new),
+        ["pcap", path] => pcap(path).map(|()| 0),
+        _ => Err(USAGE.to_owned()),
+    };
+
+    match result {
+        Ok(code) => process::exit(code),
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(2);
+        }
+    }
+}
+
+/// Prints the difference between two suite lists and, like diff(1), exits
+/// with 1 if there is one.
+fn diff(old: &str, new: &str) -> Result<i32, String> {
+    let diff = diff_cipher_suites(&ciphers(old)?, &ciphers(new)?);
+    print!("{}", diff);
+    Ok(if diff.is_empty() { 0 } else { 1 })
+}
+
+/// Reads a suite list from the file `path` if `arg` is `@path`, or else from
+/// `arg` itself. A list of suite names, in either scheme and separated by
+/// colons, commas or whitespace, is taken as is; anything else is expanded
+/// as an OpenSSL cipher string. An empty list is an error.
+fn ciphers(arg: &str) -> Result<Vec<TLSCipherSuite>, String> {
+    let list = match arg.strip_prefix('@') {
+        Some(path) => fs::read_to_string(path).map_err(|err| format!("{}: {}", path, err))?,
+        None => arg.to_owned(),
+    };
+
+    let names: Vec<_> = list
+        .split(|c: char| c == ':' || c == ',' || c.is_whitespace())
+        .filter(|name| !name.is_empty())
+        .collect();
+    if names.is_empty() {
+        return Err(format!("no cipher suites in {:?}", arg));
+    }
+    if let Ok(ciphers) = names
+        .iter()
+        .map(|name| TLSCipherSuite::lookup(name))
+        .collect()
+    {
+        return Ok(ciphers);
+    }
+    expand_openssl_cipher_string(list.trim()).map_err(|err| format!("{}: {}", arg, err))
+}
+
+#[cfg(feature = "pcap")]
+fn pcap(path: &str) -> Result<(), String> {
+    let data = fs::read(path).map_err(|err| format!("{}: {}", path, err))?;
+    let flows = read_capture(&data).map_err(|err| format!("{}: {}", path, err))?;
+    print!("{}", format_flows(&flows));
+    Ok(())
+}
+
+#[cfg(not(feature = "pcap"))]
+fn pcap(_path: &str) -> Result<(), String> {
+    Err("pcap support is not built in; rebuild with --features pcap".to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cipher_lists() {
+        assert_eq!(
+            ciphers("ECDHE-RSA-AES128-GCM-SHA256, TLS_AES_128_GCM_SHA256"),
+            Ok(vec![
+                TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+                TLSCipherSuite::TLS_AES_128_GCM_SHA256,
+            ])
+        );
+        assert_eq!(
+            ciphers("ECDHE+AESGCM:!ECDSA"),
+            Ok(vec![
+                TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+                TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+            ])
+        );
+        assert!(ciphers("").is_err());
+        assert!(ciphers(" : ").is_err());
+        assert!(ciphers("@/nonexistent/ciphers.txt").is_err());
+    }
+
+    #[test]
+    fn cipher_list_file() {
+        let path = env::temp_dir().join(format!("openssl-to-rfc-{}.txt", process::id()));
+        fs::write(&path, "AES128-SHA\nAES256-SHA\n").unwrap();
+        let arg = format!("@{}", path.display());
+        let result = ciphers(&arg);
+        fs::write(&path, "").unwrap();
+        let empty = ciphers(&arg);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            result,
+            Ok(vec![
+                TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA,
+                TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA,
+            ])
+        );
+        assert!(empty.is_err());
+    }
+}
@@ -0,0 +1,454 @@
+use strum::IntoEnumIterator;
+
+use crate::TLSCipherSuite;
+
+impl TLSCipherSuite {
+    /// Returns the name mbedTLS uses for the suite, e.g. in
+    /// `mbedtls_ssl_get_ciphersuite_name`, or `None` if mbedTLS does not
+    /// implement it.
+    ///
+    /// The names are the ones in mbedTLS's `ssl_ciphersuites.h`, which mostly
+    /// follow the RFC name with `-` separators. TLS 1.3 suites are prefixed
+    /// with `TLS1-3-`, and the registry's `TLS_PSK_DHE_WITH_AES_*_CCM_8`
+    /// suites are spelled `TLS-DHE-PSK-WITH-AES-*-CCM-8`. The suites are
+    /// those mbedTLS 2.28 and 3.x define, including the ones 3.0 dropped,
+    /// such as RC4.
+    ///
+    /// ```
+    /// use openssl_to_rfc::TLSCipherSuite;
+    ///
+    /// let cipher = TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256;
+    /// assert_eq!(
+    ///     cipher.as_mbedtls_name(),
+    ///     Some("TLS-ECDHE-RSA-WITH-AES-128-GCM-SHA256")
+    /// );
+    /// assert_eq!(
+    ///     cipher.as_mbedtls_macro().unwrap(),
+    ///     "MBEDTLS_TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256"
+    /// );
+    /// assert_eq!(
+    ///     TLSCipherSuite::TLS_KRB5_WITH_DES_CBC_SHA.as_mbedtls_name(),
+    ///     None
+    /// );
+    /// ```
+    pub fn as_mbedtls_name(&self) -> Option<&'static str> {
+        let name = match self {
+            Self::TLS_RSA_WITH_NULL_MD5 => "TLS-RSA-WITH-NULL-MD5",
+            Self::TLS_RSA_WITH_NULL_SHA => "TLS-RSA-WITH-NULL-SHA",
+            Self::TLS_RSA_WITH_RC4_128_MD5 => "TLS-RSA-WITH-RC4-128-MD5",
+            Self::TLS_RSA_WITH_RC4_128_SHA => "TLS-RSA-WITH-RC4-128-SHA",
+            Self::TLS_RSA_WITH_DES_CBC_SHA => "TLS-RSA-WITH-DES-CBC-SHA",
+            Self::TLS_RSA_WITH_3DES_EDE_CBC_SHA => "TLS-RSA-WITH-3DES-EDE-CBC-SHA",
+            Self::TLS_DHE_RSA_WITH_DES_CBC_SHA => "TLS-DHE-RSA-WITH-DES-CBC-SHA",
+            Self::TLS_DHE_RSA_WITH_3DES_EDE_CBC_SHA => "TLS-DHE-RSA-WITH-3DES-EDE-CBC-SHA",
+            Self::TLS_RSA_WITH_AES_128_CBC_SHA => "TLS-RSA-WITH-AES-128-CBC-SHA",
+            Self::TLS_DHE_RSA_WITH_AES_128_CBC_SHA => "TLS-DHE-RSA-WITH-AES-128-CBC-SHA",
+            Self::TLS_RSA_WITH_AES_256_CBC_SHA => "TLS-RSA-WITH-AES-256-CBC-SHA",
+            Self::TLS_DHE_RSA_WITH_AES_256_CBC_SHA => "TLS-DHE-RSA-WITH-AES-256-CBC-SHA",
+            Self::TLS_RSA_WITH_NULL_SHA256 => "TLS-RSA-WITH-NULL-SHA256",
+            Self::TLS_RSA_WITH_AES_128_CBC_SHA256 => "TLS-RSA-WITH-AES-128-CBC-SHA256",
+            Self::TLS_RSA_WITH_AES_256_CBC_SHA256 => "TLS-RSA-WITH-AES-256-CBC-SHA256",
+            Self::TLS_RSA_WITH_CAMELLIA_128_CBC_SHA => "TLS-RSA-WITH-CAMELLIA-128-CBC-SHA",
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_128_CBC_SHA => "TLS-DHE-RSA-WITH-CAMELLIA-128-CBC-SHA",
+            Self::TLS_DHE_RSA_WITH_AES_128_CBC_SHA256 => "TLS-DHE-RSA-WITH-AES-128-CBC-SHA256",
+            Self::TLS_DHE_RSA_WITH_AES_256_CBC_SHA256 => "TLS-DHE-RSA-WITH-AES-256-CBC-SHA256",
+            Self::TLS_RSA_WITH_CAMELLIA_256_CBC_SHA => "TLS-RSA-WITH-CAMELLIA-256-CBC-SHA",
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_256_CBC_SHA => "TLS-DHE-RSA-WITH-CAMELLIA-256-CBC-SHA",
+            Self::TLS_PSK_WITH_RC4_128_SHA => "TLS-PSK-WITH-RC4-128-SHA",
+            Self::TLS_PSK_WITH_3DES_EDE_CBC_SHA => "TLS-PSK-WITH-3DES-EDE-CBC-SHA",
+            Self::TLS_PSK_WITH_AES_128_CBC_SHA => "TLS-PSK-WITH-AES-128-CBC-SHA",
+            Self::TLS_PSK_WITH_AES_256_CBC_SHA => "TLS-PSK-WITH-AES-256-CBC-SHA",
+            Self::TLS_RSA_PSK_WITH_RC4_128_SHA => "TLS-RSA-PSK-WITH-RC4-128-SHA",
+            Self::TLS_RSA_PSK_WITH_3DES_EDE_CBC_SHA => "TLS-RSA-PSK-WITH-3DES-EDE-CBC-SHA",
+            Self::TLS_RSA_PSK_WITH_AES_128_CBC_SHA => "TLS-RSA-PSK-WITH-AES-128-CBC-SHA",
+            Self::TLS_RSA_PSK_WITH_AES_256_CBC_SHA => "TLS-RSA-PSK-WITH-AES-256-CBC-SHA",
+            Self::TLS_RSA_WITH_AES_128_GCM_SHA256 => "TLS-RSA-WITH-AES-128-GCM-SHA256",
+            Self::TLS_RSA_WITH_AES_256_GCM_SHA384 => "TLS-RSA-WITH-AES-256-GCM-SHA384",
+            Self::TLS_DHE_RSA_WITH_AES_128_GCM_SHA256 => "TLS-DHE-RSA-WITH-AES-128-GCM-SHA256",
+            Self::TLS_DHE_RSA_WITH_AES_256_GCM_SHA384 => "TLS-DHE-RSA-WITH-AES-256-GCM-SHA384",
+            Self::TLS_RSA_WITH_CAMELLIA_128_CBC_SHA256 => "TLS-RSA-WITH-CAMELLIA-128-CBC-SHA256",
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_128_CBC_SHA256 => {
+                "TLS-DHE-RSA-WITH-CAMELLIA-128-CBC-SHA256"
+            }
+            Self::TLS_RSA_WITH_CAMELLIA_256_CBC_SHA256 => "TLS-RSA-WITH-CAMELLIA-256-CBC-SHA256",
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_256_CBC_SHA256 => {
+                "TLS-DHE-RSA-WITH-CAMELLIA-256-CBC-SHA256"
+            }
+            Self::TLS_ECDH_ECDSA_WITH_NULL_SHA => "TLS-ECDH-ECDSA-WITH-NULL-SHA",
+            Self::TLS_ECDH_ECDSA_WITH_RC4_128_SHA => "TLS-ECDH-ECDSA-WITH-RC4-128-SHA",
+            Self::TLS_ECDH_ECDSA_WITH_3DES_EDE_CBC_SHA => "TLS-ECDH-ECDSA-WITH-3DES-EDE-CBC-SHA",
+            Self::TLS_ECDH_ECDSA_WITH_AES_128_CBC_SHA => "TLS-ECDH-ECDSA-WITH-AES-128-CBC-SHA",
+            Self::TLS_ECDH_ECDSA_WITH_AES_256_CBC_SHA => "TLS-ECDH-ECDSA-WITH-AES-256-CBC-SHA",
+            Self::TLS_ECDHE_ECDSA_WITH_NULL_SHA => "TLS-ECDHE-ECDSA-WITH-NULL-SHA",
+            Self::TLS_ECDHE_ECDSA_WITH_RC4_128_SHA => "TLS-ECDHE-ECDSA-WITH-RC4-128-SHA",
+            Self::TLS_ECDHE_ECDSA_WITH_3DES_EDE_CBC_SHA => "TLS-ECDHE-ECDSA-WITH-3DES-EDE-CBC-SHA",
+            Self::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA => "TLS-ECDHE-ECDSA-WITH-AES-128-CBC-SHA",
+            Self::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA => "TLS-ECDHE-ECDSA-WITH-AES-256-CBC-SHA",
+            Self::TLS_ECDH_RSA_WITH_NULL_SHA => "TLS-ECDH-RSA-WITH-NULL-SHA",
+            Self::TLS_ECDH_RSA_WITH_RC4_128_SHA => "TLS-ECDH-RSA-WITH-RC4-128-SHA",
+            Self::TLS_ECDH_RSA_WITH_3DES_EDE_CBC_SHA => "TLS-ECDH-RSA-WITH-3DES-EDE-CBC-SHA",
+            Self::TLS_ECDH_RSA_WITH_AES_128_CBC_SHA => "TLS-ECDH-RSA-WITH-AES-128-CBC-SHA",
+            Self::TLS_ECDH_RSA_WITH_AES_256_CBC_SHA => "TLS-ECDH-RSA-WITH-AES-256-CBC-SHA",
+            Self::TLS_ECDHE_RSA_WITH_NULL_SHA => "TLS-ECDHE-RSA-WITH-NULL-SHA",
+            Self::TLS_ECDHE_RSA_WITH_RC4_128_SHA => "TLS-ECDHE-RSA-WITH-RC4-128-SHA",
+            Self::TLS_ECDHE_RSA_WITH_3DES_EDE_CBC_SHA => "TLS-ECDHE-RSA-WITH-3DES-EDE-CBC-SHA",
+            Self::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA => "TLS-ECDHE-RSA-WITH-AES-128-CBC-SHA",
+            Self::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA => "TLS-ECDHE-RSA-WITH-AES-256-CBC-SHA",
+            Self::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA256 => {
+                "TLS-ECDHE-ECDSA-WITH-AES-128-CBC-SHA256"
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA384 => {
+                "TLS-ECDHE-ECDSA-WITH-AES-256-CBC-SHA384"
+            }
+            Self::TLS_ECDH_ECDSA_WITH_AES_128_CBC_SHA256 => {
+                "TLS-ECDH-ECDSA-WITH-AES-128-CBC-SHA256"
+            }
+            Self::TLS_ECDH_ECDSA_WITH_AES_256_CBC_SHA384 => {
+                "TLS-ECDH-ECDSA-WITH-AES-256-CBC-SHA384"
+            }
+            Self::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA256 => "TLS-ECDHE-RSA-WITH-AES-128-CBC-SHA256",
+            Self::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA384 => "TLS-ECDHE-RSA-WITH-AES-256-CBC-SHA384",
+            Self::TLS_ECDH_RSA_WITH_AES_128_CBC_SHA256 => "TLS-ECDH-RSA-WITH-AES-128-CBC-SHA256",
+            Self::TLS_ECDH_RSA_WITH_AES_256_CBC_SHA384 => "TLS-ECDH-RSA-WITH-AES-256-CBC-SHA384",
+            Self::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256 => {
+                "TLS-ECDHE-ECDSA-WITH-AES-128-GCM-SHA256"
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384 => {
+                "TLS-ECDHE-ECDSA-WITH-AES-256-GCM-SHA384"
+            }
+            Self::TLS_ECDH_ECDSA_WITH_AES_128_GCM_SHA256 => {
+                "TLS-ECDH-ECDSA-WITH-AES-128-GCM-SHA256"
+            }
+            Self::TLS_ECDH_ECDSA_WITH_AES_256_GCM_SHA384 => {
+                "TLS-ECDH-ECDSA-WITH-AES-256-GCM-SHA384"
+            }
+            Self::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256 => "TLS-ECDHE-RSA-WITH-AES-128-GCM-SHA256",
+            Self::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384 => "TLS-ECDHE-RSA-WITH-AES-256-GCM-SHA384",
+            Self::TLS_ECDH_RSA_WITH_AES_128_GCM_SHA256 => "TLS-ECDH-RSA-WITH-AES-128-GCM-SHA256",
+            Self::TLS_ECDH_RSA_WITH_AES_256_GCM_SHA384 => "TLS-ECDH-RSA-WITH-AES-256-GCM-SHA384",
+            Self::TLS_ECDHE_ECDSA_WITH_CAMELLIA_128_CBC_SHA256 => {
+                "TLS-ECDHE-ECDSA-WITH-CAMELLIA-128-CBC-SHA256"
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_CAMELLIA_256_CBC_SHA384 => {
+                "TLS-ECDHE-ECDSA-WITH-CAMELLIA-256-CBC-SHA384"
+            }
+            Self::TLS_ECDH_ECDSA_WITH_CAMELLIA_128_CBC_SHA256 => {
+                "TLS-ECDH-ECDSA-WITH-CAMELLIA-128-CBC-SHA256"
+            }
+            Self::TLS_ECDH_ECDSA_WITH_CAMELLIA_256_CBC_SHA384 => {
+                "TLS-ECDH-ECDSA-WITH-CAMELLIA-256-CBC-SHA384"
+            }
+            Self::TLS_ECDHE_RSA_WITH_CAMELLIA_128_CBC_SHA256 => {
+                "TLS-ECDHE-RSA-WITH-CAMELLIA-128-CBC-SHA256"
+            }
+            Self::TLS_ECDHE_RSA_WITH_CAMELLIA_256_CBC_SHA384 => {
+                "TLS-ECDHE-RSA-WITH-CAMELLIA-256-CBC-SHA384"
+            }
+            Self::TLS_ECDH_RSA_WITH_CAMELLIA_128_CBC_SHA256 => {
+                "TLS-ECDH-RSA-WITH-CAMELLIA-128-CBC-SHA256"
+            }
+            Self::TLS_ECDH_RSA_WITH_CAMELLIA_256_CBC_SHA384 => {
+                "TLS-ECDH-RSA-WITH-CAMELLIA-256-CBC-SHA384"
+            }
+            Self::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256 => {
+                "TLS-ECDHE-RSA-WITH-CHACHA20-POLY1305-SHA256"
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256 => {
+                "TLS-ECDHE-ECDSA-WITH-CHACHA20-POLY1305-SHA256"
+            }
+            Self::TLS_DHE_RSA_WITH_CHACHA20_POLY1305_SHA256 => {
+                "TLS-DHE-RSA-WITH-CHACHA20-POLY1305-SHA256"
+            }
+            Self::TLS_RSA_WITH_AES_128_CCM => "TLS-RSA-WITH-AES-128-CCM",
+            Self::TLS_RSA_WITH_AES_256_CCM => "TLS-RSA-WITH-AES-256-CCM",
+            Self::TLS_DHE_RSA_WITH_AES_128_CCM => "TLS-DHE-RSA-WITH-AES-128-CCM",
+            Self::TLS_DHE_RSA_WITH_AES_256_CCM => "TLS-DHE-RSA-WITH-AES-256-CCM",
+            Self::TLS_RSA_WITH_AES_128_CCM_8 => "TLS-RSA-WITH-AES-128-CCM-8",
+            Self::TLS_RSA_WITH_AES_256_CCM_8 => "TLS-RSA-WITH-AES-256-CCM-8",
+            Self::TLS_DHE_RSA_WITH_AES_128_CCM_8 => "TLS-DHE-RSA-WITH-AES-128-CCM-8",
+            Self::TLS_DHE_RSA_WITH_AES_256_CCM_8 => "TLS-DHE-RSA-WITH-AES-256-CCM-8",
+            Self::TLS_ECDHE_ECDSA_WITH_AES_128_CCM => "TLS-ECDHE-ECDSA-WITH-AES-128-CCM",
+            Self::TLS_ECDHE_ECDSA_WITH_AES_256_CCM => "TLS-ECDHE-ECDSA-WITH-AES-256-CCM",
+            Self::TLS_ECDHE_ECDSA_WITH_AES_128_CCM_8 => "TLS-ECDHE-ECDSA-WITH-AES-128-CCM-8",
+            Self::TLS_ECDHE_ECDSA_WITH_AES_256_CCM_8 => "TLS-ECDHE-ECDSA-WITH-AES-256-CCM-8",
+            Self::TLS_RSA_WITH_ARIA_128_GCM_SHA256 => "TLS-RSA-WITH-ARIA-128-GCM-SHA256",
+            Self::TLS_RSA_WITH_ARIA_256_GCM_SHA384 => "TLS-RSA-WITH-ARIA-256-GCM-SHA384",
+            Self::TLS_DHE_PSK_WITH_3DES_EDE_CBC_SHA => "TLS-DHE-PSK-WITH-3DES-EDE-CBC-SHA",
+            Self::TLS_DHE_PSK_WITH_AES_128_CBC_SHA => "TLS-DHE-PSK-WITH-AES-128-CBC-SHA",
+            Self::TLS_DHE_PSK_WITH_AES_128_CBC_SHA256 => "TLS-DHE-PSK-WITH-AES-128-CBC-SHA256",
+            Self::TLS_DHE_PSK_WITH_AES_128_CCM => "TLS-DHE-PSK-WITH-AES-128-CCM",
+            Self::TLS_PSK_DHE_WITH_AES_128_CCM_8 => "TLS-DHE-PSK-WITH-AES-128-CCM-8",
+            Self::TLS_DHE_PSK_WITH_AES_128_GCM_SHA256 => "TLS-DHE-PSK-WITH-AES-128-GCM-SHA256",
+            Self::TLS_DHE_PSK_WITH_AES_256_CBC_SHA => "TLS-DHE-PSK-WITH-AES-256-CBC-SHA",
+            Self::TLS_DHE_PSK_WITH_AES_256_CBC_SHA384 => "TLS-DHE-PSK-WITH-AES-256-CBC-SHA384",
+            Self::TLS_DHE_PSK_WITH_AES_256_CCM => "TLS-DHE-PSK-WITH-AES-256-CCM",
+            Self::TLS_PSK_DHE_WITH_AES_256_CCM_8 => "TLS-DHE-PSK-WITH-AES-256-CCM-8",
+            Self::TLS_DHE_PSK_WITH_AES_256_GCM_SHA384 => "TLS-DHE-PSK-WITH-AES-256-GCM-SHA384",
+            Self::TLS_DHE_PSK_WITH_ARIA_128_GCM_SHA256 => "TLS-DHE-PSK-WITH-ARIA-128-GCM-SHA256",
+            Self::TLS_DHE_PSK_WITH_ARIA_256_GCM_SHA384 => "TLS-DHE-PSK-WITH-ARIA-256-GCM-SHA384",
+            Self::TLS_DHE_PSK_WITH_CAMELLIA_128_CBC_SHA256 => {
+                "TLS-DHE-PSK-WITH-CAMELLIA-128-CBC-SHA256"
+            }
+            Self::TLS_DHE_PSK_WITH_CAMELLIA_256_CBC_SHA384 => {
+                "TLS-DHE-PSK-WITH-CAMELLIA-256-CBC-SHA384"
+            }
+            Self::TLS_DHE_PSK_WITH_CHACHA20_POLY1305_SHA256 => {
+                "TLS-DHE-PSK-WITH-CHACHA20-POLY1305-SHA256"
+            }
+            Self::TLS_DHE_PSK_WITH_NULL_SHA => "TLS-DHE-PSK-WITH-NULL-SHA",
+            Self::TLS_DHE_PSK_WITH_NULL_SHA256 => "TLS-DHE-PSK-WITH-NULL-SHA256",
+            Self::TLS_DHE_PSK_WITH_NULL_SHA384 => "TLS-DHE-PSK-WITH-NULL-SHA384",
+            Self::TLS_DHE_PSK_WITH_RC4_128_SHA => "TLS-DHE-PSK-WITH-RC4-128-SHA",
+            Self::TLS_DHE_RSA_WITH_ARIA_128_GCM_SHA256 => "TLS-DHE-RSA-WITH-ARIA-128-GCM-SHA256",
+            Self::TLS_DHE_RSA_WITH_ARIA_256_GCM_SHA384 => "TLS-DHE-RSA-WITH-ARIA-256-GCM-SHA384",
+            Self::TLS_ECDHE_RSA_WITH_ARIA_128_GCM_SHA256 => {
+                "TLS-ECDHE-RSA-WITH-ARIA-128-GCM-SHA256"
+            }
+            Self::TLS_ECDHE_RSA_WITH_ARIA_256_GCM_SHA384 => {
+                "TLS-ECDHE-RSA-WITH-ARIA-256-GCM-SHA384"
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_ARIA_128_GCM_SHA256 => {
+                "TLS-ECDHE-ECDSA-WITH-ARIA-128-GCM-SHA256"
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_ARIA_256_GCM_SHA384 => {
+                "TLS-ECDHE-ECDSA-WITH-ARIA-256-GCM-SHA384"
+            }
+            Self::TLS_ECDHE_PSK_WITH_3DES_EDE_CBC_SHA => "TLS-ECDHE-PSK-WITH-3DES-EDE-CBC-SHA",
+            Self::TLS_ECDHE_PSK_WITH_AES_128_CBC_SHA => "TLS-ECDHE-PSK-WITH-AES-128-CBC-SHA",
+            Self::TLS_ECDHE_PSK_WITH_AES_128_CBC_SHA256 => "TLS-ECDHE-PSK-WITH-AES-128-CBC-SHA256",
+            Self::TLS_ECDHE_PSK_WITH_AES_256_CBC_SHA => "TLS-ECDHE-PSK-WITH-AES-256-CBC-SHA",
+            Self::TLS_ECDHE_PSK_WITH_AES_256_CBC_SHA384 => "TLS-ECDHE-PSK-WITH-AES-256-CBC-SHA384",
+            Self::TLS_ECDHE_PSK_WITH_CAMELLIA_128_CBC_SHA256 => {
+                "TLS-ECDHE-PSK-WITH-CAMELLIA-128-CBC-SHA256"
+            }
+            Self::TLS_ECDHE_PSK_WITH_CAMELLIA_256_CBC_SHA384 => {
+                "TLS-ECDHE-PSK-WITH-CAMELLIA-256-CBC-SHA384"
+            }
+            Self::TLS_ECDHE_PSK_WITH_CHACHA20_POLY1305_SHA256 => {
+                "TLS-ECDHE-PSK-WITH-CHACHA20-POLY1305-SHA256"
+            }
+            Self::TLS_ECDHE_PSK_WITH_NULL_SHA => "TLS-ECDHE-PSK-WITH-NULL-SHA",
+            Self::TLS_ECDHE_PSK_WITH_NULL_SHA256 => "TLS-ECDHE-PSK-WITH-NULL-SHA256",
+            Self::TLS_ECDHE_PSK_WITH_NULL_SHA384 => "TLS-ECDHE-PSK-WITH-NULL-SHA384",
+            Self::TLS_ECDHE_PSK_WITH_RC4_128_SHA => "TLS-ECDHE-PSK-WITH-RC4-128-SHA",
+            Self::TLS_PSK_WITH_AES_128_CBC_SHA256 => "TLS-PSK-WITH-AES-128-CBC-SHA256",
+            Self::TLS_PSK_WITH_AES_128_CCM => "TLS-PSK-WITH-AES-128-CCM",
+            Self::TLS_PSK_WITH_AES_128_CCM_8 => "TLS-PSK-WITH-AES-128-CCM-8",
+            Self::TLS_PSK_WITH_AES_128_GCM_SHA256 => "TLS-PSK-WITH-AES-128-GCM-SHA256",
+            Self::TLS_PSK_WITH_AES_256_CBC_SHA384 => "TLS-PSK-WITH-AES-256-CBC-SHA384",
+            Self::TLS_PSK_WITH_AES_256_CCM => "TLS-PSK-WITH-AES-256-CCM",
+            Self::TLS_PSK_WITH_AES_256_CCM_8 => "TLS-PSK-WITH-AES-256-CCM-8",
+            Self::TLS_PSK_WITH_AES_256_GCM_SHA384 => "TLS-PSK-WITH-AES-256-GCM-SHA384",
+            Self::TLS_PSK_WITH_ARIA_128_GCM_SHA256 => "TLS-PSK-WITH-ARIA-128-GCM-SHA256",
+            Self::TLS_PSK_WITH_ARIA_256_GCM_SHA384 => "TLS-PSK-WITH-ARIA-256-GCM-SHA384",
+            Self::TLS_PSK_WITH_CAMELLIA_128_CBC_SHA256 => "TLS-PSK-WITH-CAMELLIA-128-CBC-SHA256",
+            Self::TLS_PSK_WITH_CAMELLIA_256_CBC_SHA384 => "TLS-PSK-WITH-CAMELLIA-256-CBC-SHA384",
+            Self::TLS_PSK_WITH_CHACHA20_POLY1305_SHA256 => "TLS-PSK-WITH-CHACHA20-POLY1305-SHA256",
+            Self::TLS_PSK_WITH_NULL_SHA => "TLS-PSK-WITH-NULL-SHA",
+            Self::TLS_PSK_WITH_NULL_SHA256 => "TLS-PSK-WITH-NULL-SHA256",
+            Self::TLS_PSK_WITH_NULL_SHA384 => "TLS-PSK-WITH-NULL-SHA384",
+            Self::TLS_RSA_PSK_WITH_AES_128_CBC_SHA256 => "TLS-RSA-PSK-WITH-AES-128-CBC-SHA256",
+            Self::TLS_RSA_PSK_WITH_AES_128_GCM_SHA256 => "TLS-RSA-PSK-WITH-AES-128-GCM-SHA256",
+            Self::TLS_RSA_PSK_WITH_AES_256_CBC_SHA384 => "TLS-RSA-PSK-WITH-AES-256-CBC-SHA384",
+            Self::TLS_RSA_PSK_WITH_AES_256_GCM_SHA384 => "TLS-RSA-PSK-WITH-AES-256-GCM-SHA384",
+            Self::TLS_RSA_PSK_WITH_ARIA_128_GCM_SHA256 => "TLS-RSA-PSK-WITH-ARIA-128-GCM-SHA256",
+            Self::TLS_RSA_PSK_WITH_ARIA_256_GCM_SHA384 => "TLS-RSA-PSK-WITH-ARIA-256-GCM-SHA384",
+            Self::TLS_RSA_PSK_WITH_CAMELLIA_128_CBC_SHA256 => {
+                "TLS-RSA-PSK-WITH-CAMELLIA-128-CBC-SHA256"
+            }
+            Self::TLS_RSA_PSK_WITH_CAMELLIA_256_CBC_SHA384 => {
+                "TLS-RSA-PSK-WITH-CAMELLIA-256-CBC-SHA384"
+            }
+            Self::TLS_RSA_PSK_WITH_CHACHA20_POLY1305_SHA256 => {
+                "TLS-RSA-PSK-WITH-CHACHA20-POLY1305-SHA256"
+            }
+            Self::TLS_RSA_PSK_WITH_NULL_SHA => "TLS-RSA-PSK-WITH-NULL-SHA",
+            Self::TLS_RSA_PSK_WITH_NULL_SHA256 => "TLS-RSA-PSK-WITH-NULL-SHA256",
+            Self::TLS_RSA_PSK_WITH_NULL_SHA384 => "TLS-RSA-PSK-WITH-NULL-SHA384",
+            Self::TLS_AES_128_GCM_SHA256 => "TLS1-3-AES-128-GCM-SHA256",
+            Self::TLS_AES_256_GCM_SHA384 => "TLS1-3-AES-256-GCM-SHA384",
+            Self::TLS_CHACHA20_POLY1305_SHA256 => "TLS1-3-CHACHA20-POLY1305-SHA256",
+            Self::TLS_AES_128_CCM_SHA256 => "TLS1-3-AES-128-CCM-SHA256",
+            Self::TLS_AES_128_CCM_8_SHA256 => "TLS1-3-AES-128-CCM-8-SHA256",
+            Self::TLS_RSA_WITH_ARIA_128_CBC_SHA256 => "TLS-RSA-WITH-ARIA-128-CBC-SHA256",
+            Self::TLS_RSA_WITH_ARIA_256_CBC_SHA384 => "TLS-RSA-WITH-ARIA-256-CBC-SHA384",
+            Self::TLS_DHE_RSA_WITH_ARIA_128_CBC_SHA256 => "TLS-DHE-RSA-WITH-ARIA-128-CBC-SHA256",
+            Self::TLS_DHE_RSA_WITH_ARIA_256_CBC_SHA384 => "TLS-DHE-RSA-WITH-ARIA-256-CBC-SHA384",
+            Self::TLS_ECDHE_ECDSA_WITH_ARIA_128_CBC_SHA256 => {
+                "TLS-ECDHE-ECDSA-WITH-ARIA-128-CBC-SHA256"
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_ARIA_256_CBC_SHA384 => {
+                "TLS-ECDHE-ECDSA-WITH-ARIA-256-CBC-SHA384"
+            }
+            Self::TLS_ECDH_ECDSA_WITH_ARIA_128_CBC_SHA256 => {
+                "TLS-ECDH-ECDSA-WITH-ARIA-128-CBC-SHA256"
+            }
+            Self::TLS_ECDH_ECDSA_WITH_ARIA_256_CBC_SHA384 => {
+                "TLS-ECDH-ECDSA-WITH-ARIA-256-CBC-SHA384"
+            }
+            Self::TLS_ECDHE_RSA_WITH_ARIA_128_CBC_SHA256 => {
+                "TLS-ECDHE-RSA-WITH-ARIA-128-CBC-SHA256"
+            }
+            Self::TLS_ECDHE_RSA_WITH_ARIA_256_CBC_SHA384 => {
+                "TLS-ECDHE-RSA-WITH-ARIA-256-CBC-SHA384"
+            }
+            Self::TLS_ECDH_RSA_WITH_ARIA_128_CBC_SHA256 => "TLS-ECDH-RSA-WITH-ARIA-128-CBC-SHA256",
+            Self::TLS_ECDH_RSA_WITH_ARIA_256_CBC_SHA384 => "TLS-ECDH-RSA-WITH-ARIA-256-CBC-SHA384",
+            Self::TLS_ECDH_ECDSA_WITH_ARIA_128_GCM_SHA256 => {
+                "TLS-ECDH-ECDSA-WITH-ARIA-128-GCM-SHA256"
+            }
+            Self::TLS_ECDH_ECDSA_WITH_ARIA_256_GCM_SHA384 => {
+                "TLS-ECDH-ECDSA-WITH-ARIA-256-GCM-SHA384"
+            }
+            Self::TLS_ECDH_RSA_WITH_ARIA_128_GCM_SHA256 => "TLS-ECDH-RSA-WITH-ARIA-128-GCM-SHA256",
+            Self::TLS_ECDH_RSA_WITH_ARIA_256_GCM_SHA384 => "TLS-ECDH-RSA-WITH-ARIA-256-GCM-SHA384",
+            Self::TLS_PSK_WITH_ARIA_128_CBC_SHA256 => "TLS-PSK-WITH-ARIA-128-CBC-SHA256",
+            Self::TLS_PSK_WITH_ARIA_256_CBC_SHA384 => "TLS-PSK-WITH-ARIA-256-CBC-SHA384",
+            Self::TLS_DHE_PSK_WITH_ARIA_128_CBC_SHA256 => "TLS-DHE-PSK-WITH-ARIA-128-CBC-SHA256",
+            Self::TLS_DHE_PSK_WITH_ARIA_256_CBC_SHA384 => "TLS-DHE-PSK-WITH-ARIA-256-CBC-SHA384",
+            Self::TLS_RSA_PSK_WITH_ARIA_128_CBC_SHA256 => "TLS-RSA-PSK-WITH-ARIA-128-CBC-SHA256",
+            Self::TLS_RSA_PSK_WITH_ARIA_256_CBC_SHA384 => "TLS-RSA-PSK-WITH-ARIA-256-CBC-SHA384",
+            Self::TLS_ECDHE_PSK_WITH_ARIA_128_CBC_SHA256 => {
+                "TLS-ECDHE-PSK-WITH-ARIA-128-CBC-SHA256"
+            }
+            Self::TLS_ECDHE_PSK_WITH_ARIA_256_CBC_SHA384 => {
+                "TLS-ECDHE-PSK-WITH-ARIA-256-CBC-SHA384"
+            }
+            Self::TLS_RSA_WITH_CAMELLIA_128_GCM_SHA256 => "TLS-RSA-WITH-CAMELLIA-128-GCM-SHA256",
+            Self::TLS_RSA_WITH_CAMELLIA_256_GCM_SHA384 => "TLS-RSA-WITH-CAMELLIA-256-GCM-SHA384",
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_128_GCM_SHA256 => {
+                "TLS-DHE-RSA-WITH-CAMELLIA-128-GCM-SHA256"
+            }
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_256_GCM_SHA384 => {
+                "TLS-DHE-RSA-WITH-CAMELLIA-256-GCM-SHA384"
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_CAMELLIA_128_GCM_SHA256 => {
+                "TLS-ECDHE-ECDSA-WITH-CAMELLIA-128-GCM-SHA256"
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_CAMELLIA_256_GCM_SHA384 => {
+                "TLS-ECDHE-ECDSA-WITH-CAMELLIA-256-GCM-SHA384"
+            }
+            Self::TLS_ECDH_ECDSA_WITH_CAMELLIA_128_GCM_SHA256 => {
+                "TLS-ECDH-ECDSA-WITH-CAMELLIA-128-GCM-SHA256"
+            }
+            Self::TLS_ECDH_ECDSA_WITH_CAMELLIA_256_GCM_SHA384 => {
+                "TLS-ECDH-ECDSA-WITH-CAMELLIA-256-GCM-SHA384"
+            }
+            Self::TLS_ECDHE_RSA_WITH_CAMELLIA_128_GCM_SHA256 => {
+                "TLS-ECDHE-RSA-WITH-CAMELLIA-128-GCM-SHA256"
+            }
+            Self::TLS_ECDHE_RSA_WITH_CAMELLIA_256_GCM_SHA384 => {
+                "TLS-ECDHE-RSA-WITH-CAMELLIA-256-GCM-SHA384"
+            }
+            Self::TLS_ECDH_RSA_WITH_CAMELLIA_128_GCM_SHA256 => {
+                "TLS-ECDH-RSA-WITH-CAMELLIA-128-GCM-SHA256"
+            }
+            Self::TLS_ECDH_RSA_WITH_CAMELLIA_256_GCM_SHA384 => {
+                "TLS-ECDH-RSA-WITH-CAMELLIA-256-GCM-SHA384"
+            }
+            Self::TLS_PSK_WITH_CAMELLIA_128_GCM_SHA256 => "TLS-PSK-WITH-CAMELLIA-128-GCM-SHA256",
+            Self::TLS_PSK_WITH_CAMELLIA_256_GCM_SHA384 => "TLS-PSK-WITH-CAMELLIA-256-GCM-SHA384",
+            Self::TLS_DHE_PSK_WITH_CAMELLIA_128_GCM_SHA256 => {
+                "TLS-DHE-PSK-WITH-CAMELLIA-128-GCM-SHA256"
+            }
+            Self::TLS_DHE_PSK_WITH_CAMELLIA_256_GCM_SHA384 => {
+                "TLS-DHE-PSK-WITH-CAMELLIA-256-GCM-SHA384"
+            }
+            Self::TLS_RSA_PSK_WITH_CAMELLIA_128_GCM_SHA256 => {
+                "TLS-RSA-PSK-WITH-CAMELLIA-128-GCM-SHA256"
+            }
+            Self::TLS_RSA_PSK_WITH_CAMELLIA_256_GCM_SHA384 => {
+                "TLS-RSA-PSK-WITH-CAMELLIA-256-GCM-SHA384"
+            }
+            _ => return None,
+        };
+        Some(name)
+    }
+
+    /// Returns the name of the `MBEDTLS_TLS_*` macro that holds the suite's
+    /// code point.
+    pub fn as_mbedtls_macro(&self) -> Option<String> {
+        self.as_mbedtls_name()
+            .map(|name| format!("MBEDTLS_{}", name.replace('-', "_")))
+    }
+
+    /// Resolves either an mbedTLS suite name or an `MBEDTLS_TLS_*` macro name.
+    pub fn from_mbedtls_name(name: &str) -> Option<TLSCipherSuite> {
+        let name = match name.strip_prefix("MBEDTLS_") {
+            Some(stem) => stem.replace('_', "-"),
+            None => name.to_owned(),
+        };
+        TLSCipherSuite::iter().find(|cipher| cipher.as_mbedtls_name() == Some(name.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_mbedtls() {
+        for cipher in TLSCipherSuite::iter() {
+            if let Some(name) = cipher.as_mbedtls_name() {
+                assert_eq!(TLSCipherSuite::from_mbedtls_name(name), Some(cipher));
+                let name = cipher.as_mbedtls_macro().unwrap();
+                assert_eq!(TLSCipherSuite::from_mbedtls_name(&name), Some(cipher));
+            }
+        }
+
+        assert_eq!(
+            TLSCipherSuite::TLS_AES_256_GCM_SHA384.as_mbedtls_name(),
+            Some("TLS1-3-AES-256-GCM-SHA384")
+        );
+        assert_eq!(
+            TLSCipherSuite::from_mbedtls_name("MBEDTLS_TLS1_3_CHACHA20_POLY1305_SHA256"),
+            Some(TLSCipherSuite::TLS_CHACHA20_POLY1305_SHA256)
+        );
+        assert_eq!(
+            TLSCipherSuite::from_mbedtls_name("TLS-PSK-WITH-AES-128-CCM-8"),
+            Some(TLSCipherSuite::TLS_PSK_WITH_AES_128_CCM_8)
+        );
+    }
+
+    #[test]
+    fn dhe_psk_ccm_8() {
+        for (cipher, name, mbedtls_macro) in [
+            (
+                TLSCipherSuite::TLS_PSK_DHE_WITH_AES_128_CCM_8,
+                "TLS-DHE-PSK-WITH-AES-128-CCM-8",
+                "MBEDTLS_TLS_DHE_PSK_WITH_AES_128_CCM_8",
+            ),
+            (
+                TLSCipherSuite::TLS_PSK_DHE_WITH_AES_256_CCM_8,
+                "TLS-DHE-PSK-WITH-AES-256-CCM-8",
+                "MBEDTLS_TLS_DHE_PSK_WITH_AES_256_CCM_8",
+            ),
+        ] {
+            assert_eq!(cipher.as_mbedtls_name(), Some(name));
+            assert_eq!(cipher.as_mbedtls_macro().as_deref(), Some(mbedtls_macro));
+            assert_eq!(TLSCipherSuite::from_mbedtls_name(name), Some(cipher));
+            assert_eq!(
+                TLSCipherSuite::from_mbedtls_name(mbedtls_macro),
+                Some(cipher)
+            );
+        }
+    }
+
+    #[test]
+    fn unimplemented_suites() {
+        for cipher in [
+            TLSCipherSuite::TLS_KRB5_WITH_3DES_EDE_CBC_SHA,
+            TLSCipherSuite::TLS_GOSTR341001_WITH_28147_CNT_IMIT,
+            TLSCipherSuite::TLS_SM4_GCM_SM3,
+            TLSCipherSuite::TLS_DH_anon_WITH_AES_128_CBC_SHA,
+            TLSCipherSuite::OLD_TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256,
+            TLSCipherSuite::TLS_EMPTY_RENEGOTIATION_INFO_SCSV,
+        ] {
+            assert_eq!(cipher.as_mbedtls_name(), None, "{}", cipher);
+        }
+        assert_eq!(
+            TLSCipherSuite::from_mbedtls_name("TLS-KRB5-WITH-3DES-EDE-CBC-SHA"),
+            None
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use crate::components::{Authentication, Cipher, KeyExchange};
+use crate::policy::assess_with;
+use crate::{Assessment, Policy, TLSCipherSuite, Verdict};
+
+const TLS13: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_AES_128_CCM_SHA256,
+    TLSCipherSuite::TLS_AES_128_CCM_8_SHA256,
+];
+
+const ECDSA: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CCM,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CCM,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CCM_8,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CCM_8,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA,
+];
+
+const RSA: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_128_CCM,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_CCM,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_128_CCM_8,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_CCM_8,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA384,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_128_CBC_SHA256,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_CBC_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_CBC_SHA,
+];
+
+const TLS13_SECTION: &str = "SP 800-52r2, Section 3.3.1.2";
+const TLS12_SECTION: &str = "SP 800-52r2, Section 3.3.1.1";
+const ECDSA_SECTION: &str = "SP 800-52r2, Section 3.3.1.1.1";
+const RSA_SECTION: &str = "SP 800-52r2, Section 3.3.1.1.2";
+
+/// NIST SP 800-52 Revision 2, the TLS guideline for US federal systems.
+///
+/// The guideline lists the suites a server may offer separately for RSA and
+/// ECDSA certificates, so the policy is tied to the server's certificate
+/// type. Only ephemeral key exchange with AES in GCM, CCM or CBC mode is
+/// approved for TLS 1.2; TLS 1.3 additionally excludes ChaCha20-Poly1305.
+///
+/// ```
+/// use openssl_to_rfc::{Nist80052r2, Policy, TLSCipherSuite};
+///
+/// let report = Nist80052r2::RSA.check(&[
+///     TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+///     TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA,
+/// ]);
+/// assert!(!report.is_compliant());
+///
+/// let violations: Vec<_> = report.violations().collect();
+/// assert_eq!(violations.len(), 1);
+/// assert_eq!(
+///     violations[0].cipher,
+///     TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Nist80052r2 {
+    /// The server certificate's key type, `Rsa` or `Ecdsa`. The guideline
+    /// approves no TLS 1.2 suites for other certificate types.
+    pub certificate: Authentication,
+}
+
+impl Nist80052r2 {
+    pub const RSA: Nist80052r2 = Nist80052r2 {
+        certificate: Authentication::Rsa,
+    };
+    pub const ECDSA: Nist80052r2 = Nist80052r2 {
+        certificate: Authentication::Ecdsa,
+    };
+}
+
+impl Policy for Nist80052r2 {
+    fn name(&self) -> String {
+        let certificate = match self.certificate {
+            Authentication::Rsa => "RSA",
+            Authentication::Ecdsa => "ECDSA",
+            Authentication::Dss => "DSA",
+            _ => "other",
+        };
+        format!("NIST SP 800-52r2 ({} certificate)", certificate)
+    }
+
+    fn assess(&self, cipher: TLSCipherSuite) -> Assessment {
+        assess_with(cipher, |c| self.judge(c))
+    }
+}
+
+impl Nist80052r2 {
+    fn judge(&self, cipher: TLSCipherSuite) -> (Verdict, &'static str, Option<&'static str>) {
+        use Verdict::*;
+
+        let c = cipher.components();
+        if cipher.is_tls13() {
+            return if TLS13.contains(&cipher) {
+                (Approved, "approved for TLS 1.3", Some(TLS13_SECTION))
+            } else if c.encryption.cipher == Cipher::ChaCha20 {
+                (
+                    NotApproved,
+                    "ChaCha20-Poly1305 is not a NIST-approved cipher",
+                    Some(TLS13_SECTION),
+                )
+            } else {
+                (NotApproved, "not listed for TLS 1.3", Some(TLS13_SECTION))
+            };
+        }
+
+        let (own, own_section) = match self.certificate {
+            Authentication::Rsa => (RSA, RSA_SECTION),
+            Authentication::Ecdsa => (ECDSA, ECDSA_SECTION),
+            _ => (&[][..], TLS12_SECTION),
+        };
+        if own.contains(&cipher) {
+            return (Approved, "approved for TLS 1.2", Some(own_section));
+        }
+        if RSA.contains(&cipher) {
+            return (
+                NotApproved,
+                "requires an RSA certificate",
+                Some(RSA_SECTION),
+            );
+        }
+        if ECDSA.contains(&cipher) {
+            return (
+                NotApproved,
+                "requires an ECDSA certificate",
+                Some(ECDSA_SECTION),
+            );
+        }
+
+        let reason = if c.authentication == Authentication::Null {
+            "anonymous key exchange"
+        } else if !c.forward_secrecy() || c.key_exchange == KeyExchange::EccPwd {
+            "key exchange is not ephemeral (EC)DH"
+        } else if c.encryption.cipher != Cipher::Aes {
+            "encryption is not AES"
+        } else {
+            "not listed for TLS 1.2"
+        };
+        (NotApproved, reason, Some(TLS12_SECTION))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn approved_counts() {
+        let count = |policy: Nist80052r2| {
+            TLSCipherSuite::iter()
+                .filter(|c| !c.is_signaling() && policy.assess(*c).is_approved())
+                .count()
+        };
+        assert_eq!(count(Nist80052r2::RSA), 20);
+        assert_eq!(count(Nist80052r2::ECDSA), 14);
+    }
+
+    #[test]
+    fn violations_in_order() {
+        let report = Nist80052r2::ECDSA.check(&[
+            TLSCipherSuite::TLS_CHACHA20_POLY1305_SHA256,
+            TLSCipherSuite::TLS_AES_256_GCM_SHA384,
+            TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+            TLSCipherSuite::TLS_RSA_WITH_3DES_EDE_CBC_SHA,
+        ]);
+        let violations: Vec<_> = report
+            .violations()
+            .map(|a| (a.cipher, a.reference.as_deref().unwrap()))
+            .collect();
+        assert_eq!(
+            violations,
+            vec![
+                (
+                    TLSCipherSuite::TLS_CHACHA20_POLY1305_SHA256,
+                    "SP 800-52r2, Section 3.3.1.2"
+                ),
+                (
+                    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+                    "SP 800-52r2, Section 3.3.1.1.2"
+                ),
+                (
+                    TLSCipherSuite::TLS_RSA_WITH_3DES_EDE_CBC_SHA,
+                    "SP 800-52r2, Section 3.3.1.1"
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,546 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{ProtocolVersion, TLSCipherSuite};
+
+/// An OpenSSL release series.
+///
+/// Only releases that changed the cipher suite table are distinguished, and
+/// all 3.x releases share `V3_0`. Releases are ordered, so
+/// `OpenSslVersion::V1_1_1 > OpenSslVersion::V1_0_2`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum OpenSslVersion {
+    V0_9_8,
+    V1_0_0,
+    V1_0_1,
+    V1_0_2,
+    V1_1_0,
+    V1_1_1,
+    V3_0,
+}
+
+impl fmt::Display for OpenSslVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::V0_9_8 => "0.9.8",
+            Self::V1_0_0 => "1.0.0",
+            Self::V1_0_1 => "1.0.1",
+            Self::V1_0_2 => "1.0.2",
+            Self::V1_1_0 => "1.1.0",
+            Self::V1_1_1 => "1.1.1",
+            Self::V3_0 => "3.0",
+        };
+        f.pad(s)
+    }
+}
+
+impl FromStr for OpenSslVersion {
+    type Err = ();
+
+    /// Parses a version as `openssl version` prints it, e.g. `1.0.2k`,
+    /// `OpenSSL 1.1.1w  11 Sep 2023` or `3.2.1`. Patch letters and any later
+    /// 3.x minor release are accepted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s.strip_prefix("OpenSSL ").unwrap_or(s);
+        let version = s.split_whitespace().next().ok_or(())?;
+        let version = version.trim_end_matches(|c: char| c.is_ascii_alphabetic() || c == '-');
+
+        let mut parts = version.split('.');
+        let major = parts.next().ok_or(())?;
+        let minor = parts.next().ok_or(())?;
+        let patch = parts.next().unwrap_or("0");
+
+        match (major, minor, patch) {
+            ("0", "9", "8") => Ok(Self::V0_9_8),
+            ("1", "0", "0") => Ok(Self::V1_0_0),
+            ("1", "0", "1") => Ok(Self::V1_0_1),
+            ("1", "0", "2") => Ok(Self::V1_0_2),
+            ("1", "1", "0") => Ok(Self::V1_1_0),
+            ("1", "1", "1") => Ok(Self::V1_1_1),
+            ("3", minor, _) if minor.parse::<u32>().is_ok() => Ok(Self::V3_0),
+            _ => Err(()),
+        }
+    }
+}
+
+impl TLSCipherSuite {
+    /// Returns the name the given OpenSSL release uses for the suite, or
+    /// `None` if that release does not implement it.
+    ///
+    /// ```
+    /// use openssl_to_rfc::{OpenSslVersion, TLSCipherSuite};
+    ///
+    /// let cipher = TLSCipherSuite::TLS_DHE_RSA_WITH_3DES_EDE_CBC_SHA;
+    /// assert_eq!(
+    ///     cipher.as_openssl_name_for(OpenSslVersion::V1_0_2).unwrap(),
+    ///     "EDH-RSA-DES-CBC3-SHA"
+    /// );
+    /// assert_eq!(
+    ///     cipher.as_openssl_name_for(OpenSslVersion::V1_1_1).unwrap(),
+    ///     "DHE-RSA-DES-CBC3-SHA"
+    /// );
+    /// ```
+    pub fn as_openssl_name_for(&self, version: OpenSslVersion) -> Option<String> {
+        if !self.supported_in(version) {
+            return None;
+        }
+
+        // 1.1.0 renamed the EDH- suites to DHE-. The only ones that survived
+        // the removal of single DES and export suites are these two.
+        let name = match self {
+            Self::TLS_DHE_RSA_WITH_3DES_EDE_CBC_SHA if version >= OpenSslVersion::V1_1_0 => {
+                "DHE-RSA-DES-CBC3-SHA".to_owned()
+            }
+            Self::TLS_DHE_DSS_WITH_3DES_EDE_CBC_SHA if version >= OpenSslVersion::V1_1_0 => {
+                "DHE-DSS-DES-CBC3-SHA".to_owned()
+            }
+            _ => self.as_openssl_name(),
+        };
+        Some(name)
+    }
+
+    /// Returns whether the given OpenSSL release can negotiate the suite.
+    ///
+    /// This reflects the release's cipher suite table with every optional
+    /// algorithm compiled in. A suite that needs e.g. `enable-weak-ssl-ciphers`,
+    /// the legacy provider or a GOST engine counts as supported.
+    pub fn supported_in(&self, version: OpenSslVersion) -> bool {
+        match self.openssl_releases() {
+            Some((added, removed)) => {
+                version >= added && !matches!(removed, Some(removed) if version >= removed)
+            }
+            None => false,
+        }
+    }
+
+    /// The release that added the suite and, if any, the release that removed
+    /// it again. `None` for suites mainline OpenSSL never implemented.
+    fn openssl_releases(&self) -> Option<(OpenSslVersion, Option<OpenSslVersion>)> {
+        use OpenSslVersion::*;
+
+        match self {
+            // 0.9.8m added the renegotiation indication SCSV.
+            Self::TLS_EMPTY_RENEGOTIATION_INFO_SCSV => Some((V0_9_8, None)),
+            // TLS_FALLBACK_SCSV first shipped in 0.9.8zc, 1.0.0o and 1.0.1j,
+            // so 1.0.2 is the first series that has it in every release.
+            Self::TLS_FALLBACK_SCSV => Some((V1_0_2, None)),
+            // The SSL 3.0 and TLS 1.0 suites of 0.9.8. It already built the ECC
+            // suites, though it left them out of DEFAULT.
+            Self::TLS_RSA_WITH_NULL_MD5
+            | Self::TLS_RSA_WITH_NULL_SHA
+            | Self::TLS_RSA_WITH_RC4_128_MD5
+            | Self::TLS_RSA_WITH_RC4_128_SHA
+            | Self::TLS_RSA_WITH_IDEA_CBC_SHA
+            | Self::TLS_RSA_WITH_3DES_EDE_CBC_SHA
+            | Self::TLS_DHE_DSS_WITH_3DES_EDE_CBC_SHA
+            | Self::TLS_DHE_RSA_WITH_3DES_EDE_CBC_SHA
+            | Self::TLS_DH_anon_WITH_RC4_128_MD5
+            | Self::TLS_DH_anon_WITH_3DES_EDE_CBC_SHA
+            | Self::TLS_RSA_WITH_AES_128_CBC_SHA
+            | Self::TLS_DHE_DSS_WITH_AES_128_CBC_SHA
+            | Self::TLS_DHE_RSA_WITH_AES_128_CBC_SHA
+            | Self::TLS_DH_anon_WITH_AES_128_CBC_SHA
+            | Self::TLS_RSA_WITH_AES_256_CBC_SHA
+            | Self::TLS_DHE_DSS_WITH_AES_256_CBC_SHA
+            | Self::TLS_DHE_RSA_WITH_AES_256_CBC_SHA
+            | Self::TLS_DH_anon_WITH_AES_256_CBC_SHA
+            | Self::TLS_RSA_WITH_CAMELLIA_128_CBC_SHA
+            | Self::TLS_DHE_DSS_WITH_CAMELLIA_128_CBC_SHA
+            | Self::TLS_DHE_RSA_WITH_CAMELLIA_128_CBC_SHA
+            | Self::TLS_DH_anon_WITH_CAMELLIA_128_CBC_SHA
+            | Self::TLS_RSA_WITH_CAMELLIA_256_CBC_SHA
+            | Self::TLS_DHE_DSS_WITH_CAMELLIA_256_CBC_SHA
+            | Self::TLS_DHE_RSA_WITH_CAMELLIA_256_CBC_SHA
+            | Self::TLS_DH_anon_WITH_CAMELLIA_256_CBC_SHA
+            | Self::TLS_RSA_WITH_SEED_CBC_SHA
+            | Self::TLS_DHE_DSS_WITH_SEED_CBC_SHA
+            | Self::TLS_DHE_RSA_WITH_SEED_CBC_SHA
+            | Self::TLS_DH_anon_WITH_SEED_CBC_SHA
+            | Self::TLS_ECDHE_ECDSA_WITH_NULL_SHA
+            | Self::TLS_ECDHE_ECDSA_WITH_RC4_128_SHA
+            | Self::TLS_ECDHE_ECDSA_WITH_3DES_EDE_CBC_SHA
+            | Self::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA
+            | Self::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA
+            | Self::TLS_ECDHE_RSA_WITH_NULL_SHA
+            | Self::TLS_ECDHE_RSA_WITH_RC4_128_SHA
+            | Self::TLS_ECDHE_RSA_WITH_3DES_EDE_CBC_SHA
+            | Self::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA
+            | Self::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA
+            | Self::TLS_ECDH_anon_WITH_NULL_SHA
+            | Self::TLS_ECDH_anon_WITH_RC4_128_SHA
+            | Self::TLS_ECDH_anon_WITH_3DES_EDE_CBC_SHA
+            | Self::TLS_ECDH_anon_WITH_AES_128_CBC_SHA
+            | Self::TLS_ECDH_anon_WITH_AES_256_CBC_SHA => Some((V0_9_8, None)),
+            // 0.9.8 was the last release to build the experimental 56-bit export
+            // suites.
+            Self::TLS_RSA_EXPORT1024_WITH_DES_CBC_SHA
+            | Self::TLS_DHE_DSS_EXPORT1024_WITH_DES_CBC_SHA
+            | Self::TLS_RSA_EXPORT1024_WITH_RC4_56_SHA
+            | Self::TLS_RSA_EXPORT1024_WITH_RC4_56_MD5
+            | Self::TLS_RSA_EXPORT1024_WITH_RC2_CBC_56_MD5
+            | Self::TLS_DHE_DSS_EXPORT1024_WITH_RC4_56_SHA
+            | Self::TLS_DHE_DSS_WITH_RC4_128_SHA => Some((V0_9_8, Some(V1_0_0))),
+            // 1.1.0 dropped the export, single DES and Kerberos suites.
+            Self::TLS_RSA_EXPORT_WITH_RC4_40_MD5
+            | Self::TLS_RSA_EXPORT_WITH_RC2_CBC_40_MD5
+            | Self::TLS_RSA_EXPORT_WITH_DES40_CBC_SHA
+            | Self::TLS_RSA_WITH_DES_CBC_SHA
+            | Self::TLS_DHE_DSS_EXPORT_WITH_DES40_CBC_SHA
+            | Self::TLS_DHE_DSS_WITH_DES_CBC_SHA
+            | Self::TLS_DHE_RSA_EXPORT_WITH_DES40_CBC_SHA
+            | Self::TLS_DHE_RSA_WITH_DES_CBC_SHA
+            | Self::TLS_DH_anon_EXPORT_WITH_RC4_40_MD5
+            | Self::TLS_DH_anon_EXPORT_WITH_DES40_CBC_SHA
+            | Self::TLS_DH_anon_WITH_DES_CBC_SHA
+            | Self::TLS_KRB5_WITH_DES_CBC_SHA
+            | Self::TLS_KRB5_WITH_3DES_EDE_CBC_SHA
+            | Self::TLS_KRB5_WITH_RC4_128_SHA
+            | Self::TLS_KRB5_WITH_IDEA_CBC_SHA
+            | Self::TLS_KRB5_WITH_DES_CBC_MD5
+            | Self::TLS_KRB5_WITH_3DES_EDE_CBC_MD5
+            | Self::TLS_KRB5_WITH_RC4_128_MD5
+            | Self::TLS_KRB5_WITH_IDEA_CBC_MD5
+            | Self::TLS_KRB5_EXPORT_WITH_DES_CBC_40_SHA
+            | Self::TLS_KRB5_EXPORT_WITH_RC2_CBC_40_SHA
+            | Self::TLS_KRB5_EXPORT_WITH_RC4_40_SHA
+            | Self::TLS_KRB5_EXPORT_WITH_DES_CBC_40_MD5
+            | Self::TLS_KRB5_EXPORT_WITH_RC2_CBC_40_MD5
+            | Self::TLS_KRB5_EXPORT_WITH_RC4_40_MD5
+            | Self::TLS_ECDH_ECDSA_WITH_NULL_SHA
+            | Self::TLS_ECDH_ECDSA_WITH_RC4_128_SHA
+            | Self::TLS_ECDH_ECDSA_WITH_3DES_EDE_CBC_SHA
+            | Self::TLS_ECDH_ECDSA_WITH_AES_128_CBC_SHA
+            | Self::TLS_ECDH_ECDSA_WITH_AES_256_CBC_SHA
+            | Self::TLS_ECDH_RSA_WITH_NULL_SHA
+            | Self::TLS_ECDH_RSA_WITH_RC4_128_SHA
+            | Self::TLS_ECDH_RSA_WITH_3DES_EDE_CBC_SHA
+            | Self::TLS_ECDH_RSA_WITH_AES_128_CBC_SHA
+            | Self::TLS_ECDH_RSA_WITH_AES_256_CBC_SHA => Some((V0_9_8, Some(V1_1_0))),
+            // 1.0.0 added the plain PSK suites of RFC 4279 and, through the ccgost
+            // engine, the GOST R 34.10-2001 suites.
+            Self::TLS_GOSTR341001_WITH_28147_CNT_IMIT
+            | Self::TLS_PSK_WITH_RC4_128_SHA
+            | Self::TLS_PSK_WITH_3DES_EDE_CBC_SHA
+            | Self::TLS_PSK_WITH_AES_128_CBC_SHA
+            | Self::TLS_PSK_WITH_AES_256_CBC_SHA
+            | Self::TLS_GOSTR341001_WITH_NULL_GOSTR3411 => Some((V1_0_0, None)),
+            // The GOST R 34.10-94 suites went with 1.1.0.
+            Self::TLS_GOSTR341094_WITH_28147_CNT_IMIT
+            | Self::TLS_GOSTR341094_WITH_NULL_GOSTR3411 => Some((V1_0_0, Some(V1_1_0))),
+            // 1.0.1 added TLS 1.2 with its SHA-2 and AES-GCM suites, and SRP.
+            Self::TLS_RSA_WITH_NULL_SHA256
+            | Self::TLS_RSA_WITH_AES_128_CBC_SHA256
+            | Self::TLS_RSA_WITH_AES_256_CBC_SHA256
+            | Self::TLS_DHE_DSS_WITH_AES_128_CBC_SHA256
+            | Self::TLS_DHE_RSA_WITH_AES_128_CBC_SHA256
+            | Self::TLS_DHE_DSS_WITH_AES_256_CBC_SHA256
+            | Self::TLS_DHE_RSA_WITH_AES_256_CBC_SHA256
+            | Self::TLS_DH_anon_WITH_AES_128_CBC_SHA256
+            | Self::TLS_DH_anon_WITH_AES_256_CBC_SHA256
+            | Self::TLS_RSA_WITH_AES_128_GCM_SHA256
+            | Self::TLS_RSA_WITH_AES_256_GCM_SHA384
+            | Self::TLS_DHE_RSA_WITH_AES_128_GCM_SHA256
+            | Self::TLS_DHE_RSA_WITH_AES_256_GCM_SHA384
+            | Self::TLS_DHE_DSS_WITH_AES_128_GCM_SHA256
+            | Self::TLS_DHE_DSS_WITH_AES_256_GCM_SHA384
+            | Self::TLS_DH_anon_WITH_AES_128_GCM_SHA256
+            | Self::TLS_DH_anon_WITH_AES_256_GCM_SHA384
+            | Self::TLS_SRP_SHA_WITH_3DES_EDE_CBC_SHA
+            | Self::TLS_SRP_SHA_RSA_WITH_3DES_EDE_CBC_SHA
+            | Self::TLS_SRP_SHA_DSS_WITH_3DES_EDE_CBC_SHA
+            | Self::TLS_SRP_SHA_WITH_AES_128_CBC_SHA
+            | Self::TLS_SRP_SHA_RSA_WITH_AES_128_CBC_SHA
+            | Self::TLS_SRP_SHA_DSS_WITH_AES_128_CBC_SHA
+            | Self::TLS_SRP_SHA_WITH_AES_256_CBC_SHA
+            | Self::TLS_SRP_SHA_RSA_WITH_AES_256_CBC_SHA
+            | Self::TLS_SRP_SHA_DSS_WITH_AES_256_CBC_SHA
+            | Self::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA256
+            | Self::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA384
+            | Self::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA256
+            | Self::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA384
+            | Self::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256
+            | Self::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384
+            | Self::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256
+            | Self::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384 => Some((V1_0_1, None)),
+            // The TLS 1.2 static ECDH suites, dropped in 1.1.0 with the older ones.
+            Self::TLS_ECDH_ECDSA_WITH_AES_128_CBC_SHA256
+            | Self::TLS_ECDH_ECDSA_WITH_AES_256_CBC_SHA384
+            | Self::TLS_ECDH_RSA_WITH_AES_128_CBC_SHA256
+            | Self::TLS_ECDH_RSA_WITH_AES_256_CBC_SHA384
+            | Self::TLS_ECDH_ECDSA_WITH_AES_128_GCM_SHA256
+            | Self::TLS_ECDH_ECDSA_WITH_AES_256_GCM_SHA384
+            | Self::TLS_ECDH_RSA_WITH_AES_128_GCM_SHA256
+            | Self::TLS_ECDH_RSA_WITH_AES_256_GCM_SHA384 => Some((V1_0_1, Some(V1_1_0))),
+            // Static DH suites were named since 0.9.8 but only implemented in
+            // 1.0.2, the last release before 1.1.0 removed them.
+            Self::TLS_DH_DSS_EXPORT_WITH_DES40_CBC_SHA
+            | Self::TLS_DH_DSS_WITH_DES_CBC_SHA
+            | Self::TLS_DH_DSS_WITH_3DES_EDE_CBC_SHA
+            | Self::TLS_DH_RSA_EXPORT_WITH_DES40_CBC_SHA
+            | Self::TLS_DH_RSA_WITH_DES_CBC_SHA
+            | Self::TLS_DH_RSA_WITH_3DES_EDE_CBC_SHA
+            | Self::TLS_DH_DSS_WITH_AES_128_CBC_SHA
+            | Self::TLS_DH_RSA_WITH_AES_128_CBC_SHA
+            | Self::TLS_DH_DSS_WITH_AES_256_CBC_SHA
+            | Self::TLS_DH_RSA_WITH_AES_256_CBC_SHA
+            | Self::TLS_DH_DSS_WITH_AES_128_CBC_SHA256
+            | Self::TLS_DH_RSA_WITH_AES_128_CBC_SHA256
+            | Self::TLS_DH_DSS_WITH_CAMELLIA_128_CBC_SHA
+            | Self::TLS_DH_RSA_WITH_CAMELLIA_128_CBC_SHA
+            | Self::TLS_DH_DSS_WITH_AES_256_CBC_SHA256
+            | Self::TLS_DH_RSA_WITH_AES_256_CBC_SHA256
+            | Self::TLS_DH_DSS_WITH_CAMELLIA_256_CBC_SHA
+            | Self::TLS_DH_RSA_WITH_CAMELLIA_256_CBC_SHA
+            | Self::TLS_DH_DSS_WITH_SEED_CBC_SHA
+            | Self::TLS_DH_RSA_WITH_SEED_CBC_SHA
+            | Self::TLS_DH_RSA_WITH_AES_128_GCM_SHA256
+            | Self::TLS_DH_RSA_WITH_AES_256_GCM_SHA384
+            | Self::TLS_DH_DSS_WITH_AES_128_GCM_SHA256
+            | Self::TLS_DH_DSS_WITH_AES_256_GCM_SHA384 => Some((V1_0_2, Some(V1_1_0))),
+            // 1.1.0 added CCM, ChaCha20-Poly1305, the Camellia SHA-2 suites and the
+            // RFC 5487 and RFC 5489 PSK suites.
+            Self::TLS_RSA_PSK_WITH_RC4_128_SHA
+            | Self::TLS_RSA_PSK_WITH_3DES_EDE_CBC_SHA
+            | Self::TLS_RSA_PSK_WITH_AES_128_CBC_SHA
+            | Self::TLS_RSA_PSK_WITH_AES_256_CBC_SHA
+            | Self::TLS_RSA_WITH_CAMELLIA_128_CBC_SHA256
+            | Self::TLS_DHE_DSS_WITH_CAMELLIA_128_CBC_SHA256
+            | Self::TLS_DHE_RSA_WITH_CAMELLIA_128_CBC_SHA256
+            | Self::TLS_DH_anon_WITH_CAMELLIA_128_CBC_SHA256
+            | Self::TLS_RSA_WITH_CAMELLIA_256_CBC_SHA256
+            | Self::TLS_DHE_DSS_WITH_CAMELLIA_256_CBC_SHA256
+            | Self::TLS_DHE_RSA_WITH_CAMELLIA_256_CBC_SHA256
+            | Self::TLS_DH_anon_WITH_CAMELLIA_256_CBC_SHA256
+            | Self::TLS_ECDHE_ECDSA_WITH_CAMELLIA_128_CBC_SHA256
+            | Self::TLS_ECDHE_ECDSA_WITH_CAMELLIA_256_CBC_SHA384
+            | Self::TLS_ECDHE_RSA_WITH_CAMELLIA_128_CBC_SHA256
+            | Self::TLS_ECDHE_RSA_WITH_CAMELLIA_256_CBC_SHA384
+            | Self::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256
+            | Self::TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256
+            | Self::TLS_DHE_RSA_WITH_CHACHA20_POLY1305_SHA256
+            | Self::TLS_RSA_WITH_AES_128_CCM
+            | Self::TLS_RSA_WITH_AES_256_CCM
+            | Self::TLS_DHE_RSA_WITH_AES_128_CCM
+            | Self::TLS_DHE_RSA_WITH_AES_256_CCM
+            | Self::TLS_RSA_WITH_AES_128_CCM_8
+            | Self::TLS_RSA_WITH_AES_256_CCM_8
+            | Self::TLS_DHE_RSA_WITH_AES_128_CCM_8
+            | Self::TLS_DHE_RSA_WITH_AES_256_CCM_8
+            | Self::TLS_ECDHE_ECDSA_WITH_AES_128_CCM
+            | Self::TLS_ECDHE_ECDSA_WITH_AES_256_CCM
+            | Self::TLS_ECDHE_ECDSA_WITH_AES_128_CCM_8
+            | Self::TLS_ECDHE_ECDSA_WITH_AES_256_CCM_8
+            | Self::TLS_DHE_PSK_WITH_3DES_EDE_CBC_SHA
+            | Self::TLS_DHE_PSK_WITH_AES_128_CBC_SHA
+            | Self::TLS_DHE_PSK_WITH_AES_128_CBC_SHA256
+            | Self::TLS_DHE_PSK_WITH_AES_128_CCM
+            | Self::TLS_PSK_DHE_WITH_AES_128_CCM_8
+            | Self::TLS_DHE_PSK_WITH_AES_128_GCM_SHA256
+            | Self::TLS_DHE_PSK_WITH_AES_256_CBC_SHA
+            | Self::TLS_DHE_PSK_WITH_AES_256_CBC_SHA384
+            | Self::TLS_DHE_PSK_WITH_AES_256_CCM
+            | Self::TLS_PSK_DHE_WITH_AES_256_CCM_8
+            | Self::TLS_DHE_PSK_WITH_AES_256_GCM_SHA384
+            | Self::TLS_DHE_PSK_WITH_CAMELLIA_128_CBC_SHA256
+            | Self::TLS_DHE_PSK_WITH_CAMELLIA_256_CBC_SHA384
+            | Self::TLS_DHE_PSK_WITH_CHACHA20_POLY1305_SHA256
+            | Self::TLS_DHE_PSK_WITH_NULL_SHA
+            | Self::TLS_DHE_PSK_WITH_NULL_SHA256
+            | Self::TLS_DHE_PSK_WITH_NULL_SHA384
+            | Self::TLS_DHE_PSK_WITH_RC4_128_SHA
+            | Self::TLS_ECDHE_PSK_WITH_3DES_EDE_CBC_SHA
+            | Self::TLS_ECDHE_PSK_WITH_AES_128_CBC_SHA
+            | Self::TLS_ECDHE_PSK_WITH_AES_128_CBC_SHA256
+            | Self::TLS_ECDHE_PSK_WITH_AES_256_CBC_SHA
+            | Self::TLS_ECDHE_PSK_WITH_AES_256_CBC_SHA384
+            | Self::TLS_ECDHE_PSK_WITH_CAMELLIA_128_CBC_SHA256
+            | Self::TLS_ECDHE_PSK_WITH_CAMELLIA_256_CBC_SHA384
+            | Self::TLS_ECDHE_PSK_WITH_CHACHA20_POLY1305_SHA256
+            | Self::TLS_ECDHE_PSK_WITH_NULL_SHA
+            | Self::TLS_ECDHE_PSK_WITH_NULL_SHA256
+            | Self::TLS_ECDHE_PSK_WITH_NULL_SHA384
+            | Self::TLS_ECDHE_PSK_WITH_RC4_128_SHA
+            | Self::TLS_PSK_WITH_AES_128_CBC_SHA256
+            | Self::TLS_PSK_WITH_AES_128_CCM
+            | Self::TLS_PSK_WITH_AES_128_CCM_8
+            | Self::TLS_PSK_WITH_AES_128_GCM_SHA256
+            | Self::TLS_PSK_WITH_AES_256_CBC_SHA384
+            | Self::TLS_PSK_WITH_AES_256_CCM
+            | Self::TLS_PSK_WITH_AES_256_CCM_8
+            | Self::TLS_PSK_WITH_AES_256_GCM_SHA384
+            | Self::TLS_PSK_WITH_CAMELLIA_128_CBC_SHA256
+            | Self::TLS_PSK_WITH_CAMELLIA_256_CBC_SHA384
+            | Self::TLS_PSK_WITH_CHACHA20_POLY1305_SHA256
+            | Self::TLS_PSK_WITH_NULL_SHA
+            | Self::TLS_PSK_WITH_NULL_SHA256
+            | Self::TLS_PSK_WITH_NULL_SHA384
+            | Self::TLS_RSA_PSK_WITH_AES_128_CBC_SHA256
+            | Self::TLS_RSA_PSK_WITH_AES_128_GCM_SHA256
+            | Self::TLS_RSA_PSK_WITH_AES_256_CBC_SHA384
+            | Self::TLS_RSA_PSK_WITH_AES_256_GCM_SHA384
+            | Self::TLS_RSA_PSK_WITH_CAMELLIA_128_CBC_SHA256
+            | Self::TLS_RSA_PSK_WITH_CAMELLIA_256_CBC_SHA384
+            | Self::TLS_RSA_PSK_WITH_CHACHA20_POLY1305_SHA256
+            | Self::TLS_RSA_PSK_WITH_NULL_SHA
+            | Self::TLS_RSA_PSK_WITH_NULL_SHA256
+            | Self::TLS_RSA_PSK_WITH_NULL_SHA384 => Some((V1_1_0, None)),
+            // 1.1.1 added TLS 1.3 and the ARIA-GCM suites.
+            Self::TLS_RSA_WITH_ARIA_128_GCM_SHA256
+            | Self::TLS_RSA_WITH_ARIA_256_GCM_SHA384
+            | Self::TLS_DHE_DSS_WITH_ARIA_128_GCM_SHA256
+            | Self::TLS_DHE_DSS_WITH_ARIA_256_GCM_SHA384
+            | Self::TLS_DHE_PSK_WITH_ARIA_128_GCM_SHA256
+            | Self::TLS_DHE_PSK_WITH_ARIA_256_GCM_SHA384
+            | Self::TLS_DHE_RSA_WITH_ARIA_128_GCM_SHA256
+            | Self::TLS_DHE_RSA_WITH_ARIA_256_GCM_SHA384
+            | Self::TLS_ECDHE_RSA_WITH_ARIA_128_GCM_SHA256
+            | Self::TLS_ECDHE_RSA_WITH_ARIA_256_GCM_SHA384
+            | Self::TLS_ECDHE_ECDSA_WITH_ARIA_128_GCM_SHA256
+            | Self::TLS_ECDHE_ECDSA_WITH_ARIA_256_GCM_SHA384
+            | Self::TLS_PSK_WITH_ARIA_128_GCM_SHA256
+            | Self::TLS_PSK_WITH_ARIA_256_GCM_SHA384
+            | Self::TLS_RSA_PSK_WITH_ARIA_128_GCM_SHA256
+            | Self::TLS_RSA_PSK_WITH_ARIA_256_GCM_SHA384
+            | Self::TLS_AES_128_GCM_SHA256
+            | Self::TLS_AES_256_GCM_SHA384
+            | Self::TLS_CHACHA20_POLY1305_SHA256
+            | Self::TLS_AES_128_CCM_SHA256
+            | Self::TLS_AES_128_CCM_8_SHA256 => Some((V1_1_1, None)),
+            // 3.0 added the GOST R 34.10-2012 suites with IANA code points.
+            Self::TLS_GOSTR341112_256_WITH_KUZNYECHIK_CTR_OMAC
+            | Self::TLS_GOSTR341112_256_WITH_MAGMA_CTR_OMAC
+            | Self::TLS_GOSTR341112_256_WITH_28147_CNT_IMIT => Some((V3_0, None)),
+            // The pre-RFC ChaCha20 suites only ever shipped in LibreSSL, BoringSSL
+            // and third-party patches, and OpenSSL named but never implemented the
+            // Camellia SHA-2 suites with static (EC)DH.
+            _ => None,
+        }
+    }
+}
+
+impl From<OpenSslVersion> for ProtocolVersion {
+    /// The newest protocol version the release can negotiate.
+    fn from(version: OpenSslVersion) -> ProtocolVersion {
+        match version {
+            OpenSslVersion::V0_9_8 | OpenSslVersion::V1_0_0 => ProtocolVersion::Tls10,
+            OpenSslVersion::V1_0_1 | OpenSslVersion::V1_0_2 | OpenSslVersion::V1_1_0 => {
+                ProtocolVersion::Tls12
+            }
+            OpenSslVersion::V1_1_1 | OpenSslVersion::V3_0 => ProtocolVersion::Tls13,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn parse_versions() {
+        assert_eq!("1.0.2k".parse(), Ok(OpenSslVersion::V1_0_2));
+        assert_eq!(
+            "OpenSSL 1.1.1w  11 Sep 2023".parse(),
+            Ok(OpenSslVersion::V1_1_1)
+        );
+        assert_eq!("3.5.6".parse(), Ok(OpenSslVersion::V3_0));
+        assert_eq!("0.9.8zh".parse(), Ok(OpenSslVersion::V0_9_8));
+        assert_eq!("2.0.0".parse::<OpenSslVersion>(), Err(()));
+        assert_eq!(OpenSslVersion::V1_1_0.to_string(), "1.1.0");
+    }
+
+    #[test]
+    fn fleet_support() {
+        use OpenSslVersion::*;
+
+        let cipher = TLSCipherSuite::TLS_RSA_EXPORT_WITH_RC4_40_MD5;
+        assert!(cipher.supported_in(V1_0_2));
+        assert!(!cipher.supported_in(V1_1_1));
+
+        let cipher = TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256;
+        assert!(!cipher.supported_in(V1_0_0));
+        assert!(cipher.supported_in(V1_0_1));
+        assert!(cipher.supported_in(V3_0));
+
+        let cipher = TLSCipherSuite::TLS_AES_128_GCM_SHA256;
+        assert!(!cipher.supported_in(V1_1_0));
+        assert_eq!(cipher.as_openssl_name_for(V1_0_2), None);
+
+        let cipher = TLSCipherSuite::OLD_TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256;
+        assert!(!cipher.supported_in(V1_0_2));
+    }
+
+    #[test]
+    fn ecc_support() {
+        use OpenSslVersion::*;
+
+        let cipher = TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA;
+        assert!(cipher.supported_in(V0_9_8));
+        assert!(cipher.supported_in(V3_0));
+
+        let cipher = TLSCipherSuite::TLS_ECDH_ECDSA_WITH_AES_128_CBC_SHA;
+        assert!(cipher.supported_in(V0_9_8));
+        assert!(!cipher.supported_in(V1_1_0));
+
+        let cipher = TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384;
+        assert!(!cipher.supported_in(V0_9_8));
+        assert!(cipher.supported_in(V1_0_1));
+    }
+
+    #[test]
+    fn gost_support() {
+        use OpenSslVersion::*;
+
+        let cipher = TLSCipherSuite::TLS_GOSTR341094_WITH_28147_CNT_IMIT;
+        assert!(!cipher.supported_in(V0_9_8));
+        assert!(cipher.supported_in(V1_0_2));
+        assert!(!cipher.supported_in(V1_1_0));
+
+        let cipher = TLSCipherSuite::TLS_GOSTR341001_WITH_28147_CNT_IMIT;
+        assert!(cipher.supported_in(V1_0_0));
+        assert!(cipher.supported_in(V3_0));
+
+        for cipher in [
+            TLSCipherSuite::TLS_GOSTR341112_256_WITH_28147_CNT_IMIT,
+            TLSCipherSuite::TLS_GOSTR341112_256_WITH_KUZNYECHIK_CTR_OMAC,
+            TLSCipherSuite::TLS_GOSTR341112_256_WITH_MAGMA_CTR_OMAC,
+        ] {
+            assert!(!cipher.supported_in(V1_1_1), "{}", cipher);
+            assert!(cipher.supported_in(V3_0), "{}", cipher);
+        }
+        assert_eq!(
+            TLSCipherSuite::TLS_GOSTR341112_256_WITH_28147_CNT_IMIT
+                .as_openssl_name_for(V3_0)
+                .unwrap(),
+            "IANA-GOST2012-GOST8912-GOST8912"
+        );
+    }
+
+    #[test]
+    fn signaling_support() {
+        use OpenSslVersion::*;
+
+        let cipher = TLSCipherSuite::TLS_EMPTY_RENEGOTIATION_INFO_SCSV;
+        assert!(cipher.supported_in(V0_9_8));
+
+        let cipher = TLSCipherSuite::TLS_FALLBACK_SCSV;
+        assert!(!cipher.supported_in(V1_0_1));
+        assert!(cipher.supported_in(V1_0_2));
+        assert!(cipher.supported_in(V3_0));
+    }
+
+    #[test]
+    fn suites_fit_protocol() {
+        for cipher in TLSCipherSuite::iter().filter(|c| !c.is_signaling()) {
+            if let Some((added, _)) = cipher.openssl_releases() {
+                assert!(
+                    cipher.components().min_version <= ProtocolVersion::from(added),
+                    "{} in {}",
+                    cipher,
+                    added
+                );
+            }
+        }
+    }
+}
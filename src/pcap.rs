@@ -0,0 +1,515 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::fmt::Write;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use crate::{CipherSuiteId, ClientHello, Extension, ServerHello};
+
+/// How much of each direction of a connection is reassembled. ClientHellos
+/// with post-quantum key shares run to a few kilobytes; this leaves plenty
+/// of room without buffering whole transfers.
+const MAX_STREAM: usize = 64 * 1024;
+
+/// Error returned by `read_capture` for input that is not a usable capture.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum PcapError {
+    /// The file is neither pcap nor pcapng.
+    BadMagic(u32),
+    /// The file ends in the middle of a header or block.
+    Truncated,
+    /// The capture uses a link layer other than Ethernet, Linux cooked,
+    /// BSD loopback or raw IP.
+    UnsupportedLinkType(u32),
+}
+
+impl fmt::Display for PcapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BadMagic(magic) => write!(f, "not a pcap or pcapng file: magic {:#010x}", magic),
+            Self::Truncated => f.write_str("truncated capture file"),
+            Self::UnsupportedLinkType(t) => write!(f, "unsupported link type {}", t),
+        }
+    }
+}
+
+impl std::error::Error for PcapError {}
+
+/// The TLS handshake seen on one TCP connection.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Flow {
+    pub client: SocketAddr,
+    pub server: SocketAddr,
+    pub client_hello: ClientHello,
+    /// The server's reply, if the capture contains one. In a TLS 1.3
+    /// handshake this may be a HelloRetryRequest.
+    pub server_hello: Option<ServerHello>,
+}
+
+impl Flow {
+    /// The suites the client offered, in its preference order.
+    pub fn offered(&self) -> &[CipherSuiteId] {
+        &self.client_hello.cipher_suites
+    }
+
+    /// The suite the server chose, if the capture contains its reply.
+    pub fn chosen(&self) -> Option<CipherSuiteId> {
+        self.server_hello.as_ref().map(|hello| hello.cipher_suite)
+    }
+}
+
+/// Reads a pcap or pcapng capture and returns every TCP connection on which
+/// a ClientHello was found, in the order the connections appear.
+///
+/// TCP is reassembled only as far as needed to read the ClientHello and
+/// ServerHello, so segments that arrive out of order or split a hello are
+/// handled, but retransmissions with different contents are not.
+pub fn read_capture(data: &[u8]) -> Result<Vec<Flow>, PcapError> {
+    let mut connections = Connections::default();
+    let magic = u32::from_le_bytes(
+        data.get(..4)
+            .ok_or(PcapError::Truncated)?
+            .try_into()
+            .unwrap(),
+    );
+
+    match magic {
+        0x0A0D0D0A => read_pcapng(data, &mut connections)?,
+        0xA1B2C3D4 | 0xA1B23C4D => read_pcap(data, Endian::Little, &mut connections)?,
+        0xD4C3B2A1 | 0x4D3CB2A1 => read_pcap(data, Endian::Big, &mut connections)?,
+        _ => return Err(PcapError::BadMagic(magic)),
+    }
+
+    Ok(connections.into_flows())
+}
+
+/// Formats flows with the OpenSSL and RFC name of every suite side by side.
+pub fn format_flows(flows: &[Flow]) -> String {
+    let mut out = String::new();
+    for flow in flows {
+        let _ = writeln!(out, "{} -> {}", flow.client, flow.server);
+        out.push_str("  offered:\n");
+        for id in flow.offered() {
+            out.push_str(&describe(*id));
+        }
+        let _ = writeln!(
+            out,
+            "  extensions: {}",
+            describe_extensions(&flow.client_hello.extensions)
+        );
+        match &flow.server_hello {
+            Some(hello) => {
+                let version = hello.negotiated().map(|n| n.version.to_string());
+                let _ = writeln!(
+                    out,
+                    "  chosen ({}):",
+                    version.as_deref().unwrap_or("invalid")
+                );
+                out.push_str(&describe(hello.cipher_suite));
+            }
+            None => out.push_str("  chosen:\n    (no ServerHello)\n"),
+        }
+    }
+    out
+}
+
+fn describe_extensions(extensions: &[Extension]) -> String {
+    let names: Vec<String> = extensions
+        .iter()
+        .map(|e| match e.known_type() {
+            Some(known) => known.to_string(),
+            None => format!("0x{:04X}", e.extension_type),
+        })
+        .collect();
+    names.join(", ")
+}
+
+fn describe(id: CipherSuiteId) -> String {
+    match id.known() {
+        Some(cipher) => format!("    {}  {:<30} {}\n", id, cipher.as_openssl_name(), cipher),
+        None if id.is_grease() => format!("    {}  (GREASE)\n", id),
+        None => format!("    {}  (unknown)\n", id),
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Endian {
+    Little,
+    Big,
+}
+
+impl Endian {
+    fn u16(self, b: &[u8]) -> u16 {
+        let b = [b[0], b[1]];
+        match self {
+            Self::Little => u16::from_le_bytes(b),
+            Self::Big => u16::from_be_bytes(b),
+        }
+    }
+
+    fn u32(self, b: &[u8]) -> u32 {
+        let b = [b[0], b[1], b[2], b[3]];
+        match self {
+            Self::Little => u32::from_le_bytes(b),
+            Self::Big => u32::from_be_bytes(b),
+        }
+    }
+}
+
+fn take(data: &[u8], length: usize) -> Result<(&[u8], &[u8]), PcapError> {
+    if data.len() < length {
+        return Err(PcapError::Truncated);
+    }
+    Ok(data.split_at(length))
+}
+
+fn read_pcap(data: &[u8], endian: Endian, connections: &mut Connections) -> Result<(), PcapError> {
+    let (header, mut data) = take(data, 24)?;
+    let link_type = endian.u32(&header[20..]);
+    check_link_type(link_type)?;
+
+    while !data.is_empty() {
+        let (record, rest) = take(data, 16)?;
+        let (frame, rest) = take(rest, endian.u32(&record[8..]) as usize)?;
+        connections.add_frame(link_type, frame);
+        data = rest;
+    }
+    Ok(())
+}
+
+fn read_pcapng(mut data: &[u8], connections: &mut Connections) -> Result<(), PcapError> {
+    let mut endian = Endian::Little;
+    let mut interfaces = Vec::new();
+
+    while !data.is_empty() {
+        let (header, _) = take(data, 12)?;
+        let block_type = u32::from_le_bytes([header[0], header[1], header[2], header[3]]);
+        if block_type == 0x0A0D0D0A {
+            // The section header's byte-order magic sets the endianness of
+            // everything up to the next section header.
+            endian = match endian.u32(&header[8..]) {
+                0x1A2B3C4D => endian,
+                _ => match endian {
+                    Endian::Little => Endian::Big,
+                    Endian::Big => Endian::Little,
+                },
+            };
+            interfaces.clear();
+        }
+
+        let length = endian.u32(&header[4..]) as usize;
+        if length < 12 {
+            return Err(PcapError::Truncated);
+        }
+        let (block, rest) = take(data, length)?;
+        let body = &block[8..length - 4];
+        data = rest;
+
+        match endian.u32(&block[..4]) {
+            // Interface description block.
+            1 => {
+                let link_type = u32::from(endian.u16(take(body, 2)?.0));
+                check_link_type(link_type)?;
+                interfaces.push(link_type);
+            }
+            // Enhanced packet block.
+            6 => {
+                let (fields, rest) = take(body, 20)?;
+                let interface = endian.u32(fields) as usize;
+                let (frame, _) = take(rest, endian.u32(&fields[12..]) as usize)?;
+                if let Some(link_type) = interfaces.get(interface) {
+                    connections.add_frame(*link_type, frame);
+                }
+            }
+            // Simple packet block, always on the first interface.
+            3 => {
+                let (fields, rest) = take(body, 4)?;
+                let length = (endian.u32(fields) as usize).min(rest.len());
+                if let Some(link_type) = interfaces.first() {
+                    connections.add_frame(*link_type, &rest[..length]);
+                }
+            }
+            // Obsolete packet block.
+            2 => {
+                let (fields, rest) = take(body, 20)?;
+                let interface = usize::from(endian.u16(fields));
+                let (frame, _) = take(rest, endian.u32(&fields[12..]) as usize)?;
+                if let Some(link_type) = interfaces.get(interface) {
+                    connections.add_frame(*link_type, frame);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn check_link_type(link_type: u32) -> Result<(), PcapError> {
+    match link_type {
+        0 | 1 | 101 | 113 | 228 | 229 | 276 => Ok(()),
+        _ => Err(PcapError::UnsupportedLinkType(link_type)),
+    }
+}
+
+/// Strips the link layer and returns the IP packet, if the frame carries one.
+fn ip_packet(link_type: u32, frame: &[u8]) -> Option<&[u8]> {
+    match link_type {
+        // BSD loopback: a host-endian address family.
+        0 => frame.get(4..),
+        // Ethernet, possibly with 802.1Q tags.
+        1 => {
+            let mut ethertype = u16::from_be_bytes([*frame.get(12)?, *frame.get(13)?]);
+            let mut offset = 14;
+            while ethertype == 0x8100 || ethertype == 0x88A8 {
+                ethertype = u16::from_be_bytes([*frame.get(offset + 2)?, *frame.get(offset + 3)?]);
+                offset += 4;
+            }
+            match ethertype {
+                0x0800 | 0x86DD => frame.get(offset..),
+                _ => None,
+            }
+        }
+        101 | 228 | 229 => Some(frame),
+        113 => frame.get(16..),
+        276 => frame.get(20..),
+        _ => None,
+    }
+}
+
+struct Segment<'a> {
+    src: SocketAddr,
+    dst: SocketAddr,
+    seq: u32,
+    syn: bool,
+    payload: &'a [u8],
+}
+
+fn tcp_segment(packet: &[u8]) -> Option<Segment<'_>> {
+    let (src, dst, tcp) = match packet.first()? >> 4 {
+        4 => {
+            let header_length = usize::from(packet[0] & 0x0F) * 4;
+            let total_length = usize::from(u16::from_be_bytes([*packet.get(2)?, *packet.get(3)?]));
+            let fragment = u16::from_be_bytes([*packet.get(6)?, *packet.get(7)?]);
+            // Skip non-TCP packets and all but unfragmented ones.
+            if *packet.get(9)? != 6 || fragment & 0x3FFF != 0 {
+                return None;
+            }
+            let src: [u8; 4] = packet.get(12..16)?.try_into().ok()?;
+            let dst: [u8; 4] = packet.get(16..20)?.try_into().ok()?;
+            let end = total_length.min(packet.len());
+            (
+                IpAddr::V4(Ipv4Addr::from(src)),
+                IpAddr::V4(Ipv4Addr::from(dst)),
+                packet.get(header_length..end)?,
+            )
+        }
+        6 => {
+            let payload_length =
+                usize::from(u16::from_be_bytes([*packet.get(4)?, *packet.get(5)?]));
+            let src: [u8; 16] = packet.get(8..24)?.try_into().ok()?;
+            let dst: [u8; 16] = packet.get(24..40)?.try_into().ok()?;
+            let mut next_header = *packet.get(6)?;
+            let mut offset = 40;
+            // Walk hop-by-hop, routing and destination options headers.
+            while matches!(next_header, 0 | 43 | 60) {
+                next_header = *packet.get(offset)?;
+                offset += (usize::from(*packet.get(offset + 1)?) + 1) * 8;
+            }
+            if next_header != 6 {
+                return None;
+            }
+            let end = (40 + payload_length).min(packet.len());
+            (
+                IpAddr::V6(Ipv6Addr::from(src)),
+                IpAddr::V6(Ipv6Addr::from(dst)),
+                packet.get(offset..end)?,
+            )
+        }
+        _ => return None,
+    };
+
+    let src_port = u16::from_be_bytes([*tcp.first()?, *tcp.get(1)?]);
+    let dst_port = u16::from_be_bytes([*tcp.get(2)?, *tcp.get(3)?]);
+    let seq = u32::from_be_bytes(tcp.get(4..8)?.try_into().ok()?);
+    let data_offset = usize::from(tcp.get(12)? >> 4) * 4;
+    let syn = tcp.get(13)? & 0x02 != 0;
+
+    Some(Segment {
+        src: SocketAddr::new(src, src_port),
+        dst: SocketAddr::new(dst, dst_port),
+        seq,
+        syn,
+        payload: tcp.get(data_offset..)?,
+    })
+}
+
+/// One direction of a TCP connection.
+#[derive(Default)]
+struct Stream {
+    /// The sequence number of the first payload byte, once known.
+    base: Option<u32>,
+    /// Payload keyed by offset from `base`. Offsets are signed because
+    /// segments may arrive before the one `base` was taken from.
+    segments: BTreeMap<i64, Vec<u8>>,
+    buffered: usize,
+}
+
+impl Stream {
+    fn add(&mut self, segment: &Segment<'_>) {
+        if segment.syn {
+            let isn = segment.seq.wrapping_add(1);
+            // Re-key anything seen before the SYN.
+            if let Some(base) = self.base {
+                let shift = i64::from(base.wrapping_sub(isn) as i32);
+                self.segments = std::mem::take(&mut self.segments)
+                    .into_iter()
+                    .map(|(offset, data)| (offset + shift, data))
+                    .collect();
+            }
+            self.base = Some(isn);
+        }
+        if segment.payload.is_empty() || self.buffered >= MAX_STREAM {
+            return;
+        }
+
+        let base = *self.base.get_or_insert(segment.seq);
+        let offset = i64::from(segment.seq.wrapping_sub(base) as i32);
+        self.buffered += segment.payload.len();
+        self.segments
+            .entry(offset)
+            .or_insert_with(|| segment.payload.to_vec());
+    }
+
+    /// Returns the contiguous bytes from the start of the stream.
+    fn assemble(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        let mut cursor = match self.segments.keys().next() {
+            Some(start) => *start,
+            None => return out,
+        };
+        for (offset, data) in &self.segments {
+            if *offset > cursor {
+                break;
+            }
+            let skip = (cursor - offset) as usize;
+            if skip < data.len() {
+                out.extend_from_slice(&data[skip..]);
+                cursor += (data.len() - skip) as i64;
+            }
+        }
+        out
+    }
+}
+
+#[derive(Default)]
+struct Connections {
+    streams: HashMap<(SocketAddr, SocketAddr), Stream>,
+    /// Connections in order of appearance, each under one of its directions.
+    order: Vec<(SocketAddr, SocketAddr)>,
+}
+
+impl Connections {
+    fn add_frame(&mut self, link_type: u32, frame: &[u8]) {
+        let segment = match ip_packet(link_type, frame).and_then(tcp_segment) {
+            Some(segment) => segment,
+            None => return,
+        };
+
+        let key = (segment.src, segment.dst);
+        if !self.streams.contains_key(&key) && !self.streams.contains_key(&(key.1, key.0)) {
+            self.order.push(key);
+        }
+        self.streams.entry(key).or_default().add(&segment);
+    }
+
+    fn into_flows(self) -> Vec<Flow> {
+        let mut flows = Vec::new();
+        for (a, b) in &self.order {
+            let stream = |src: &SocketAddr, dst: &SocketAddr| {
+                self.streams
+                    .get(&(*src, *dst))
+                    .map(Stream::assemble)
+                    .unwrap_or_default()
+            };
+            let (ab, ba) = (stream(a, b), stream(b, a));
+
+            let (client, server, client_hello, server_stream) =
+                match (ClientHello::from_stream(&ab), ClientHello::from_stream(&ba)) {
+                    (Ok(hello), _) => (*a, *b, hello, ba),
+                    (_, Ok(hello)) => (*b, *a, hello, ab),
+                    _ => continue,
+                };
+
+            flows.push(Flow {
+                client,
+                server,
+                client_hello,
+                server_hello: ServerHello::from_stream(&server_stream).ok(),
+            });
+        }
+        flows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TLSCipherSuite;
+
+    #[test]
+    fn read_pcap_fixture() {
+        let flows = read_capture(include_bytes!("../tests/fixtures/handshakes.pcap")).unwrap();
+        assert_eq!(flows.len(), 2);
+
+        assert_eq!(flows[0].client, "10.0.0.1:50000".parse().unwrap());
+        assert_eq!(flows[0].server, "10.0.0.2:443".parse().unwrap());
+        assert!(flows[0]
+            .offered()
+            .contains(&TLSCipherSuite::TLS_AES_256_GCM_SHA384.into()));
+        assert_eq!(
+            flows[0].chosen(),
+            Some(TLSCipherSuite::TLS_AES_256_GCM_SHA384.into())
+        );
+
+        assert_eq!(
+            flows[1].offered(),
+            [
+                TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256.into(),
+                TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA.into(),
+            ]
+        );
+        assert_eq!(
+            flows[1].chosen(),
+            Some(TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256.into())
+        );
+
+        let report = format_flows(&flows[1..]);
+        assert!(report.contains("  chosen (TLSv1.2):\n"));
+        assert!(report.contains(
+            "    0xC0,0x2F  ECDHE-RSA-AES128-GCM-SHA256    TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256\n"
+        ));
+    }
+
+    #[test]
+    fn read_pcapng_fixture() {
+        let flows =
+            read_capture(include_bytes!("../tests/fixtures/handshake-ipv6.pcapng")).unwrap();
+        assert_eq!(flows.len(), 1);
+        assert_eq!(flows[0].client, "[2001:db8::1]:50002".parse().unwrap());
+        assert_eq!(
+            flows[0].chosen(),
+            Some(TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256.into())
+        );
+    }
+
+    #[test]
+    fn reject_garbage() {
+        assert_eq!(
+            read_capture(b"GIF89a"),
+            Err(PcapError::BadMagic(0x38464947))
+        );
+        assert_eq!(
+            read_capture(&[0xD4, 0xC3, 0xB2, 0xA1, 0x02]),
+            Err(PcapError::Truncated)
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use crate::components::{Authentication, Cipher, KeyExchange};
+use crate::policy::assess_with;
+use crate::{Assessment, Policy, TLSCipherSuite, Verdict};
+
+const STRONG_CRYPTOGRAPHY: &str = "PCI DSS v4.0, Requirement 4.2.1 and Appendix G";
+const ASV_SCANS: &str = "PCI DSS v4.0, Requirement 11.3.2";
+
+/// PCI DSS v4.0, the Payment Card Industry Data Security Standard.
+///
+/// PCI DSS does not list cipher suites. Requirement 4.2.1 asks for "strong
+/// cryptography", which the glossary defines as at least 112 bits of
+/// effective strength with trusted keys, so NULL, export, anonymous and
+/// weak ciphers are not approved. RC4 and 3DES are also rejected because
+/// their known vulnerabilities fail the quarterly ASV scans of Requirement
+/// 11.3.2.
+///
+/// Requirement 4.2.1 also rules out SSL and TLS 1.0, which is a matter of
+/// protocol configuration rather than of the suites.
+///
+/// ```
+/// use openssl_to_rfc::{PciDss4, Policy, TLSCipherSuite};
+///
+/// let report = PciDss4.check(&[
+///     TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+///     TLSCipherSuite::TLS_RSA_WITH_3DES_EDE_CBC_SHA,
+/// ]);
+/// assert!(report.assessments[0].is_approved());
+/// assert!(!report.assessments[1].is_approved());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct PciDss4;
+
+impl Policy for PciDss4 {
+    fn name(&self) -> String {
+        "PCI DSS v4.0".to_owned()
+    }
+
+    fn assess(&self, cipher: TLSCipherSuite) -> Assessment {
+        assess_with(cipher, judge)
+    }
+}
+
+fn judge(cipher: TLSCipherSuite) -> (Verdict, &'static str, Option<&'static str>) {
+    use Verdict::*;
+
+    let c = cipher.components();
+    let not_strong = |reason| (NotApproved, reason, Some(STRONG_CRYPTOGRAPHY));
+    match c.encryption.cipher {
+        Cipher::Null => return not_strong("no encryption"),
+        Cipher::Rc4 => {
+            return (
+                NotApproved,
+                "RC4 has known biases (CVE-2013-2566, CVE-2015-2808)",
+                Some(ASV_SCANS),
+            )
+        }
+        Cipher::TripleDes => {
+            return (
+                NotApproved,
+                "64-bit block cipher vulnerable to Sweet32 (CVE-2016-2183)",
+                Some(ASV_SCANS),
+            )
+        }
+        _ => {}
+    }
+    if c.export {
+        return not_strong("export-grade key exchange and encryption");
+    }
+    if c.authentication == Authentication::Null || c.key_exchange == KeyExchange::Null {
+        return not_strong("anonymous key exchange does not authenticate the server");
+    }
+    if c.encryption.strength_bits() < 112 {
+        return not_strong("encryption is weaker than 112 bits");
+    }
+    (
+        Approved,
+        "strong cryptography of at least 112 bits",
+        Some(STRONG_CRYPTOGRAPHY),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verdicts() {
+        let approved = |cipher: TLSCipherSuite| PciDss4.assess(cipher).is_approved();
+        assert!(approved(TLSCipherSuite::TLS_AES_256_GCM_SHA384));
+        assert!(approved(TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA));
+        assert!(approved(TLSCipherSuite::TLS_PSK_WITH_AES_128_GCM_SHA256));
+        assert!(!approved(TLSCipherSuite::TLS_RSA_WITH_NULL_SHA256));
+        assert!(!approved(TLSCipherSuite::TLS_RSA_WITH_RC4_128_SHA));
+        assert!(!approved(TLSCipherSuite::TLS_RSA_WITH_DES_CBC_SHA));
+        assert!(!approved(TLSCipherSuite::TLS_RSA_EXPORT_WITH_RC4_40_MD5));
+        assert!(!approved(
+            TLSCipherSuite::TLS_DH_anon_WITH_AES_256_GCM_SHA384
+        ));
+    }
+}
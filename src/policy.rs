@@ -0,0 +1,123 @@
+use std::fmt;
+
+use crate::TLSCipherSuite;
+
+/// Whether a policy permits a cipher suite.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Verdict {
+    Approved,
+    NotApproved,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Approved => "approved",
+            Self::NotApproved => "not approved",
+        };
+        f.pad(s)
+    }
+}
+
+/// A policy's verdict on a single cipher suite.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Assessment {
+    pub cipher: TLSCipherSuite,
+    pub verdict: Verdict,
+    /// Why the policy reached the verdict.
+    pub reason: String,
+    /// The part of the policy document the verdict is based on, if any.
+    pub reference: Option<String>,
+}
+
+impl Assessment {
+    pub fn is_approved(&self) -> bool {
+        self.verdict == Verdict::Approved
+    }
+}
+
+/// A set of rules that cipher suite configurations are checked against.
+///
+/// Implementations only need to judge single suites; `check` applies the
+/// policy to a whole list.
+pub trait Policy {
+    /// The policy's name as it should appear in reports.
+    fn name(&self) -> String;
+
+    /// Judges a single suite.
+    fn assess(&self, cipher: TLSCipherSuite) -> Assessment;
+
+    /// Judges every suite of a list, keeping the list's order.
+    fn check(&self, ciphers: &[TLSCipherSuite]) -> Report {
+        Report {
+            policy: self.name(),
+            assessments: ciphers.iter().map(|c| self.assess(*c)).collect(),
+        }
+    }
+}
+
+/// Builds the `Assessment` of a preset policy from its table lookup, which
+/// returns the verdict, the reason and the section it is based on.
+///
+/// Signaling values are approved without consulting `judge`, since they are
+/// never negotiated.
+pub(crate) fn assess_with(
+    cipher: TLSCipherSuite,
+    judge: impl FnOnce(TLSCipherSuite) -> (Verdict, &'static str, Option<&'static str>),
+) -> Assessment {
+    let (verdict, reason, reference) = if cipher.is_signaling() {
+        (Verdict::Approved, "signaling value, never negotiated", None)
+    } else {
+        judge(cipher)
+    };
+    Assessment {
+        cipher,
+        verdict,
+        reason: reason.to_owned(),
+        reference: reference.map(str::to_owned),
+    }
+}
+
+/// The result of checking a cipher suite list against a `Policy`.
+///
+/// The `Display` output lists every suite with its verdict and is meant to
+/// be pasted into audit reports as is.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Report {
+    pub policy: String,
+    /// One assessment per suite, in the order of the checked list.
+    pub assessments: Vec<Assessment>,
+}
+
+impl Report {
+    /// Returns true if the policy approves every suite.
+    pub fn is_compliant(&self) -> bool {
+        self.assessments.iter().all(Assessment::is_approved)
+    }
+
+    /// Returns the suites the policy does not approve, in list order.
+    pub fn violations(&self) -> impl Iterator<Item = &Assessment> {
+        self.assessments.iter().filter(|a| !a.is_approved())
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let violations = self.violations().count();
+        writeln!(
+            f,
+            "{}: {} of {} suites not approved",
+            self.policy,
+            violations,
+            self.assessments.len()
+        )?;
+        for a in &self.assessments {
+            write!(f, "  {:<12} {}: {}", a.verdict, a.cipher, a.reason)?;
+            if let Some(reference) = &a.reference {
+                write!(f, " ({})", reference)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use std::str::FromStr;
+
+use crate::TLSCipherSuite;
+
+/// A vendor's naming convention for cipher suites.
+///
+/// Implement this for a unit struct to teach the crate a new convention, then
+/// pass it to `translate` alongside the built-in `OpenSslNames` and
+/// `RfcNames`:
+///
+/// ```
+/// use openssl_to_rfc::{translate, NameScheme, RfcNames, TLSCipherSuite};
+///
+/// /// Go's crypto/tls constant names.
+/// struct GoNames;
+///
+/// impl NameScheme for GoNames {
+///     fn parse(&self, name: &str) -> Option<TLSCipherSuite> {
+///         match name {
+///             "TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256" => {
+///                 Some(TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256)
+///             }
+///             "TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305" => {
+///                 Some(TLSCipherSuite::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256)
+///             }
+///             _ => None,
+///         }
+///     }
+///
+///     fn format(&self, cipher: TLSCipherSuite) -> Option<&'static str> {
+///         match cipher {
+///             TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256 => {
+///                 Some("TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256")
+///             }
+///             TLSCipherSuite::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256 => {
+///                 Some("TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305")
+///             }
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// assert_eq!(
+///     translate("TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305", &GoNames, &RfcNames),
+///     Some("TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256")
+/// );
+/// ```
+pub trait NameScheme {
+    /// Resolves a name in this scheme, or returns `None` if it is not one.
+    fn parse(&self, name: &str) -> Option<TLSCipherSuite>;
+
+    /// Returns the suite's name in this scheme, or `None` if it has none.
+    fn format(&self, cipher: TLSCipherSuite) -> Option<&'static str>;
+}
+
+/// OpenSSL's names, as used by `from_openssl_name` and `as_openssl_name`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct OpenSslNames;
+
+impl NameScheme for OpenSslNames {
+    fn parse(&self, name: &str) -> Option<TLSCipherSuite> {
+        TLSCipherSuite::from_openssl_name(name)
+    }
+
+    fn format(&self, cipher: TLSCipherSuite) -> Option<&'static str> {
+        Some(cipher.openssl_name())
+    }
+}
+
+/// The IANA registry's names, as used by `Display` and `FromStr`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct RfcNames;
+
+impl NameScheme for RfcNames {
+    fn parse(&self, name: &str) -> Option<TLSCipherSuite> {
+        TLSCipherSuite::from_str(name).ok()
+    }
+
+    fn format(&self, cipher: TLSCipherSuite) -> Option<&'static str> {
+        Some(cipher.into())
+    }
+}
+
+/// mbedTLS's names, as used by `from_mbedtls_name` and `as_mbedtls_name`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct MbedTlsNames;
+
+impl NameScheme for MbedTlsNames {
+    fn parse(&self, name: &str) -> Option<TLSCipherSuite> {
+        TLSCipherSuite::from_mbedtls_name(name)
+    }
+
+    fn format(&self, cipher: TLSCipherSuite) -> Option<&'static str> {
+        cipher.as_mbedtls_name()
+    }
+}
+
+/// wolfSSL's names, as used by `from_wolfssl_name` and `as_wolfssl_name`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct WolfSslNames;
+
+impl NameScheme for WolfSslNames {
+    fn parse(&self, name: &str) -> Option<TLSCipherSuite> {
+        TLSCipherSuite::from_wolfssl_name(name)
+    }
+
+    fn format(&self, cipher: TLSCipherSuite) -> Option<&'static str> {
+        cipher.as_wolfssl_name()
+    }
+}
+
+/// Translates a name from one scheme into another.
+///
+/// ```
+/// use openssl_to_rfc::{translate, OpenSslNames, RfcNames};
+///
+/// assert_eq!(
+///     translate("ECDHE-RSA-AES128-GCM-SHA256", &OpenSslNames, &RfcNames),
+///     Some("TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256")
+/// );
+/// ```
+pub fn translate(name: &str, from: &dyn NameScheme, to: &dyn NameScheme) -> Option<&'static str> {
+    from.parse(name).and_then(|cipher| to.format(cipher))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn round_trip_schemes() {
+        let schemes: [&dyn NameScheme; 4] =
+            [&OpenSslNames, &RfcNames, &MbedTlsNames, &WolfSslNames];
+        for cipher in TLSCipherSuite::iter() {
+            assert!(OpenSslNames.format(cipher).is_some());
+            assert!(RfcNames.format(cipher).is_some());
+            for from in schemes {
+                let Some(name) = from.format(cipher) else {
+                    continue;
+                };
+                assert_eq!(from.parse(name), Some(cipher));
+                for to in schemes {
+                    assert_eq!(translate(name, from, to), to.format(cipher));
+                }
+            }
+        }
+
+        assert_eq!(translate("NOT-A-SUITE", &OpenSslNames, &RfcNames), None);
+        assert_eq!(
+            translate("TLS13-AES128-CCM-8-SHA256", &WolfSslNames, &MbedTlsNames),
+            Some("TLS1-3-AES-128-CCM-8-SHA256")
+        );
+        assert_eq!(
+            translate("KRB5-DES-CBC3-SHA", &OpenSslNames, &MbedTlsNames),
+            None
+        );
+    }
+}
@@ -0,0 +1,359 @@
+use strum_macros::{Display, EnumIter, EnumString, IntoStaticStr};
+
+/// A TLS signature scheme, named as in the IANA TLS SignatureScheme registry.
+///
+/// OpenSSL's `-sigalgs` option takes either these names or the older
+/// `SIGNATURE+HASH` form, e.g. `RSA+SHA256` for `rsa_pkcs1_sha256`. The
+/// SHA-1 and SHA-224 schemes are only used by TLS 1.2 and carry the names
+/// OpenSSL gives them, as the registry has none. The same goes for the
+/// private-use code points OpenSSL sends for TLS 1.2 GOST signatures,
+/// `gost2012_256`, `gost2012_512` and `gost2001_gost94`.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Display, EnumIter, EnumString, IntoStaticStr)]
+pub enum SignatureScheme {
+    rsa_pkcs1_sha1,
+    dsa_sha1,
+    ecdsa_sha1,
+    rsa_pkcs1_sha224,
+    dsa_sha224,
+    ecdsa_sha224,
+    rsa_pkcs1_sha256,
+    dsa_sha256,
+    ecdsa_secp256r1_sha256,
+    rsa_pkcs1_sha384,
+    dsa_sha384,
+    ecdsa_secp384r1_sha384,
+    rsa_pkcs1_sha512,
+    dsa_sha512,
+    ecdsa_secp521r1_sha512,
+    gostr34102012_256a,
+    gostr34102012_256b,
+    gostr34102012_256c,
+    gostr34102012_256d,
+    gostr34102012_512a,
+    gostr34102012_512b,
+    gostr34102012_512c,
+    gostr34102012_256,
+    gostr34102012_512,
+    gost2012_256,
+    gost2012_512,
+    gost2001_gost94,
+    rsa_pss_rsae_sha256,
+    rsa_pss_rsae_sha384,
+    rsa_pss_rsae_sha512,
+    ed25519,
+    ed448,
+    rsa_pss_pss_sha256,
+    rsa_pss_pss_sha384,
+    rsa_pss_pss_sha512,
+    ecdsa_brainpoolP256r1tls13_sha256,
+    ecdsa_brainpoolP384r1tls13_sha384,
+    ecdsa_brainpoolP512r1tls13_sha512,
+    mldsa44,
+    mldsa65,
+    mldsa87,
+}
+
+impl SignatureScheme {
+    /// Resolves a name as given to OpenSSL's `-sigalgs`, in either the
+    /// `SIGNATURE+HASH` form or as a scheme name.
+    ///
+    /// ```
+    /// use openssl_to_rfc::SignatureScheme;
+    ///
+    /// assert_eq!(
+    ///     SignatureScheme::from_openssl_name("RSA+SHA256"),
+    ///     Some(SignatureScheme::rsa_pkcs1_sha256)
+    /// );
+    /// assert_eq!(
+    ///     SignatureScheme::from_openssl_name("rsa_pss_rsae_sha256"),
+    ///     Some(SignatureScheme::rsa_pss_rsae_sha256)
+    /// );
+    /// ```
+    pub fn from_openssl_name(name: &str) -> Option<SignatureScheme> {
+        if let Some((a, b)) = name.split_once('+') {
+            // OpenSSL accepts the two halves in either order.
+            return Self::from_openssl_pair(a, b).or_else(|| Self::from_openssl_pair(b, a));
+        }
+        match name {
+            "rsa_pkcs1_sha1" => Some(Self::rsa_pkcs1_sha1),
+            "dsa_sha1" => Some(Self::dsa_sha1),
+            "ecdsa_sha1" => Some(Self::ecdsa_sha1),
+            "rsa_pkcs1_sha224" => Some(Self::rsa_pkcs1_sha224),
+            "dsa_sha224" => Some(Self::dsa_sha224),
+            "ecdsa_sha224" => Some(Self::ecdsa_sha224),
+            "rsa_pkcs1_sha256" => Some(Self::rsa_pkcs1_sha256),
+            "dsa_sha256" => Some(Self::dsa_sha256),
+            "ecdsa_secp256r1_sha256" => Some(Self::ecdsa_secp256r1_sha256),
+            "rsa_pkcs1_sha384" => Some(Self::rsa_pkcs1_sha384),
+            "dsa_sha384" => Some(Self::dsa_sha384),
+            "ecdsa_secp384r1_sha384" => Some(Self::ecdsa_secp384r1_sha384),
+            "rsa_pkcs1_sha512" => Some(Self::rsa_pkcs1_sha512),
+            "dsa_sha512" => Some(Self::dsa_sha512),
+            "ecdsa_secp521r1_sha512" => Some(Self::ecdsa_secp521r1_sha512),
+            "gostr34102012_256" => Some(Self::gostr34102012_256),
+            "gostr34102012_512" => Some(Self::gostr34102012_512),
+            "gost2012_256" => Some(Self::gost2012_256),
+            "gost2012_512" => Some(Self::gost2012_512),
+            "gost2001_gost94" => Some(Self::gost2001_gost94),
+            "rsa_pss_rsae_sha256" => Some(Self::rsa_pss_rsae_sha256),
+            "rsa_pss_rsae_sha384" => Some(Self::rsa_pss_rsae_sha384),
+            "rsa_pss_rsae_sha512" => Some(Self::rsa_pss_rsae_sha512),
+            "ed25519" => Some(Self::ed25519),
+            "ed448" => Some(Self::ed448),
+            "rsa_pss_pss_sha256" => Some(Self::rsa_pss_pss_sha256),
+            "rsa_pss_pss_sha384" => Some(Self::rsa_pss_pss_sha384),
+            "rsa_pss_pss_sha512" => Some(Self::rsa_pss_pss_sha512),
+            "ecdsa_brainpoolP256r1tls13_sha256" | "ecdsa_brainpoolP256r1_sha256" => {
+                Some(Self::ecdsa_brainpoolP256r1tls13_sha256)
+            }
+            "ecdsa_brainpoolP384r1tls13_sha384" | "ecdsa_brainpoolP384r1_sha384" => {
+                Some(Self::ecdsa_brainpoolP384r1tls13_sha384)
+            }
+            "ecdsa_brainpoolP512r1tls13_sha512" | "ecdsa_brainpoolP512r1_sha512" => {
+                Some(Self::ecdsa_brainpoolP512r1tls13_sha512)
+            }
+            "mldsa44" => Some(Self::mldsa44),
+            "mldsa65" => Some(Self::mldsa65),
+            "mldsa87" => Some(Self::mldsa87),
+            _ => None,
+        }
+    }
+
+    fn from_openssl_pair(signature: &str, hash: &str) -> Option<SignatureScheme> {
+        let hash = hash.to_ascii_uppercase();
+        match (signature, hash.as_str()) {
+            ("RSA", "SHA1") => Some(Self::rsa_pkcs1_sha1),
+            ("DSA", "SHA1") => Some(Self::dsa_sha1),
+            ("ECDSA", "SHA1") => Some(Self::ecdsa_sha1),
+            ("RSA", "SHA224") => Some(Self::rsa_pkcs1_sha224),
+            ("DSA", "SHA224") => Some(Self::dsa_sha224),
+            ("ECDSA", "SHA224") => Some(Self::ecdsa_sha224),
+            ("RSA", "SHA256") => Some(Self::rsa_pkcs1_sha256),
+            ("DSA", "SHA256") => Some(Self::dsa_sha256),
+            ("ECDSA", "SHA256") => Some(Self::ecdsa_secp256r1_sha256),
+            ("RSA", "SHA384") => Some(Self::rsa_pkcs1_sha384),
+            ("DSA", "SHA384") => Some(Self::dsa_sha384),
+            ("ECDSA", "SHA384") => Some(Self::ecdsa_secp384r1_sha384),
+            ("RSA", "SHA512") => Some(Self::rsa_pkcs1_sha512),
+            ("DSA", "SHA512") => Some(Self::dsa_sha512),
+            ("ECDSA", "SHA512") => Some(Self::ecdsa_secp521r1_sha512),
+            ("RSA-PSS" | "PSS", "SHA256") => Some(Self::rsa_pss_rsae_sha256),
+            ("RSA-PSS" | "PSS", "SHA384") => Some(Self::rsa_pss_rsae_sha384),
+            ("RSA-PSS" | "PSS", "SHA512") => Some(Self::rsa_pss_rsae_sha512),
+            _ => None,
+        }
+    }
+
+    /// Returns the name OpenSSL 3 uses for the scheme, or `None` if OpenSSL
+    /// does not implement it, as for the TLS 1.3 GOST schemes of RFC 9367.
+    ///
+    /// ```
+    /// use openssl_to_rfc::SignatureScheme;
+    ///
+    /// assert_eq!(
+    ///     SignatureScheme::gost2012_256.as_openssl_name().unwrap(),
+    ///     "gost2012_256"
+    /// );
+    /// assert_eq!(SignatureScheme::gostr34102012_256a.as_openssl_name(), None);
+    /// ```
+    pub fn as_openssl_name(&self) -> Option<String> {
+        match self {
+            Self::gostr34102012_256a
+            | Self::gostr34102012_256b
+            | Self::gostr34102012_256c
+            | Self::gostr34102012_256d
+            | Self::gostr34102012_512a
+            | Self::gostr34102012_512b
+            | Self::gostr34102012_512c => None,
+            _ => {
+                let s: &'static str = self.into();
+                Some(s.to_owned())
+            }
+        }
+    }
+
+    /// Returns the scheme in OpenSSL's `SIGNATURE+HASH` form, or `None` for
+    /// schemes that can only be given by name, such as `ed25519`.
+    ///
+    /// `RSA-PSS+SHA256` selects the `rsa_pss_rsae_*` schemes; the
+    /// `rsa_pss_pss_*` ones have no such form.
+    pub fn as_openssl_legacy_name(&self) -> Option<String> {
+        let s = match self {
+            Self::rsa_pkcs1_sha1 => Some("RSA+SHA1"),
+            Self::dsa_sha1 => Some("DSA+SHA1"),
+            Self::ecdsa_sha1 => Some("ECDSA+SHA1"),
+            Self::rsa_pkcs1_sha224 => Some("RSA+SHA224"),
+            Self::dsa_sha224 => Some("DSA+SHA224"),
+            Self::ecdsa_sha224 => Some("ECDSA+SHA224"),
+            Self::rsa_pkcs1_sha256 => Some("RSA+SHA256"),
+            Self::dsa_sha256 => Some("DSA+SHA256"),
+            Self::ecdsa_secp256r1_sha256 => Some("ECDSA+SHA256"),
+            Self::rsa_pkcs1_sha384 => Some("RSA+SHA384"),
+            Self::dsa_sha384 => Some("DSA+SHA384"),
+            Self::ecdsa_secp384r1_sha384 => Some("ECDSA+SHA384"),
+            Self::rsa_pkcs1_sha512 => Some("RSA+SHA512"),
+            Self::dsa_sha512 => Some("DSA+SHA512"),
+            Self::ecdsa_secp521r1_sha512 => Some("ECDSA+SHA512"),
+            Self::rsa_pss_rsae_sha256 => Some("RSA-PSS+SHA256"),
+            Self::rsa_pss_rsae_sha384 => Some("RSA-PSS+SHA384"),
+            Self::rsa_pss_rsae_sha512 => Some("RSA-PSS+SHA512"),
+            _ => None,
+        };
+        s.map(|s: &str| s.to_owned())
+    }
+
+    pub fn from_u16(value: u16) -> Option<SignatureScheme> {
+        match value {
+            0x0201 => Some(Self::rsa_pkcs1_sha1),
+            0x0202 => Some(Self::dsa_sha1),
+            0x0203 => Some(Self::ecdsa_sha1),
+            0x0301 => Some(Self::rsa_pkcs1_sha224),
+            0x0302 => Some(Self::dsa_sha224),
+            0x0303 => Some(Self::ecdsa_sha224),
+            0x0401 => Some(Self::rsa_pkcs1_sha256),
+            0x0402 => Some(Self::dsa_sha256),
+            0x0403 => Some(Self::ecdsa_secp256r1_sha256),
+            0x0501 => Some(Self::rsa_pkcs1_sha384),
+            0x0502 => Some(Self::dsa_sha384),
+            0x0503 => Some(Self::ecdsa_secp384r1_sha384),
+            0x0601 => Some(Self::rsa_pkcs1_sha512),
+            0x0602 => Some(Self::dsa_sha512),
+            0x0603 => Some(Self::ecdsa_secp521r1_sha512),
+            0x0709 => Some(Self::gostr34102012_256a),
+            0x070A => Some(Self::gostr34102012_256b),
+            0x070B => Some(Self::gostr34102012_256c),
+            0x070C => Some(Self::gostr34102012_256d),
+            0x070D => Some(Self::gostr34102012_512a),
+            0x070E => Some(Self::gostr34102012_512b),
+            0x070F => Some(Self::gostr34102012_512c),
+            0x0840 => Some(Self::gostr34102012_256),
+            0x0841 => Some(Self::gostr34102012_512),
+            0x0804 => Some(Self::rsa_pss_rsae_sha256),
+            0x0805 => Some(Self::rsa_pss_rsae_sha384),
+            0x0806 => Some(Self::rsa_pss_rsae_sha512),
+            0x0807 => Some(Self::ed25519),
+            0x0808 => Some(Self::ed448),
+            0x0809 => Some(Self::rsa_pss_pss_sha256),
+            0x080A => Some(Self::rsa_pss_pss_sha384),
+            0x080B => Some(Self::rsa_pss_pss_sha512),
+            0x081A => Some(Self::ecdsa_brainpoolP256r1tls13_sha256),
+            0x081B => Some(Self::ecdsa_brainpoolP384r1tls13_sha384),
+            0x081C => Some(Self::ecdsa_brainpoolP512r1tls13_sha512),
+            0x0904 => Some(Self::mldsa44),
+            0x0905 => Some(Self::mldsa65),
+            0x0906 => Some(Self::mldsa87),
+            0xEDED => Some(Self::gost2001_gost94),
+            0xEEEE => Some(Self::gost2012_256),
+            0xEFEF => Some(Self::gost2012_512),
+            _ => None,
+        }
+    }
+
+    pub fn as_u16(&self) -> u16 {
+        match self {
+            Self::rsa_pkcs1_sha1 => 0x0201,
+            Self::dsa_sha1 => 0x0202,
+            Self::ecdsa_sha1 => 0x0203,
+            Self::rsa_pkcs1_sha224 => 0x0301,
+            Self::dsa_sha224 => 0x0302,
+            Self::ecdsa_sha224 => 0x0303,
+            Self::rsa_pkcs1_sha256 => 0x0401,
+            Self::dsa_sha256 => 0x0402,
+            Self::ecdsa_secp256r1_sha256 => 0x0403,
+            Self::rsa_pkcs1_sha384 => 0x0501,
+            Self::dsa_sha384 => 0x0502,
+            Self::ecdsa_secp384r1_sha384 => 0x0503,
+            Self::rsa_pkcs1_sha512 => 0x0601,
+            Self::dsa_sha512 => 0x0602,
+            Self::ecdsa_secp521r1_sha512 => 0x0603,
+            Self::gostr34102012_256a => 0x0709,
+            Self::gostr34102012_256b => 0x070A,
+            Self::gostr34102012_256c => 0x070B,
+            Self::gostr34102012_256d => 0x070C,
+            Self::gostr34102012_512a => 0x070D,
+            Self::gostr34102012_512b => 0x070E,
+            Self::gostr34102012_512c => 0x070F,
+            Self::gostr34102012_256 => 0x0840,
+            Self::gostr34102012_512 => 0x0841,
+            Self::gost2012_256 => 0xEEEE,
+            Self::gost2012_512 => 0xEFEF,
+            Self::gost2001_gost94 => 0xEDED,
+            Self::rsa_pss_rsae_sha256 => 0x0804,
+            Self::rsa_pss_rsae_sha384 => 0x0805,
+            Self::rsa_pss_rsae_sha512 => 0x0806,
+            Self::ed25519 => 0x0807,
+            Self::ed448 => 0x0808,
+            Self::rsa_pss_pss_sha256 => 0x0809,
+            Self::rsa_pss_pss_sha384 => 0x080A,
+            Self::rsa_pss_pss_sha512 => 0x080B,
+            Self::ecdsa_brainpoolP256r1tls13_sha256 => 0x081A,
+            Self::ecdsa_brainpoolP384r1tls13_sha384 => 0x081B,
+            Self::ecdsa_brainpoolP512r1tls13_sha512 => 0x081C,
+            Self::mldsa44 => 0x0904,
+            Self::mldsa65 => 0x0905,
+            Self::mldsa87 => 0x0906,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_names() {
+        assert_eq!(
+            SignatureScheme::from_openssl_name("ECDSA+SHA384"),
+            Some(SignatureScheme::ecdsa_secp384r1_sha384)
+        );
+        assert_eq!(
+            SignatureScheme::from_openssl_name("sha256+RSA-PSS"),
+            Some(SignatureScheme::rsa_pss_rsae_sha256)
+        );
+        assert_eq!(
+            SignatureScheme::from_openssl_name("PSS+SHA512"),
+            Some(SignatureScheme::rsa_pss_rsae_sha512)
+        );
+        assert_eq!(SignatureScheme::from_openssl_name("ECDSA+MD5"), None);
+        assert_eq!(
+            SignatureScheme::from_openssl_name("RSA+SHA256+SHA384"),
+            None
+        );
+        assert_eq!(SignatureScheme::ed25519.as_openssl_legacy_name(), None);
+        assert_eq!(SignatureScheme::rsa_pkcs1_sha256.as_u16(), 0x0401);
+    }
+
+    #[test]
+    fn gost_schemes() {
+        for (scheme, code, name) in [
+            (
+                SignatureScheme::gostr34102012_256,
+                0x0840,
+                "gostr34102012_256",
+            ),
+            (
+                SignatureScheme::gostr34102012_512,
+                0x0841,
+                "gostr34102012_512",
+            ),
+            (SignatureScheme::gost2012_256, 0xEEEE, "gost2012_256"),
+            (SignatureScheme::gost2012_512, 0xEFEF, "gost2012_512"),
+            (SignatureScheme::gost2001_gost94, 0xEDED, "gost2001_gost94"),
+        ] {
+            assert_eq!(SignatureScheme::from_u16(code), Some(scheme));
+            assert_eq!(scheme.as_openssl_name().as_deref(), Some(name));
+            assert_eq!(SignatureScheme::from_openssl_name(name), Some(scheme));
+        }
+
+        assert_eq!(SignatureScheme::gostr34102012_512c.as_openssl_name(), None);
+        assert_eq!(
+            SignatureScheme::from_openssl_name("gostr34102012_256a"),
+            None
+        );
+        assert_eq!(
+            SignatureScheme::from_u16(0x0709),
+            Some(SignatureScheme::gostr34102012_256a)
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use std::fmt;
+
+use crate::components::{Authentication, KeyExchange};
+use crate::{ProtocolVersion, TLSCipherSuite};
+
+/// The cipher suite configuration of a server, for predicting which suite a
+/// client ends up with.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ServerConfig {
+    /// The suites the server enables, in its order of preference.
+    pub ciphers: Vec<TLSCipherSuite>,
+    /// If true, the server picks the first of its own suites that the
+    /// client offers, as with nginx's `ssl_prefer_server_ciphers on`.
+    /// Otherwise it follows the client's order.
+    pub server_preference: bool,
+    /// The key type of the server's certificate: `Rsa`, `Ecdsa` or `Dss`.
+    pub certificate: Authentication,
+}
+
+/// Why a simulated handshake fails.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum HandshakeFailure {
+    /// The client and the server have no protocol version in common.
+    ///
+    /// `negotiate` never returns this since it takes an agreed version. It is
+    /// meant for callers that pick the version first, such as
+    /// `ClientProfile::connect`.
+    NoSharedVersion,
+    /// The client and the server have no suite in common.
+    NoSharedCipher,
+    /// No shared suite can be used with the protocol version.
+    Version(ProtocolVersion),
+    /// No shared suite that suits the version can be used with the server's
+    /// certificate.
+    Certificate(Authentication),
+}
+
+impl fmt::Display for HandshakeFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSharedVersion => write!(f, "no shared protocol version"),
+            Self::NoSharedCipher => write!(f, "no shared cipher suite"),
+            Self::Version(version) => {
+                write!(f, "no shared cipher suite can be used with {}", version)
+            }
+            Self::Certificate(key) => {
+                let key = match key {
+                    Authentication::Rsa => "RSA".to_owned(),
+                    Authentication::Ecdsa => "ECDSA".to_owned(),
+                    Authentication::Dss => "DSA".to_owned(),
+                    other => format!("{:?}", other),
+                };
+                write!(
+                    f,
+                    "no shared cipher suite can be used with the {} certificate",
+                    key
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for HandshakeFailure {}
+
+impl ServerConfig {
+    /// Predicts the suite the server selects for a client that offers
+    /// `client` and has agreed on `version`.
+    ///
+    /// A suite is selected only if both sides have it, it may be used with
+    /// the version, see `TLSCipherSuite::usable_in`, and it works with the
+    /// certificate. Suites that authenticate with the certificate must
+    /// match its key type, static ECDH suites need an ECDSA certificate,
+    /// which holds an EC key, and static DH and GOST suites can never be
+    /// used since none of the supported key types fits them. TLS 1.3 suites
+    /// work with any certificate except DSA, which TLS 1.3 dropped.
+    /// Anonymous, PSK and SRP suites need no certificate.
+    ///
+    /// Signature algorithms, groups and extensions are not simulated.
+    ///
+    /// ```
+    /// use openssl_to_rfc::components::Authentication;
+    /// use openssl_to_rfc::{HandshakeFailure, ProtocolVersion, ServerConfig, TLSCipherSuite};
+    ///
+    /// let server = ServerConfig {
+    ///     ciphers: vec![
+    ///         TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+    ///         TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+    ///     ],
+    ///     server_preference: true,
+    ///     certificate: Authentication::Rsa,
+    /// };
+    /// let client = [
+    ///     TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+    ///     TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+    /// ];
+    /// assert_eq!(
+    ///     server.negotiate(&client, ProtocolVersion::Tls12),
+    ///     Ok(TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256)
+    /// );
+    /// assert_eq!(
+    ///     server.negotiate(&client, ProtocolVersion::Tls11),
+    ///     Err(HandshakeFailure::Version(ProtocolVersion::Tls11))
+    /// );
+    /// ```
+    pub fn negotiate(
+        &self,
+        client: &[TLSCipherSuite],
+        version: ProtocolVersion,
+    ) -> Result<TLSCipherSuite, HandshakeFailure> {
+        let (first, second) = if self.server_preference {
+            (&self.ciphers[..], client)
+        } else {
+            (client, &self.ciphers[..])
+        };
+        let shared: Vec<_> = first
+            .iter()
+            .copied()
+            .filter(|c| !c.is_signaling() && second.contains(c))
+            .collect();
+        if shared.is_empty() {
+            return Err(HandshakeFailure::NoSharedCipher);
+        }
+
+        let mut usable = shared
+            .into_iter()
+            .filter(|c| c.usable_in(version))
+            .peekable();
+        if usable.peek().is_none() {
+            return Err(HandshakeFailure::Version(version));
+        }
+        usable
+            .find(|c| self.fits_certificate(*c))
+            .ok_or(HandshakeFailure::Certificate(self.certificate))
+    }
+
+    fn fits_certificate(&self, cipher: TLSCipherSuite) -> bool {
+        let c = cipher.components();
+        match (c.key_exchange, c.authentication) {
+            (KeyExchange::Any, _) => self.certificate != Authentication::Dss,
+            (KeyExchange::Ecdh, _) => self.certificate == Authentication::Ecdsa,
+            (KeyExchange::Dh, _) => false,
+            (
+                _,
+                Authentication::Null
+                | Authentication::Psk
+                | Authentication::Srp
+                | Authentication::Krb5
+                | Authentication::EccPwd,
+            ) => true,
+            (_, authentication) => authentication == self.certificate,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selection() {
+        use TLSCipherSuite::*;
+
+        let client = [
+            TLS_AES_128_GCM_SHA256,
+            TLS_CHACHA20_POLY1305_SHA256,
+            TLS_AES_256_GCM_SHA384,
+            TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256,
+            TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+            TLS_ECDH_ECDSA_WITH_AES_128_CBC_SHA,
+            TLS_DHE_DSS_WITH_AES_128_CBC_SHA,
+            TLS_EMPTY_RENEGOTIATION_INFO_SCSV,
+        ];
+        let mut server = ServerConfig {
+            ciphers: vec![
+                TLS_AES_256_GCM_SHA384,
+                TLS_AES_128_GCM_SHA256,
+                TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+                TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256,
+                TLS_ECDH_ECDSA_WITH_AES_128_CBC_SHA,
+                TLS_DHE_DSS_WITH_AES_128_CBC_SHA,
+            ],
+            server_preference: false,
+            certificate: Authentication::Ecdsa,
+        };
+        let negotiate = |server: &ServerConfig, version| server.negotiate(&client, version);
+
+        assert_eq!(
+            negotiate(&server, ProtocolVersion::Tls13),
+            Ok(TLS_AES_128_GCM_SHA256)
+        );
+        assert_eq!(
+            negotiate(&server, ProtocolVersion::Tls12),
+            Ok(TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256)
+        );
+        assert_eq!(
+            negotiate(&server, ProtocolVersion::Tls10),
+            Ok(TLS_ECDH_ECDSA_WITH_AES_128_CBC_SHA)
+        );
+
+        server.server_preference = true;
+        assert_eq!(
+            negotiate(&server, ProtocolVersion::Tls13),
+            Ok(TLS_AES_256_GCM_SHA384)
+        );
+        assert_eq!(
+            negotiate(&server, ProtocolVersion::Tls12),
+            Ok(TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384)
+        );
+
+        server.certificate = Authentication::Dss;
+        assert_eq!(
+            negotiate(&server, ProtocolVersion::Tls13),
+            Err(HandshakeFailure::Certificate(Authentication::Dss))
+        );
+        assert_eq!(
+            negotiate(&server, ProtocolVersion::Tls12),
+            Ok(TLS_DHE_DSS_WITH_AES_128_CBC_SHA)
+        );
+
+        server.certificate = Authentication::Rsa;
+        assert_eq!(
+            negotiate(&server, ProtocolVersion::Tls11),
+            Err(HandshakeFailure::Certificate(Authentication::Rsa))
+        );
+        assert_eq!(
+            negotiate(&server, ProtocolVersion::Ssl2),
+            Err(HandshakeFailure::Version(ProtocolVersion::Ssl2))
+        );
+
+        server.ciphers = vec![TLS_RSA_WITH_AES_128_CBC_SHA];
+        assert_eq!(
+            negotiate(&server, ProtocolVersion::Tls12),
+            Err(HandshakeFailure::NoSharedCipher)
+        );
+    }
+}
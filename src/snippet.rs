@@ -0,0 +1,260 @@
+use std::fmt;
+
+use crate::{expand_openssl_cipher_string, Policy, TLSCipherSuite, TlsLibrary};
+
+/// Server software that `generate_config` writes cipher suite directives
+/// for.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ServerSoftware {
+    Nginx,
+    Apache,
+    HAProxy,
+    Postfix,
+    Dovecot,
+    Lighttpd,
+    Caddy,
+}
+
+impl fmt::Display for ServerSoftware {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Nginx => "nginx",
+            Self::Apache => "Apache httpd",
+            Self::HAProxy => "HAProxy",
+            Self::Postfix => "Postfix",
+            Self::Dovecot => "Dovecot",
+            Self::Lighttpd => "lighttpd",
+            Self::Caddy => "Caddy",
+        };
+        f.pad(s)
+    }
+}
+
+/// The suites Caddy accepts in its `ciphers` option. Go does not let TLS 1.3
+/// suites be configured.
+const CADDY: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_RSA_WITH_AES_256_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_AES_128_CBC_SHA,
+    TLSCipherSuite::TLS_ECDHE_RSA_WITH_3DES_EDE_CBC_SHA,
+    TLSCipherSuite::TLS_RSA_WITH_3DES_EDE_CBC_SHA,
+];
+
+/// OpenSSL's TLS 1.3 suites, in the order of its default `Ciphersuites`
+/// followed by the ones it does not enable by default.
+const TLS13: &[TLSCipherSuite] = &[
+    TLSCipherSuite::TLS_AES_256_GCM_SHA384,
+    TLSCipherSuite::TLS_CHACHA20_POLY1305_SHA256,
+    TLSCipherSuite::TLS_AES_128_GCM_SHA256,
+    TLSCipherSuite::TLS_AES_128_CCM_SHA256,
+    TLSCipherSuite::TLS_AES_128_CCM_8_SHA256,
+];
+
+/// Writes the directives that configure a server to offer the given suites,
+/// in order.
+///
+/// Every software except Caddy uses OpenSSL, so the suites are written with
+/// OpenSSL names and TLS 1.3 suites get a line of their own, since OpenSSL
+/// configures them separately:
+///
+/// - nginx: `ssl_ciphers` and `ssl_conf_command Ciphersuites`,
+/// - Apache httpd: `SSLCipherSuite` and `SSLCipherSuite TLSv1.3`,
+/// - HAProxy: `ssl-default-bind-ciphers` and `ssl-default-bind-ciphersuites`
+///   for the `global` section,
+/// - Postfix: `tls_high_cipherlist` for `main.cf`, with the SMTP server's
+///   cipher grade set to `high`. Postfix has no setting for TLS 1.3 suites,
+///   so those are left to OpenSSL's defaults,
+/// - Dovecot: `ssl_cipher_list` and `ssl_cipher_suites`,
+/// - lighttpd: `ssl.openssl.ssl-conf-cmd` with `CipherString` and
+///   `Ciphersuites`,
+/// - Caddy: a `tls` block with `ciphers`, which takes RFC names. Caddy
+///   cannot restrict TLS 1.3 suites.
+///
+/// Suites the software cannot use, such as TLS 1.2 suites missing from
+/// OpenSSL 3 or from Go, are left out. A directive with no suites left is
+/// not written.
+///
+/// ```
+/// use openssl_to_rfc::{generate_config, ServerSoftware, TLSCipherSuite};
+///
+/// let ciphers = [
+///     TLSCipherSuite::TLS_AES_128_GCM_SHA256,
+///     TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+///     TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+/// ];
+/// assert_eq!(
+///     generate_config(ServerSoftware::Nginx, &ciphers),
+///     "ssl_ciphers ECDHE-ECDSA-AES128-GCM-SHA256:ECDHE-RSA-AES128-GCM-SHA256;\n\
+///      ssl_conf_command Ciphersuites TLS_AES_128_GCM_SHA256;\n"
+/// );
+/// ```
+pub fn generate_config(software: ServerSoftware, ciphers: &[TLSCipherSuite]) -> String {
+    let ciphers: Vec<_> = ciphers
+        .iter()
+        .copied()
+        .filter(|c| !c.is_signaling())
+        .collect();
+
+    let join = |tls13: bool| {
+        ciphers
+            .iter()
+            .filter(|c| c.is_tls13() == tls13 && c.supported_by(TlsLibrary::OpenSsl))
+            .map(|c| c.as_openssl_name())
+            .collect::<Vec<_>>()
+            .join(":")
+    };
+    let (tls12, tls13) = (join(false), join(true));
+
+    let mut lines = Vec::new();
+    let mut line = |value: &str, directive: &dyn Fn(&str) -> String| {
+        if !value.is_empty() {
+            lines.push(directive(value));
+        }
+    };
+    match software {
+        ServerSoftware::Nginx => {
+            line(&tls12, &|v| format!("ssl_ciphers {};", v));
+            line(&tls13, &|v| format!("ssl_conf_command Ciphersuites {};", v));
+        }
+        ServerSoftware::Apache => {
+            line(&tls12, &|v| format!("SSLCipherSuite {}", v));
+            line(&tls13, &|v| format!("SSLCipherSuite TLSv1.3 {}", v));
+        }
+        ServerSoftware::HAProxy => {
+            line(&tls12, &|v| format!("ssl-default-bind-ciphers {}", v));
+            line(&tls13, &|v| format!("ssl-default-bind-ciphersuites {}", v));
+        }
+        ServerSoftware::Postfix => {
+            line(&tls12, &|v| {
+                format!(
+                    "tls_high_cipherlist = {}\n\
+                     smtpd_tls_ciphers = high\n\
+                     smtpd_tls_mandatory_ciphers = high",
+                    v
+                )
+            });
+        }
+        ServerSoftware::Dovecot => {
+            line(&tls12, &|v| format!("ssl_cipher_list = {}", v));
+            line(&tls13, &|v| format!("ssl_cipher_suites = {}", v));
+        }
+        ServerSoftware::Lighttpd => {
+            let mut commands = Vec::new();
+            if !tls12.is_empty() {
+                commands.push(format!("\"CipherString\" => \"{}\"", tls12));
+            }
+            if !tls13.is_empty() {
+                commands.push(format!("\"Ciphersuites\" => \"{}\"", tls13));
+            }
+            let commands = commands.join(", ");
+            line(&commands, &|v| {
+                format!("ssl.openssl.ssl-conf-cmd = ({})", v)
+            });
+        }
+        ServerSoftware::Caddy => {
+            let names = ciphers
+                .iter()
+                .filter(|c| CADDY.contains(c))
+                .map(|c| c.to_string())
+                .collect::<Vec<_>>()
+                .join(" ");
+            line(&names, &|v| format!("tls {{\n\tciphers {}\n}}", v));
+        }
+    }
+
+    let mut config = lines.join("\n");
+    if !config.is_empty() {
+        config.push('\n');
+    }
+    config
+}
+
+/// Writes the directives that configure a server to offer the suites a
+/// policy approves.
+///
+/// TLS 1.2 suites are ordered the way OpenSSL orders `ALL:COMPLEMENTOFALL`,
+/// strongest first, and TLS 1.3 suites in OpenSSL's default order. See
+/// `generate_config` for the directives each software gets.
+///
+/// ```
+/// use openssl_to_rfc::{generate_config_for_policy, Nist80052r2, ServerSoftware};
+///
+/// let config = generate_config_for_policy(ServerSoftware::Dovecot, &Nist80052r2::ECDSA);
+/// assert!(config.starts_with("ssl_cipher_list = ECDHE-ECDSA-AES256-GCM-SHA384:"));
+/// assert!(config.ends_with(
+///     "ssl_cipher_suites = TLS_AES_256_GCM_SHA384:TLS_AES_128_GCM_SHA256:\
+///      TLS_AES_128_CCM_SHA256:TLS_AES_128_CCM_8_SHA256\n"
+/// ));
+/// ```
+pub fn generate_config_for_policy(software: ServerSoftware, policy: &dyn Policy) -> String {
+    let mut ciphers = TLS13.to_vec();
+    ciphers.extend(
+        expand_openssl_cipher_string("ALL:COMPLEMENTOFALL")
+            .expect("ALL:COMPLEMENTOFALL is a valid cipher string"),
+    );
+    ciphers.retain(|c| policy.assess(*c).is_approved());
+    generate_config(software, &ciphers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directives() {
+        let ciphers = [
+            TLSCipherSuite::TLS_AES_256_GCM_SHA384,
+            TLSCipherSuite::TLS_CHACHA20_POLY1305_SHA256,
+            TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+            TLSCipherSuite::TLS_DHE_RSA_WITH_AES_256_GCM_SHA384,
+            TLSCipherSuite::TLS_EMPTY_RENEGOTIATION_INFO_SCSV,
+        ];
+        let config = |software| generate_config(software, &ciphers);
+
+        assert_eq!(
+            config(ServerSoftware::Apache),
+            "SSLCipherSuite ECDHE-RSA-AES256-GCM-SHA384:DHE-RSA-AES256-GCM-SHA384\n\
+             SSLCipherSuite TLSv1.3 TLS_AES_256_GCM_SHA384:TLS_CHACHA20_POLY1305_SHA256\n"
+        );
+        assert_eq!(
+            config(ServerSoftware::HAProxy),
+            "ssl-default-bind-ciphers ECDHE-RSA-AES256-GCM-SHA384:DHE-RSA-AES256-GCM-SHA384\n\
+             ssl-default-bind-ciphersuites TLS_AES_256_GCM_SHA384:TLS_CHACHA20_POLY1305_SHA256\n"
+        );
+        assert_eq!(
+            config(ServerSoftware::Postfix),
+            "tls_high_cipherlist = ECDHE-RSA-AES256-GCM-SHA384:DHE-RSA-AES256-GCM-SHA384\n\
+             smtpd_tls_ciphers = high\n\
+             smtpd_tls_mandatory_ciphers = high\n"
+        );
+        assert_eq!(
+            config(ServerSoftware::Lighttpd),
+            "ssl.openssl.ssl-conf-cmd = (\
+             \"CipherString\" => \"ECDHE-RSA-AES256-GCM-SHA384:DHE-RSA-AES256-GCM-SHA384\", \
+             \"Ciphersuites\" => \"TLS_AES_256_GCM_SHA384:TLS_CHACHA20_POLY1305_SHA256\")\n"
+        );
+        assert_eq!(
+            config(ServerSoftware::Caddy),
+            "tls {\n\tciphers TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384\n}\n"
+        );
+        assert_eq!(
+            generate_config(
+                ServerSoftware::Nginx,
+                &[TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256]
+            ),
+            "ssl_ciphers ECDHE-RSA-AES128-GCM-SHA256;\n"
+        );
+        assert_eq!(generate_config(ServerSoftware::Dovecot, &[]), "");
+    }
+}
@@ -1,7 +1,7 @@
 use strum_macros::{Display, EnumIter, EnumString};
 
 #[allow(non_camel_case_types)]
-#[derive(PartialEq, Debug, Display, EnumIter, EnumString)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Display, EnumIter, EnumString)]
 pub enum SSLV2CipherSuite {
     SSL_CK_RC4_128_WITH_MD5,
     SSL_CK_RC4_128_EXPORT40_WITH_MD5,
@@ -45,4 +45,33 @@ impl SSLV2CipherSuite {
 
         s.to_owned()
     }
+
+    pub fn from_u32(value: u32) -> Option<SSLV2CipherSuite> {
+        match value {
+            0x010080 => Some(Self::SSL_CK_RC4_128_WITH_MD5),
+            0x020080 => Some(Self::SSL_CK_RC4_128_EXPORT40_WITH_MD5),
+            0x030080 => Some(Self::SSL_CK_RC2_128_CBC_WITH_MD5),
+            0x040080 => Some(Self::SSL_CK_RC2_128_CBC_EXPORT40_WITH_MD5),
+            0x050080 => Some(Self::SSL_CK_IDEA_128_CBC_WITH_MD5),
+            0x060040 => Some(Self::SSL_CK_DES_64_CBC_WITH_MD5),
+            0x0700C0 => Some(Self::SSL_CK_DES_192_EDE3_CBC_WITH_MD5),
+            0x080080 => Some(Self::SSL_CK_RC4_64_WITH_MD5),
+            0x000000 => Some(Self::TLS_RSA_WITH_NULL_MD5),
+            _ => None,
+        }
+    }
+
+    pub fn as_u32(&self) -> u32 {
+        match self {
+            Self::SSL_CK_RC4_128_WITH_MD5 => 0x010080,
+            Self::SSL_CK_RC4_128_EXPORT40_WITH_MD5 => 0x020080,
+            Self::SSL_CK_RC2_128_CBC_WITH_MD5 => 0x030080,
+            Self::SSL_CK_RC2_128_CBC_EXPORT40_WITH_MD5 => 0x040080,
+            Self::SSL_CK_IDEA_128_CBC_WITH_MD5 => 0x050080,
+            Self::SSL_CK_DES_64_CBC_WITH_MD5 => 0x060040,
+            Self::SSL_CK_DES_192_EDE3_CBC_WITH_MD5 => 0x0700C0,
+            Self::SSL_CK_RC4_64_WITH_MD5 => 0x080080,
+            Self::TLS_RSA_WITH_NULL_MD5 => 0x000000,
+        }
+    }
 }
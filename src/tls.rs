@@ -1,7 +1,7 @@
-use strum_macros::{Display, EnumIter, EnumString};
+use strum_macros::{Display, EnumIter, EnumString, IntoStaticStr};
 
 #[allow(non_camel_case_types)]
-#[derive(PartialEq, Debug, Display, EnumIter, EnumString)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Display, EnumIter, EnumString, IntoStaticStr)]
 pub enum TLSCipherSuite {
     TLS_RSA_WITH_NULL_MD5,
     TLS_RSA_WITH_NULL_SHA,
@@ -277,6 +277,98 @@ pub enum TLSCipherSuite {
     TLS_RSA_PSK_WITH_NULL_SHA,
     TLS_RSA_PSK_WITH_NULL_SHA256,
     TLS_RSA_PSK_WITH_NULL_SHA384,
+    TLS_AES_128_GCM_SHA256,
+    TLS_AES_256_GCM_SHA384,
+    TLS_CHACHA20_POLY1305_SHA256,
+    TLS_AES_128_CCM_SHA256,
+    TLS_AES_128_CCM_8_SHA256,
+    TLS_GOSTR341112_256_WITH_KUZNYECHIK_CTR_OMAC,
+    TLS_GOSTR341112_256_WITH_MAGMA_CTR_OMAC,
+    TLS_GOSTR341112_256_WITH_28147_CNT_IMIT,
+    TLS_NULL_WITH_NULL_NULL,
+    TLS_SM4_GCM_SM3,
+    TLS_SM4_CCM_SM3,
+    TLS_EMPTY_RENEGOTIATION_INFO_SCSV,
+    TLS_AEGIS_256_SHA512,
+    TLS_AEGIS_128L_SHA256,
+    TLS_RSA_WITH_ARIA_128_CBC_SHA256,
+    TLS_RSA_WITH_ARIA_256_CBC_SHA384,
+    TLS_DH_DSS_WITH_ARIA_128_CBC_SHA256,
+    TLS_DH_DSS_WITH_ARIA_256_CBC_SHA384,
+    TLS_DH_RSA_WITH_ARIA_128_CBC_SHA256,
+    TLS_DH_RSA_WITH_ARIA_256_CBC_SHA384,
+    TLS_DHE_DSS_WITH_ARIA_128_CBC_SHA256,
+    TLS_DHE_DSS_WITH_ARIA_256_CBC_SHA384,
+    TLS_DHE_RSA_WITH_ARIA_128_CBC_SHA256,
+    TLS_DHE_RSA_WITH_ARIA_256_CBC_SHA384,
+    TLS_DH_anon_WITH_ARIA_128_CBC_SHA256,
+    TLS_DH_anon_WITH_ARIA_256_CBC_SHA384,
+    TLS_ECDHE_ECDSA_WITH_ARIA_128_CBC_SHA256,
+    TLS_ECDHE_ECDSA_WITH_ARIA_256_CBC_SHA384,
+    TLS_ECDH_ECDSA_WITH_ARIA_128_CBC_SHA256,
+    TLS_ECDH_ECDSA_WITH_ARIA_256_CBC_SHA384,
+    TLS_ECDHE_RSA_WITH_ARIA_128_CBC_SHA256,
+    TLS_ECDHE_RSA_WITH_ARIA_256_CBC_SHA384,
+    TLS_ECDH_RSA_WITH_ARIA_128_CBC_SHA256,
+    TLS_ECDH_RSA_WITH_ARIA_256_CBC_SHA384,
+    TLS_DH_RSA_WITH_ARIA_128_GCM_SHA256,
+    TLS_DH_RSA_WITH_ARIA_256_GCM_SHA384,
+    TLS_DH_DSS_WITH_ARIA_128_GCM_SHA256,
+    TLS_DH_DSS_WITH_ARIA_256_GCM_SHA384,
+    TLS_DH_anon_WITH_ARIA_128_GCM_SHA256,
+    TLS_DH_anon_WITH_ARIA_256_GCM_SHA384,
+    TLS_ECDH_ECDSA_WITH_ARIA_128_GCM_SHA256,
+    TLS_ECDH_ECDSA_WITH_ARIA_256_GCM_SHA384,
+    TLS_ECDH_RSA_WITH_ARIA_128_GCM_SHA256,
+    TLS_ECDH_RSA_WITH_ARIA_256_GCM_SHA384,
+    TLS_PSK_WITH_ARIA_128_CBC_SHA256,
+    TLS_PSK_WITH_ARIA_256_CBC_SHA384,
+    TLS_DHE_PSK_WITH_ARIA_128_CBC_SHA256,
+    TLS_DHE_PSK_WITH_ARIA_256_CBC_SHA384,
+    TLS_RSA_PSK_WITH_ARIA_128_CBC_SHA256,
+    TLS_RSA_PSK_WITH_ARIA_256_CBC_SHA384,
+    TLS_ECDHE_PSK_WITH_ARIA_128_CBC_SHA256,
+    TLS_ECDHE_PSK_WITH_ARIA_256_CBC_SHA384,
+    TLS_RSA_WITH_CAMELLIA_128_GCM_SHA256,
+    TLS_RSA_WITH_CAMELLIA_256_GCM_SHA384,
+    TLS_DHE_RSA_WITH_CAMELLIA_128_GCM_SHA256,
+    TLS_DHE_RSA_WITH_CAMELLIA_256_GCM_SHA384,
+    TLS_DH_RSA_WITH_CAMELLIA_128_GCM_SHA256,
+    TLS_DH_RSA_WITH_CAMELLIA_256_GCM_SHA384,
+    TLS_DHE_DSS_WITH_CAMELLIA_128_GCM_SHA256,
+    TLS_DHE_DSS_WITH_CAMELLIA_256_GCM_SHA384,
+    TLS_DH_DSS_WITH_CAMELLIA_128_GCM_SHA256,
+    TLS_DH_DSS_WITH_CAMELLIA_256_GCM_SHA384,
+    TLS_DH_anon_WITH_CAMELLIA_128_GCM_SHA256,
+    TLS_DH_anon_WITH_CAMELLIA_256_GCM_SHA384,
+    TLS_ECDHE_ECDSA_WITH_CAMELLIA_128_GCM_SHA256,
+    TLS_ECDHE_ECDSA_WITH_CAMELLIA_256_GCM_SHA384,
+    TLS_ECDH_ECDSA_WITH_CAMELLIA_128_GCM_SHA256,
+    TLS_ECDH_ECDSA_WITH_CAMELLIA_256_GCM_SHA384,
+    TLS_ECDHE_RSA_WITH_CAMELLIA_128_GCM_SHA256,
+    TLS_ECDHE_RSA_WITH_CAMELLIA_256_GCM_SHA384,
+    TLS_ECDH_RSA_WITH_CAMELLIA_128_GCM_SHA256,
+    TLS_ECDH_RSA_WITH_CAMELLIA_256_GCM_SHA384,
+    TLS_PSK_WITH_CAMELLIA_128_GCM_SHA256,
+    TLS_PSK_WITH_CAMELLIA_256_GCM_SHA384,
+    TLS_DHE_PSK_WITH_CAMELLIA_128_GCM_SHA256,
+    TLS_DHE_PSK_WITH_CAMELLIA_256_GCM_SHA384,
+    TLS_RSA_PSK_WITH_CAMELLIA_128_GCM_SHA256,
+    TLS_RSA_PSK_WITH_CAMELLIA_256_GCM_SHA384,
+    TLS_ECCPWD_WITH_AES_128_GCM_SHA256,
+    TLS_ECCPWD_WITH_AES_256_GCM_SHA384,
+    TLS_ECCPWD_WITH_AES_128_CCM_SHA256,
+    TLS_ECCPWD_WITH_AES_256_CCM_SHA384,
+    TLS_SHA256_SHA256,
+    TLS_SHA384_SHA384,
+    TLS_GOSTR341112_256_WITH_KUZNYECHIK_MGM_L,
+    TLS_GOSTR341112_256_WITH_MAGMA_MGM_L,
+    TLS_GOSTR341112_256_WITH_KUZNYECHIK_MGM_S,
+    TLS_GOSTR341112_256_WITH_MAGMA_MGM_S,
+    TLS_ECDHE_PSK_WITH_AES_128_GCM_SHA256,
+    TLS_ECDHE_PSK_WITH_AES_256_GCM_SHA384,
+    TLS_ECDHE_PSK_WITH_AES_128_CCM_8_SHA256,
+    TLS_ECDHE_PSK_WITH_AES_128_CCM_SHA256,
 }
 
 impl TLSCipherSuite {
@@ -590,12 +682,248 @@ impl TLSCipherSuite {
             "RSA-PSK-NULL-SHA" => Some(Self::TLS_RSA_PSK_WITH_NULL_SHA),
             "RSA-PSK-NULL-SHA256" => Some(Self::TLS_RSA_PSK_WITH_NULL_SHA256),
             "RSA-PSK-NULL-SHA384" => Some(Self::TLS_RSA_PSK_WITH_NULL_SHA384),
+            "TLS_AES_128_GCM_SHA256" => Some(Self::TLS_AES_128_GCM_SHA256),
+            "TLS_AES_256_GCM_SHA384" => Some(Self::TLS_AES_256_GCM_SHA384),
+            "TLS_CHACHA20_POLY1305_SHA256" => Some(Self::TLS_CHACHA20_POLY1305_SHA256),
+            "TLS_AES_128_CCM_SHA256" => Some(Self::TLS_AES_128_CCM_SHA256),
+            "TLS_AES_128_CCM_8_SHA256" => Some(Self::TLS_AES_128_CCM_8_SHA256),
+            "GOST2012-KUZNYECHIK-KUZNYECHIKOMAC" => {
+                Some(Self::TLS_GOSTR341112_256_WITH_KUZNYECHIK_CTR_OMAC)
+            }
+            "GOST2012-MAGMA-MAGMAOMAC" => Some(Self::TLS_GOSTR341112_256_WITH_MAGMA_CTR_OMAC),
+            "IANA-GOST2012-GOST8912-GOST8912" => {
+                Some(Self::TLS_GOSTR341112_256_WITH_28147_CNT_IMIT)
+            }
+            "TLS_NULL_WITH_NULL_NULL" => Some(Self::TLS_NULL_WITH_NULL_NULL),
+            "TLS_SM4_GCM_SM3" => Some(Self::TLS_SM4_GCM_SM3),
+            "TLS_SM4_CCM_SM3" => Some(Self::TLS_SM4_CCM_SM3),
+            "TLS_EMPTY_RENEGOTIATION_INFO_SCSV" => Some(Self::TLS_EMPTY_RENEGOTIATION_INFO_SCSV),
+            "TLS_AEGIS_256_SHA512" => Some(Self::TLS_AEGIS_256_SHA512),
+            "TLS_AEGIS_128L_SHA256" => Some(Self::TLS_AEGIS_128L_SHA256),
+            "TLS_RSA_WITH_ARIA_128_CBC_SHA256" => Some(Self::TLS_RSA_WITH_ARIA_128_CBC_SHA256),
+            "TLS_RSA_WITH_ARIA_256_CBC_SHA384" => Some(Self::TLS_RSA_WITH_ARIA_256_CBC_SHA384),
+            "TLS_DH_DSS_WITH_ARIA_128_CBC_SHA256" => {
+                Some(Self::TLS_DH_DSS_WITH_ARIA_128_CBC_SHA256)
+            }
+            "TLS_DH_DSS_WITH_ARIA_256_CBC_SHA384" => {
+                Some(Self::TLS_DH_DSS_WITH_ARIA_256_CBC_SHA384)
+            }
+            "TLS_DH_RSA_WITH_ARIA_128_CBC_SHA256" => {
+                Some(Self::TLS_DH_RSA_WITH_ARIA_128_CBC_SHA256)
+            }
+            "TLS_DH_RSA_WITH_ARIA_256_CBC_SHA384" => {
+                Some(Self::TLS_DH_RSA_WITH_ARIA_256_CBC_SHA384)
+            }
+            "TLS_DHE_DSS_WITH_ARIA_128_CBC_SHA256" => {
+                Some(Self::TLS_DHE_DSS_WITH_ARIA_128_CBC_SHA256)
+            }
+            "TLS_DHE_DSS_WITH_ARIA_256_CBC_SHA384" => {
+                Some(Self::TLS_DHE_DSS_WITH_ARIA_256_CBC_SHA384)
+            }
+            "TLS_DHE_RSA_WITH_ARIA_128_CBC_SHA256" => {
+                Some(Self::TLS_DHE_RSA_WITH_ARIA_128_CBC_SHA256)
+            }
+            "TLS_DHE_RSA_WITH_ARIA_256_CBC_SHA384" => {
+                Some(Self::TLS_DHE_RSA_WITH_ARIA_256_CBC_SHA384)
+            }
+            "TLS_DH_anon_WITH_ARIA_128_CBC_SHA256" => {
+                Some(Self::TLS_DH_anon_WITH_ARIA_128_CBC_SHA256)
+            }
+            "TLS_DH_anon_WITH_ARIA_256_CBC_SHA384" => {
+                Some(Self::TLS_DH_anon_WITH_ARIA_256_CBC_SHA384)
+            }
+            "TLS_ECDHE_ECDSA_WITH_ARIA_128_CBC_SHA256" => {
+                Some(Self::TLS_ECDHE_ECDSA_WITH_ARIA_128_CBC_SHA256)
+            }
+            "TLS_ECDHE_ECDSA_WITH_ARIA_256_CBC_SHA384" => {
+                Some(Self::TLS_ECDHE_ECDSA_WITH_ARIA_256_CBC_SHA384)
+            }
+            "TLS_ECDH_ECDSA_WITH_ARIA_128_CBC_SHA256" => {
+                Some(Self::TLS_ECDH_ECDSA_WITH_ARIA_128_CBC_SHA256)
+            }
+            "TLS_ECDH_ECDSA_WITH_ARIA_256_CBC_SHA384" => {
+                Some(Self::TLS_ECDH_ECDSA_WITH_ARIA_256_CBC_SHA384)
+            }
+            "TLS_ECDHE_RSA_WITH_ARIA_128_CBC_SHA256" => {
+                Some(Self::TLS_ECDHE_RSA_WITH_ARIA_128_CBC_SHA256)
+            }
+            "TLS_ECDHE_RSA_WITH_ARIA_256_CBC_SHA384" => {
+                Some(Self::TLS_ECDHE_RSA_WITH_ARIA_256_CBC_SHA384)
+            }
+            "TLS_ECDH_RSA_WITH_ARIA_128_CBC_SHA256" => {
+                Some(Self::TLS_ECDH_RSA_WITH_ARIA_128_CBC_SHA256)
+            }
+            "TLS_ECDH_RSA_WITH_ARIA_256_CBC_SHA384" => {
+                Some(Self::TLS_ECDH_RSA_WITH_ARIA_256_CBC_SHA384)
+            }
+            "TLS_DH_RSA_WITH_ARIA_128_GCM_SHA256" => {
+                Some(Self::TLS_DH_RSA_WITH_ARIA_128_GCM_SHA256)
+            }
+            "TLS_DH_RSA_WITH_ARIA_256_GCM_SHA384" => {
+                Some(Self::TLS_DH_RSA_WITH_ARIA_256_GCM_SHA384)
+            }
+            "TLS_DH_DSS_WITH_ARIA_128_GCM_SHA256" => {
+                Some(Self::TLS_DH_DSS_WITH_ARIA_128_GCM_SHA256)
+            }
+            "TLS_DH_DSS_WITH_ARIA_256_GCM_SHA384" => {
+                Some(Self::TLS_DH_DSS_WITH_ARIA_256_GCM_SHA384)
+            }
+            "TLS_DH_anon_WITH_ARIA_128_GCM_SHA256" => {
+                Some(Self::TLS_DH_anon_WITH_ARIA_128_GCM_SHA256)
+            }
+            "TLS_DH_anon_WITH_ARIA_256_GCM_SHA384" => {
+                Some(Self::TLS_DH_anon_WITH_ARIA_256_GCM_SHA384)
+            }
+            "TLS_ECDH_ECDSA_WITH_ARIA_128_GCM_SHA256" => {
+                Some(Self::TLS_ECDH_ECDSA_WITH_ARIA_128_GCM_SHA256)
+            }
+            "TLS_ECDH_ECDSA_WITH_ARIA_256_GCM_SHA384" => {
+                Some(Self::TLS_ECDH_ECDSA_WITH_ARIA_256_GCM_SHA384)
+            }
+            "TLS_ECDH_RSA_WITH_ARIA_128_GCM_SHA256" => {
+                Some(Self::TLS_ECDH_RSA_WITH_ARIA_128_GCM_SHA256)
+            }
+            "TLS_ECDH_RSA_WITH_ARIA_256_GCM_SHA384" => {
+                Some(Self::TLS_ECDH_RSA_WITH_ARIA_256_GCM_SHA384)
+            }
+            "TLS_PSK_WITH_ARIA_128_CBC_SHA256" => Some(Self::TLS_PSK_WITH_ARIA_128_CBC_SHA256),
+            "TLS_PSK_WITH_ARIA_256_CBC_SHA384" => Some(Self::TLS_PSK_WITH_ARIA_256_CBC_SHA384),
+            "TLS_DHE_PSK_WITH_ARIA_128_CBC_SHA256" => {
+                Some(Self::TLS_DHE_PSK_WITH_ARIA_128_CBC_SHA256)
+            }
+            "TLS_DHE_PSK_WITH_ARIA_256_CBC_SHA384" => {
+                Some(Self::TLS_DHE_PSK_WITH_ARIA_256_CBC_SHA384)
+            }
+            "TLS_RSA_PSK_WITH_ARIA_128_CBC_SHA256" => {
+                Some(Self::TLS_RSA_PSK_WITH_ARIA_128_CBC_SHA256)
+            }
+            "TLS_RSA_PSK_WITH_ARIA_256_CBC_SHA384" => {
+                Some(Self::TLS_RSA_PSK_WITH_ARIA_256_CBC_SHA384)
+            }
+            "TLS_ECDHE_PSK_WITH_ARIA_128_CBC_SHA256" => {
+                Some(Self::TLS_ECDHE_PSK_WITH_ARIA_128_CBC_SHA256)
+            }
+            "TLS_ECDHE_PSK_WITH_ARIA_256_CBC_SHA384" => {
+                Some(Self::TLS_ECDHE_PSK_WITH_ARIA_256_CBC_SHA384)
+            }
+            "TLS_RSA_WITH_CAMELLIA_128_GCM_SHA256" => {
+                Some(Self::TLS_RSA_WITH_CAMELLIA_128_GCM_SHA256)
+            }
+            "TLS_RSA_WITH_CAMELLIA_256_GCM_SHA384" => {
+                Some(Self::TLS_RSA_WITH_CAMELLIA_256_GCM_SHA384)
+            }
+            "TLS_DHE_RSA_WITH_CAMELLIA_128_GCM_SHA256" => {
+                Some(Self::TLS_DHE_RSA_WITH_CAMELLIA_128_GCM_SHA256)
+            }
+            "TLS_DHE_RSA_WITH_CAMELLIA_256_GCM_SHA384" => {
+                Some(Self::TLS_DHE_RSA_WITH_CAMELLIA_256_GCM_SHA384)
+            }
+            "TLS_DH_RSA_WITH_CAMELLIA_128_GCM_SHA256" => {
+                Some(Self::TLS_DH_RSA_WITH_CAMELLIA_128_GCM_SHA256)
+            }
+            "TLS_DH_RSA_WITH_CAMELLIA_256_GCM_SHA384" => {
+                Some(Self::TLS_DH_RSA_WITH_CAMELLIA_256_GCM_SHA384)
+            }
+            "TLS_DHE_DSS_WITH_CAMELLIA_128_GCM_SHA256" => {
+                Some(Self::TLS_DHE_DSS_WITH_CAMELLIA_128_GCM_SHA256)
+            }
+            "TLS_DHE_DSS_WITH_CAMELLIA_256_GCM_SHA384" => {
+                Some(Self::TLS_DHE_DSS_WITH_CAMELLIA_256_GCM_SHA384)
+            }
+            "TLS_DH_DSS_WITH_CAMELLIA_128_GCM_SHA256" => {
+                Some(Self::TLS_DH_DSS_WITH_CAMELLIA_128_GCM_SHA256)
+            }
+            "TLS_DH_DSS_WITH_CAMELLIA_256_GCM_SHA384" => {
+                Some(Self::TLS_DH_DSS_WITH_CAMELLIA_256_GCM_SHA384)
+            }
+            "TLS_DH_anon_WITH_CAMELLIA_128_GCM_SHA256" => {
+                Some(Self::TLS_DH_anon_WITH_CAMELLIA_128_GCM_SHA256)
+            }
+            "TLS_DH_anon_WITH_CAMELLIA_256_GCM_SHA384" => {
+                Some(Self::TLS_DH_anon_WITH_CAMELLIA_256_GCM_SHA384)
+            }
+            "TLS_ECDHE_ECDSA_WITH_CAMELLIA_128_GCM_SHA256" => {
+                Some(Self::TLS_ECDHE_ECDSA_WITH_CAMELLIA_128_GCM_SHA256)
+            }
+            "TLS_ECDHE_ECDSA_WITH_CAMELLIA_256_GCM_SHA384" => {
+                Some(Self::TLS_ECDHE_ECDSA_WITH_CAMELLIA_256_GCM_SHA384)
+            }
+            "TLS_ECDH_ECDSA_WITH_CAMELLIA_128_GCM_SHA256" => {
+                Some(Self::TLS_ECDH_ECDSA_WITH_CAMELLIA_128_GCM_SHA256)
+            }
+            "TLS_ECDH_ECDSA_WITH_CAMELLIA_256_GCM_SHA384" => {
+                Some(Self::TLS_ECDH_ECDSA_WITH_CAMELLIA_256_GCM_SHA384)
+            }
+            "TLS_ECDHE_RSA_WITH_CAMELLIA_128_GCM_SHA256" => {
+                Some(Self::TLS_ECDHE_RSA_WITH_CAMELLIA_128_GCM_SHA256)
+            }
+            "TLS_ECDHE_RSA_WITH_CAMELLIA_256_GCM_SHA384" => {
+                Some(Self::TLS_ECDHE_RSA_WITH_CAMELLIA_256_GCM_SHA384)
+            }
+            "TLS_ECDH_RSA_WITH_CAMELLIA_128_GCM_SHA256" => {
+                Some(Self::TLS_ECDH_RSA_WITH_CAMELLIA_128_GCM_SHA256)
+            }
+            "TLS_ECDH_RSA_WITH_CAMELLIA_256_GCM_SHA384" => {
+                Some(Self::TLS_ECDH_RSA_WITH_CAMELLIA_256_GCM_SHA384)
+            }
+            "TLS_PSK_WITH_CAMELLIA_128_GCM_SHA256" => {
+                Some(Self::TLS_PSK_WITH_CAMELLIA_128_GCM_SHA256)
+            }
+            "TLS_PSK_WITH_CAMELLIA_256_GCM_SHA384" => {
+                Some(Self::TLS_PSK_WITH_CAMELLIA_256_GCM_SHA384)
+            }
+            "TLS_DHE_PSK_WITH_CAMELLIA_128_GCM_SHA256" => {
+                Some(Self::TLS_DHE_PSK_WITH_CAMELLIA_128_GCM_SHA256)
+            }
+            "TLS_DHE_PSK_WITH_CAMELLIA_256_GCM_SHA384" => {
+                Some(Self::TLS_DHE_PSK_WITH_CAMELLIA_256_GCM_SHA384)
+            }
+            "TLS_RSA_PSK_WITH_CAMELLIA_128_GCM_SHA256" => {
+                Some(Self::TLS_RSA_PSK_WITH_CAMELLIA_128_GCM_SHA256)
+            }
+            "TLS_RSA_PSK_WITH_CAMELLIA_256_GCM_SHA384" => {
+                Some(Self::TLS_RSA_PSK_WITH_CAMELLIA_256_GCM_SHA384)
+            }
+            "TLS_ECCPWD_WITH_AES_128_GCM_SHA256" => Some(Self::TLS_ECCPWD_WITH_AES_128_GCM_SHA256),
+            "TLS_ECCPWD_WITH_AES_256_GCM_SHA384" => Some(Self::TLS_ECCPWD_WITH_AES_256_GCM_SHA384),
+            "TLS_ECCPWD_WITH_AES_128_CCM_SHA256" => Some(Self::TLS_ECCPWD_WITH_AES_128_CCM_SHA256),
+            "TLS_ECCPWD_WITH_AES_256_CCM_SHA384" => Some(Self::TLS_ECCPWD_WITH_AES_256_CCM_SHA384),
+            "TLS_SHA256_SHA256" => Some(Self::TLS_SHA256_SHA256),
+            "TLS_SHA384_SHA384" => Some(Self::TLS_SHA384_SHA384),
+            "TLS_GOSTR341112_256_WITH_KUZNYECHIK_MGM_L" => {
+                Some(Self::TLS_GOSTR341112_256_WITH_KUZNYECHIK_MGM_L)
+            }
+            "TLS_GOSTR341112_256_WITH_MAGMA_MGM_L" => {
+                Some(Self::TLS_GOSTR341112_256_WITH_MAGMA_MGM_L)
+            }
+            "TLS_GOSTR341112_256_WITH_KUZNYECHIK_MGM_S" => {
+                Some(Self::TLS_GOSTR341112_256_WITH_KUZNYECHIK_MGM_S)
+            }
+            "TLS_GOSTR341112_256_WITH_MAGMA_MGM_S" => {
+                Some(Self::TLS_GOSTR341112_256_WITH_MAGMA_MGM_S)
+            }
+            "TLS_ECDHE_PSK_WITH_AES_128_GCM_SHA256" => {
+                Some(Self::TLS_ECDHE_PSK_WITH_AES_128_GCM_SHA256)
+            }
+            "TLS_ECDHE_PSK_WITH_AES_256_GCM_SHA384" => {
+                Some(Self::TLS_ECDHE_PSK_WITH_AES_256_GCM_SHA384)
+            }
+            "TLS_ECDHE_PSK_WITH_AES_128_CCM_8_SHA256" => {
+                Some(Self::TLS_ECDHE_PSK_WITH_AES_128_CCM_8_SHA256)
+            }
+            "TLS_ECDHE_PSK_WITH_AES_128_CCM_SHA256" => {
+                Some(Self::TLS_ECDHE_PSK_WITH_AES_128_CCM_SHA256)
+            }
             _ => None,
         }
     }
 
     pub fn as_openssl_name(&self) -> String {
-        let s = match self {
+        self.openssl_name().to_owned()
+    }
+
+    pub(crate) fn openssl_name(&self) -> &'static str {
+        match self {
             Self::TLS_RSA_WITH_NULL_MD5 => "NULL-MD5",
             Self::TLS_RSA_WITH_NULL_SHA => "NULL-SHA",
             Self::TLS_RSA_EXPORT_WITH_RC4_40_MD5 => "EXP-RC4-MD5",
@@ -874,8 +1202,915 @@ impl TLSCipherSuite {
             Self::TLS_RSA_PSK_WITH_NULL_SHA => "RSA-PSK-NULL-SHA",
             Self::TLS_RSA_PSK_WITH_NULL_SHA256 => "RSA-PSK-NULL-SHA256",
             Self::TLS_RSA_PSK_WITH_NULL_SHA384 => "RSA-PSK-NULL-SHA384",
-        };
+            Self::TLS_AES_128_GCM_SHA256 => "TLS_AES_128_GCM_SHA256",
+            Self::TLS_AES_256_GCM_SHA384 => "TLS_AES_256_GCM_SHA384",
+            Self::TLS_CHACHA20_POLY1305_SHA256 => "TLS_CHACHA20_POLY1305_SHA256",
+            Self::TLS_AES_128_CCM_SHA256 => "TLS_AES_128_CCM_SHA256",
+            Self::TLS_AES_128_CCM_8_SHA256 => "TLS_AES_128_CCM_8_SHA256",
+            Self::TLS_GOSTR341112_256_WITH_KUZNYECHIK_CTR_OMAC => {
+                "GOST2012-KUZNYECHIK-KUZNYECHIKOMAC"
+            }
+            Self::TLS_GOSTR341112_256_WITH_MAGMA_CTR_OMAC => "GOST2012-MAGMA-MAGMAOMAC",
+            Self::TLS_GOSTR341112_256_WITH_28147_CNT_IMIT => "IANA-GOST2012-GOST8912-GOST8912",
+            // OpenSSL does not implement the following suites. Like the TLS 1.3
+            // suites, they are named after the RFC so that they still round-trip.
+            Self::TLS_NULL_WITH_NULL_NULL => "TLS_NULL_WITH_NULL_NULL",
+            Self::TLS_SM4_GCM_SM3 => "TLS_SM4_GCM_SM3",
+            Self::TLS_SM4_CCM_SM3 => "TLS_SM4_CCM_SM3",
+            Self::TLS_EMPTY_RENEGOTIATION_INFO_SCSV => "TLS_EMPTY_RENEGOTIATION_INFO_SCSV",
+            Self::TLS_AEGIS_256_SHA512 => "TLS_AEGIS_256_SHA512",
+            Self::TLS_AEGIS_128L_SHA256 => "TLS_AEGIS_128L_SHA256",
+            Self::TLS_RSA_WITH_ARIA_128_CBC_SHA256 => "TLS_RSA_WITH_ARIA_128_CBC_SHA256",
+            Self::TLS_RSA_WITH_ARIA_256_CBC_SHA384 => "TLS_RSA_WITH_ARIA_256_CBC_SHA384",
+            Self::TLS_DH_DSS_WITH_ARIA_128_CBC_SHA256 => "TLS_DH_DSS_WITH_ARIA_128_CBC_SHA256",
+            Self::TLS_DH_DSS_WITH_ARIA_256_CBC_SHA384 => "TLS_DH_DSS_WITH_ARIA_256_CBC_SHA384",
+            Self::TLS_DH_RSA_WITH_ARIA_128_CBC_SHA256 => "TLS_DH_RSA_WITH_ARIA_128_CBC_SHA256",
+            Self::TLS_DH_RSA_WITH_ARIA_256_CBC_SHA384 => "TLS_DH_RSA_WITH_ARIA_256_CBC_SHA384",
+            Self::TLS_DHE_DSS_WITH_ARIA_128_CBC_SHA256 => "TLS_DHE_DSS_WITH_ARIA_128_CBC_SHA256",
+            Self::TLS_DHE_DSS_WITH_ARIA_256_CBC_SHA384 => "TLS_DHE_DSS_WITH_ARIA_256_CBC_SHA384",
+            Self::TLS_DHE_RSA_WITH_ARIA_128_CBC_SHA256 => "TLS_DHE_RSA_WITH_ARIA_128_CBC_SHA256",
+            Self::TLS_DHE_RSA_WITH_ARIA_256_CBC_SHA384 => "TLS_DHE_RSA_WITH_ARIA_256_CBC_SHA384",
+            Self::TLS_DH_anon_WITH_ARIA_128_CBC_SHA256 => "TLS_DH_anon_WITH_ARIA_128_CBC_SHA256",
+            Self::TLS_DH_anon_WITH_ARIA_256_CBC_SHA384 => "TLS_DH_anon_WITH_ARIA_256_CBC_SHA384",
+            Self::TLS_ECDHE_ECDSA_WITH_ARIA_128_CBC_SHA256 => {
+                "TLS_ECDHE_ECDSA_WITH_ARIA_128_CBC_SHA256"
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_ARIA_256_CBC_SHA384 => {
+                "TLS_ECDHE_ECDSA_WITH_ARIA_256_CBC_SHA384"
+            }
+            Self::TLS_ECDH_ECDSA_WITH_ARIA_128_CBC_SHA256 => {
+                "TLS_ECDH_ECDSA_WITH_ARIA_128_CBC_SHA256"
+            }
+            Self::TLS_ECDH_ECDSA_WITH_ARIA_256_CBC_SHA384 => {
+                "TLS_ECDH_ECDSA_WITH_ARIA_256_CBC_SHA384"
+            }
+            Self::TLS_ECDHE_RSA_WITH_ARIA_128_CBC_SHA256 => {
+                "TLS_ECDHE_RSA_WITH_ARIA_128_CBC_SHA256"
+            }
+            Self::TLS_ECDHE_RSA_WITH_ARIA_256_CBC_SHA384 => {
+                "TLS_ECDHE_RSA_WITH_ARIA_256_CBC_SHA384"
+            }
+            Self::TLS_ECDH_RSA_WITH_ARIA_128_CBC_SHA256 => "TLS_ECDH_RSA_WITH_ARIA_128_CBC_SHA256",
+            Self::TLS_ECDH_RSA_WITH_ARIA_256_CBC_SHA384 => "TLS_ECDH_RSA_WITH_ARIA_256_CBC_SHA384",
+            Self::TLS_DH_RSA_WITH_ARIA_128_GCM_SHA256 => "TLS_DH_RSA_WITH_ARIA_128_GCM_SHA256",
+            Self::TLS_DH_RSA_WITH_ARIA_256_GCM_SHA384 => "TLS_DH_RSA_WITH_ARIA_256_GCM_SHA384",
+            Self::TLS_DH_DSS_WITH_ARIA_128_GCM_SHA256 => "TLS_DH_DSS_WITH_ARIA_128_GCM_SHA256",
+            Self::TLS_DH_DSS_WITH_ARIA_256_GCM_SHA384 => "TLS_DH_DSS_WITH_ARIA_256_GCM_SHA384",
+            Self::TLS_DH_anon_WITH_ARIA_128_GCM_SHA256 => "TLS_DH_anon_WITH_ARIA_128_GCM_SHA256",
+            Self::TLS_DH_anon_WITH_ARIA_256_GCM_SHA384 => "TLS_DH_anon_WITH_ARIA_256_GCM_SHA384",
+            Self::TLS_ECDH_ECDSA_WITH_ARIA_128_GCM_SHA256 => {
+                "TLS_ECDH_ECDSA_WITH_ARIA_128_GCM_SHA256"
+            }
+            Self::TLS_ECDH_ECDSA_WITH_ARIA_256_GCM_SHA384 => {
+                "TLS_ECDH_ECDSA_WITH_ARIA_256_GCM_SHA384"
+            }
+            Self::TLS_ECDH_RSA_WITH_ARIA_128_GCM_SHA256 => "TLS_ECDH_RSA_WITH_ARIA_128_GCM_SHA256",
+            Self::TLS_ECDH_RSA_WITH_ARIA_256_GCM_SHA384 => "TLS_ECDH_RSA_WITH_ARIA_256_GCM_SHA384",
+            Self::TLS_PSK_WITH_ARIA_128_CBC_SHA256 => "TLS_PSK_WITH_ARIA_128_CBC_SHA256",
+            Self::TLS_PSK_WITH_ARIA_256_CBC_SHA384 => "TLS_PSK_WITH_ARIA_256_CBC_SHA384",
+            Self::TLS_DHE_PSK_WITH_ARIA_128_CBC_SHA256 => "TLS_DHE_PSK_WITH_ARIA_128_CBC_SHA256",
+            Self::TLS_DHE_PSK_WITH_ARIA_256_CBC_SHA384 => "TLS_DHE_PSK_WITH_ARIA_256_CBC_SHA384",
+            Self::TLS_RSA_PSK_WITH_ARIA_128_CBC_SHA256 => "TLS_RSA_PSK_WITH_ARIA_128_CBC_SHA256",
+            Self::TLS_RSA_PSK_WITH_ARIA_256_CBC_SHA384 => "TLS_RSA_PSK_WITH_ARIA_256_CBC_SHA384",
+            Self::TLS_ECDHE_PSK_WITH_ARIA_128_CBC_SHA256 => {
+                "TLS_ECDHE_PSK_WITH_ARIA_128_CBC_SHA256"
+            }
+            Self::TLS_ECDHE_PSK_WITH_ARIA_256_CBC_SHA384 => {
+                "TLS_ECDHE_PSK_WITH_ARIA_256_CBC_SHA384"
+            }
+            Self::TLS_RSA_WITH_CAMELLIA_128_GCM_SHA256 => "TLS_RSA_WITH_CAMELLIA_128_GCM_SHA256",
+            Self::TLS_RSA_WITH_CAMELLIA_256_GCM_SHA384 => "TLS_RSA_WITH_CAMELLIA_256_GCM_SHA384",
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_128_GCM_SHA256 => {
+                "TLS_DHE_RSA_WITH_CAMELLIA_128_GCM_SHA256"
+            }
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_256_GCM_SHA384 => {
+                "TLS_DHE_RSA_WITH_CAMELLIA_256_GCM_SHA384"
+            }
+            Self::TLS_DH_RSA_WITH_CAMELLIA_128_GCM_SHA256 => {
+                "TLS_DH_RSA_WITH_CAMELLIA_128_GCM_SHA256"
+            }
+            Self::TLS_DH_RSA_WITH_CAMELLIA_256_GCM_SHA384 => {
+                "TLS_DH_RSA_WITH_CAMELLIA_256_GCM_SHA384"
+            }
+            Self::TLS_DHE_DSS_WITH_CAMELLIA_128_GCM_SHA256 => {
+                "TLS_DHE_DSS_WITH_CAMELLIA_128_GCM_SHA256"
+            }
+            Self::TLS_DHE_DSS_WITH_CAMELLIA_256_GCM_SHA384 => {
+                "TLS_DHE_DSS_WITH_CAMELLIA_256_GCM_SHA384"
+            }
+            Self::TLS_DH_DSS_WITH_CAMELLIA_128_GCM_SHA256 => {
+                "TLS_DH_DSS_WITH_CAMELLIA_128_GCM_SHA256"
+            }
+            Self::TLS_DH_DSS_WITH_CAMELLIA_256_GCM_SHA384 => {
+                "TLS_DH_DSS_WITH_CAMELLIA_256_GCM_SHA384"
+            }
+            Self::TLS_DH_anon_WITH_CAMELLIA_128_GCM_SHA256 => {
+                "TLS_DH_anon_WITH_CAMELLIA_128_GCM_SHA256"
+            }
+            Self::TLS_DH_anon_WITH_CAMELLIA_256_GCM_SHA384 => {
+                "TLS_DH_anon_WITH_CAMELLIA_256_GCM_SHA384"
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_CAMELLIA_128_GCM_SHA256 => {
+                "TLS_ECDHE_ECDSA_WITH_CAMELLIA_128_GCM_SHA256"
+            }
+            Self::TLS_ECDHE_ECDSA_WITH_CAMELLIA_256_GCM_SHA384 => {
+                "TLS_ECDHE_ECDSA_WITH_CAMELLIA_256_GCM_SHA384"
+            }
+            Self::TLS_ECDH_ECDSA_WITH_CAMELLIA_128_GCM_SHA256 => {
+                "TLS_ECDH_ECDSA_WITH_CAMELLIA_128_GCM_SHA256"
+            }
+            Self::TLS_ECDH_ECDSA_WITH_CAMELLIA_256_GCM_SHA384 => {
+                "TLS_ECDH_ECDSA_WITH_CAMELLIA_256_GCM_SHA384"
+            }
+            Self::TLS_ECDHE_RSA_WITH_CAMELLIA_128_GCM_SHA256 => {
+                "TLS_ECDHE_RSA_WITH_CAMELLIA_128_GCM_SHA256"
+            }
+            Self::TLS_ECDHE_RSA_WITH_CAMELLIA_256_GCM_SHA384 => {
+                "TLS_ECDHE_RSA_WITH_CAMELLIA_256_GCM_SHA384"
+            }
+            Self::TLS_ECDH_RSA_WITH_CAMELLIA_128_GCM_SHA256 => {
+                "TLS_ECDH_RSA_WITH_CAMELLIA_128_GCM_SHA256"
+            }
+            Self::TLS_ECDH_RSA_WITH_CAMELLIA_256_GCM_SHA384 => {
+                "TLS_ECDH_RSA_WITH_CAMELLIA_256_GCM_SHA384"
+            }
+            Self::TLS_PSK_WITH_CAMELLIA_128_GCM_SHA256 => "TLS_PSK_WITH_CAMELLIA_128_GCM_SHA256",
+            Self::TLS_PSK_WITH_CAMELLIA_256_GCM_SHA384 => "TLS_PSK_WITH_CAMELLIA_256_GCM_SHA384",
+            Self::TLS_DHE_PSK_WITH_CAMELLIA_128_GCM_SHA256 => {
+                "TLS_DHE_PSK_WITH_CAMELLIA_128_GCM_SHA256"
+            }
+            Self::TLS_DHE_PSK_WITH_CAMELLIA_256_GCM_SHA384 => {
+                "TLS_DHE_PSK_WITH_CAMELLIA_256_GCM_SHA384"
+            }
+            Self::TLS_RSA_PSK_WITH_CAMELLIA_128_GCM_SHA256 => {
+                "TLS_RSA_PSK_WITH_CAMELLIA_128_GCM_SHA256"
+            }
+            Self::TLS_RSA_PSK_WITH_CAMELLIA_256_GCM_SHA384 => {
+                "TLS_RSA_PSK_WITH_CAMELLIA_256_GCM_SHA384"
+            }
+            Self::TLS_ECCPWD_WITH_AES_128_GCM_SHA256 => "TLS_ECCPWD_WITH_AES_128_GCM_SHA256",
+            Self::TLS_ECCPWD_WITH_AES_256_GCM_SHA384 => "TLS_ECCPWD_WITH_AES_256_GCM_SHA384",
+            Self::TLS_ECCPWD_WITH_AES_128_CCM_SHA256 => "TLS_ECCPWD_WITH_AES_128_CCM_SHA256",
+            Self::TLS_ECCPWD_WITH_AES_256_CCM_SHA384 => "TLS_ECCPWD_WITH_AES_256_CCM_SHA384",
+            Self::TLS_SHA256_SHA256 => "TLS_SHA256_SHA256",
+            Self::TLS_SHA384_SHA384 => "TLS_SHA384_SHA384",
+            Self::TLS_GOSTR341112_256_WITH_KUZNYECHIK_MGM_L => {
+                "TLS_GOSTR341112_256_WITH_KUZNYECHIK_MGM_L"
+            }
+            Self::TLS_GOSTR341112_256_WITH_MAGMA_MGM_L => "TLS_GOSTR341112_256_WITH_MAGMA_MGM_L",
+            Self::TLS_GOSTR341112_256_WITH_KUZNYECHIK_MGM_S => {
+                "TLS_GOSTR341112_256_WITH_KUZNYECHIK_MGM_S"
+            }
+            Self::TLS_GOSTR341112_256_WITH_MAGMA_MGM_S => "TLS_GOSTR341112_256_WITH_MAGMA_MGM_S",
+            Self::TLS_ECDHE_PSK_WITH_AES_128_GCM_SHA256 => "TLS_ECDHE_PSK_WITH_AES_128_GCM_SHA256",
+            Self::TLS_ECDHE_PSK_WITH_AES_256_GCM_SHA384 => "TLS_ECDHE_PSK_WITH_AES_256_GCM_SHA384",
+            Self::TLS_ECDHE_PSK_WITH_AES_128_CCM_8_SHA256 => {
+                "TLS_ECDHE_PSK_WITH_AES_128_CCM_8_SHA256"
+            }
+            Self::TLS_ECDHE_PSK_WITH_AES_128_CCM_SHA256 => "TLS_ECDHE_PSK_WITH_AES_128_CCM_SHA256",
+        }
+    }
 
-        s.to_owned()
+    pub fn from_u16(value: u16) -> Option<TLSCipherSuite> {
+        match value {
+            0x0001 => Some(Self::TLS_RSA_WITH_NULL_MD5),
+            0x0002 => Some(Self::TLS_RSA_WITH_NULL_SHA),
+            0x0003 => Some(Self::TLS_RSA_EXPORT_WITH_RC4_40_MD5),
+            0x0004 => Some(Self::TLS_RSA_WITH_RC4_128_MD5),
+            0x0005 => Some(Self::TLS_RSA_WITH_RC4_128_SHA),
+            0x0006 => Some(Self::TLS_RSA_EXPORT_WITH_RC2_CBC_40_MD5),
+            0x0007 => Some(Self::TLS_RSA_WITH_IDEA_CBC_SHA),
+            0x0008 => Some(Self::TLS_RSA_EXPORT_WITH_DES40_CBC_SHA),
+            0x0009 => Some(Self::TLS_RSA_WITH_DES_CBC_SHA),
+            0x000A => Some(Self::TLS_RSA_WITH_3DES_EDE_CBC_SHA),
+            0x000B => Some(Self::TLS_DH_DSS_EXPORT_WITH_DES40_CBC_SHA),
+            0x000C => Some(Self::TLS_DH_DSS_WITH_DES_CBC_SHA),
+            0x000D => Some(Self::TLS_DH_DSS_WITH_3DES_EDE_CBC_SHA),
+            0x000E => Some(Self::TLS_DH_RSA_EXPORT_WITH_DES40_CBC_SHA),
+            0x000F => Some(Self::TLS_DH_RSA_WITH_DES_CBC_SHA),
+            0x0010 => Some(Self::TLS_DH_RSA_WITH_3DES_EDE_CBC_SHA),
+            0x0011 => Some(Self::TLS_DHE_DSS_EXPORT_WITH_DES40_CBC_SHA),
+            0x0012 => Some(Self::TLS_DHE_DSS_WITH_DES_CBC_SHA),
+            0x0013 => Some(Self::TLS_DHE_DSS_WITH_3DES_EDE_CBC_SHA),
+            0x0014 => Some(Self::TLS_DHE_RSA_EXPORT_WITH_DES40_CBC_SHA),
+            0x0015 => Some(Self::TLS_DHE_RSA_WITH_DES_CBC_SHA),
+            0x0016 => Some(Self::TLS_DHE_RSA_WITH_3DES_EDE_CBC_SHA),
+            0x0017 => Some(Self::TLS_DH_anon_EXPORT_WITH_RC4_40_MD5),
+            0x0018 => Some(Self::TLS_DH_anon_WITH_RC4_128_MD5),
+            0x0019 => Some(Self::TLS_DH_anon_EXPORT_WITH_DES40_CBC_SHA),
+            0x001A => Some(Self::TLS_DH_anon_WITH_DES_CBC_SHA),
+            0x001B => Some(Self::TLS_DH_anon_WITH_3DES_EDE_CBC_SHA),
+            0x001E => Some(Self::TLS_KRB5_WITH_DES_CBC_SHA),
+            0x001F => Some(Self::TLS_KRB5_WITH_3DES_EDE_CBC_SHA),
+            0x0020 => Some(Self::TLS_KRB5_WITH_RC4_128_SHA),
+            0x0021 => Some(Self::TLS_KRB5_WITH_IDEA_CBC_SHA),
+            0x0022 => Some(Self::TLS_KRB5_WITH_DES_CBC_MD5),
+            0x0023 => Some(Self::TLS_KRB5_WITH_3DES_EDE_CBC_MD5),
+            0x0024 => Some(Self::TLS_KRB5_WITH_RC4_128_MD5),
+            0x0025 => Some(Self::TLS_KRB5_WITH_IDEA_CBC_MD5),
+            0x0026 => Some(Self::TLS_KRB5_EXPORT_WITH_DES_CBC_40_SHA),
+            0x0027 => Some(Self::TLS_KRB5_EXPORT_WITH_RC2_CBC_40_SHA),
+            0x0028 => Some(Self::TLS_KRB5_EXPORT_WITH_RC4_40_SHA),
+            0x0029 => Some(Self::TLS_KRB5_EXPORT_WITH_DES_CBC_40_MD5),
+            0x002A => Some(Self::TLS_KRB5_EXPORT_WITH_RC2_CBC_40_MD5),
+            0x002B => Some(Self::TLS_KRB5_EXPORT_WITH_RC4_40_MD5),
+            0x002F => Some(Self::TLS_RSA_WITH_AES_128_CBC_SHA),
+            0x0030 => Some(Self::TLS_DH_DSS_WITH_AES_128_CBC_SHA),
+            0x0031 => Some(Self::TLS_DH_RSA_WITH_AES_128_CBC_SHA),
+            0x0032 => Some(Self::TLS_DHE_DSS_WITH_AES_128_CBC_SHA),
+            0x0033 => Some(Self::TLS_DHE_RSA_WITH_AES_128_CBC_SHA),
+            0x0034 => Some(Self::TLS_DH_anon_WITH_AES_128_CBC_SHA),
+            0x0035 => Some(Self::TLS_RSA_WITH_AES_256_CBC_SHA),
+            0x0036 => Some(Self::TLS_DH_DSS_WITH_AES_256_CBC_SHA),
+            0x0037 => Some(Self::TLS_DH_RSA_WITH_AES_256_CBC_SHA),
+            0x0038 => Some(Self::TLS_DHE_DSS_WITH_AES_256_CBC_SHA),
+            0x0039 => Some(Self::TLS_DHE_RSA_WITH_AES_256_CBC_SHA),
+            0x003A => Some(Self::TLS_DH_anon_WITH_AES_256_CBC_SHA),
+            0x003B => Some(Self::TLS_RSA_WITH_NULL_SHA256),
+            0x003C => Some(Self::TLS_RSA_WITH_AES_128_CBC_SHA256),
+            0x003D => Some(Self::TLS_RSA_WITH_AES_256_CBC_SHA256),
+            0x003E => Some(Self::TLS_DH_DSS_WITH_AES_128_CBC_SHA256),
+            0x003F => Some(Self::TLS_DH_RSA_WITH_AES_128_CBC_SHA256),
+            0x0040 => Some(Self::TLS_DHE_DSS_WITH_AES_128_CBC_SHA256),
+            0x0041 => Some(Self::TLS_RSA_WITH_CAMELLIA_128_CBC_SHA),
+            0x0042 => Some(Self::TLS_DH_DSS_WITH_CAMELLIA_128_CBC_SHA),
+            0x0043 => Some(Self::TLS_DH_RSA_WITH_CAMELLIA_128_CBC_SHA),
+            0x0044 => Some(Self::TLS_DHE_DSS_WITH_CAMELLIA_128_CBC_SHA),
+            0x0045 => Some(Self::TLS_DHE_RSA_WITH_CAMELLIA_128_CBC_SHA),
+            0x0046 => Some(Self::TLS_DH_anon_WITH_CAMELLIA_128_CBC_SHA),
+            0x0062 => Some(Self::TLS_RSA_EXPORT1024_WITH_DES_CBC_SHA),
+            0x0063 => Some(Self::TLS_DHE_DSS_EXPORT1024_WITH_DES_CBC_SHA),
+            0x0064 => Some(Self::TLS_RSA_EXPORT1024_WITH_RC4_56_SHA),
+            0x0060 => Some(Self::TLS_RSA_EXPORT1024_WITH_RC4_56_MD5),
+            0x0061 => Some(Self::TLS_RSA_EXPORT1024_WITH_RC2_CBC_56_MD5),
+            0x0065 => Some(Self::TLS_DHE_DSS_EXPORT1024_WITH_RC4_56_SHA),
+            0x0066 => Some(Self::TLS_DHE_DSS_WITH_RC4_128_SHA),
+            0x0067 => Some(Self::TLS_DHE_RSA_WITH_AES_128_CBC_SHA256),
+            0x0068 => Some(Self::TLS_DH_DSS_WITH_AES_256_CBC_SHA256),
+            0x0069 => Some(Self::TLS_DH_RSA_WITH_AES_256_CBC_SHA256),
+            0x006A => Some(Self::TLS_DHE_DSS_WITH_AES_256_CBC_SHA256),
+            0x006B => Some(Self::TLS_DHE_RSA_WITH_AES_256_CBC_SHA256),
+            0x006C => Some(Self::TLS_DH_anon_WITH_AES_128_CBC_SHA256),
+            0x006D => Some(Self::TLS_DH_anon_WITH_AES_256_CBC_SHA256),
+            0x0080 => Some(Self::TLS_GOSTR341094_WITH_28147_CNT_IMIT),
+            0x0081 => Some(Self::TLS_GOSTR341001_WITH_28147_CNT_IMIT),
+            0x0084 => Some(Self::TLS_RSA_WITH_CAMELLIA_256_CBC_SHA),
+            0x0085 => Some(Self::TLS_DH_DSS_WITH_CAMELLIA_256_CBC_SHA),
+            0x0086 => Some(Self::TLS_DH_RSA_WITH_CAMELLIA_256_CBC_SHA),
+            0x0087 => Some(Self::TLS_DHE_DSS_WITH_CAMELLIA_256_CBC_SHA),
+            0x0088 => Some(Self::TLS_DHE_RSA_WITH_CAMELLIA_256_CBC_SHA),
+            0x0089 => Some(Self::TLS_DH_anon_WITH_CAMELLIA_256_CBC_SHA),
+            0x008A => Some(Self::TLS_PSK_WITH_RC4_128_SHA),
+            0x008B => Some(Self::TLS_PSK_WITH_3DES_EDE_CBC_SHA),
+            0x008C => Some(Self::TLS_PSK_WITH_AES_128_CBC_SHA),
+            0x008D => Some(Self::TLS_PSK_WITH_AES_256_CBC_SHA),
+            0x0092 => Some(Self::TLS_RSA_PSK_WITH_RC4_128_SHA),
+            0x0093 => Some(Self::TLS_RSA_PSK_WITH_3DES_EDE_CBC_SHA),
+            0x0094 => Some(Self::TLS_RSA_PSK_WITH_AES_128_CBC_SHA),
+            0x0095 => Some(Self::TLS_RSA_PSK_WITH_AES_256_CBC_SHA),
+            0x0096 => Some(Self::TLS_RSA_WITH_SEED_CBC_SHA),
+            0x0097 => Some(Self::TLS_DH_DSS_WITH_SEED_CBC_SHA),
+            0x0098 => Some(Self::TLS_DH_RSA_WITH_SEED_CBC_SHA),
+            0x0099 => Some(Self::TLS_DHE_DSS_WITH_SEED_CBC_SHA),
+            0x009A => Some(Self::TLS_DHE_RSA_WITH_SEED_CBC_SHA),
+            0x009B => Some(Self::TLS_DH_anon_WITH_SEED_CBC_SHA),
+            0x009C => Some(Self::TLS_RSA_WITH_AES_128_GCM_SHA256),
+            0x009D => Some(Self::TLS_RSA_WITH_AES_256_GCM_SHA384),
+            0x009E => Some(Self::TLS_DHE_RSA_WITH_AES_128_GCM_SHA256),
+            0x009F => Some(Self::TLS_DHE_RSA_WITH_AES_256_GCM_SHA384),
+            0x00A0 => Some(Self::TLS_DH_RSA_WITH_AES_128_GCM_SHA256),
+            0x00A1 => Some(Self::TLS_DH_RSA_WITH_AES_256_GCM_SHA384),
+            0x00A2 => Some(Self::TLS_DHE_DSS_WITH_AES_128_GCM_SHA256),
+            0x00A3 => Some(Self::TLS_DHE_DSS_WITH_AES_256_GCM_SHA384),
+            0x00A4 => Some(Self::TLS_DH_DSS_WITH_AES_128_GCM_SHA256),
+            0x00A5 => Some(Self::TLS_DH_DSS_WITH_AES_256_GCM_SHA384),
+            0x00A6 => Some(Self::TLS_DH_anon_WITH_AES_128_GCM_SHA256),
+            0x00A7 => Some(Self::TLS_DH_anon_WITH_AES_256_GCM_SHA384),
+            0x00BA => Some(Self::TLS_RSA_WITH_CAMELLIA_128_CBC_SHA256),
+            0x00BB => Some(Self::TLS_DH_DSS_WITH_CAMELLIA_128_CBC_SHA256),
+            0x00BC => Some(Self::TLS_DH_RSA_WITH_CAMELLIA_128_CBC_SHA256),
+            0x00BD => Some(Self::TLS_DHE_DSS_WITH_CAMELLIA_128_CBC_SHA256),
+            0x00BE => Some(Self::TLS_DHE_RSA_WITH_CAMELLIA_128_CBC_SHA256),
+            0x00BF => Some(Self::TLS_DH_anon_WITH_CAMELLIA_128_CBC_SHA256),
+            0x00C0 => Some(Self::TLS_RSA_WITH_CAMELLIA_256_CBC_SHA256),
+            0x00C1 => Some(Self::TLS_DH_DSS_WITH_CAMELLIA_256_CBC_SHA256),
+            0x00C2 => Some(Self::TLS_DH_RSA_WITH_CAMELLIA_256_CBC_SHA256),
+            0x00C3 => Some(Self::TLS_DHE_DSS_WITH_CAMELLIA_256_CBC_SHA256),
+            0x00C4 => Some(Self::TLS_DHE_RSA_WITH_CAMELLIA_256_CBC_SHA256),
+            0x00C5 => Some(Self::TLS_DH_anon_WITH_CAMELLIA_256_CBC_SHA256),
+            0x5600 => Some(Self::TLS_FALLBACK_SCSV),
+            0xC001 => Some(Self::TLS_ECDH_ECDSA_WITH_NULL_SHA),
+            0xC002 => Some(Self::TLS_ECDH_ECDSA_WITH_RC4_128_SHA),
+            0xC003 => Some(Self::TLS_ECDH_ECDSA_WITH_3DES_EDE_CBC_SHA),
+            0xC004 => Some(Self::TLS_ECDH_ECDSA_WITH_AES_128_CBC_SHA),
+            0xC005 => Some(Self::TLS_ECDH_ECDSA_WITH_AES_256_CBC_SHA),
+            0xC006 => Some(Self::TLS_ECDHE_ECDSA_WITH_NULL_SHA),
+            0xC007 => Some(Self::TLS_ECDHE_ECDSA_WITH_RC4_128_SHA),
+            0xC008 => Some(Self::TLS_ECDHE_ECDSA_WITH_3DES_EDE_CBC_SHA),
+            0xC009 => Some(Self::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA),
+            0xC00A => Some(Self::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA),
+            0xC00B => Some(Self::TLS_ECDH_RSA_WITH_NULL_SHA),
+            0xC00C => Some(Self::TLS_ECDH_RSA_WITH_RC4_128_SHA),
+            0xC00D => Some(Self::TLS_ECDH_RSA_WITH_3DES_EDE_CBC_SHA),
+            0xC00E => Some(Self::TLS_ECDH_RSA_WITH_AES_128_CBC_SHA),
+            0xC00F => Some(Self::TLS_ECDH_RSA_WITH_AES_256_CBC_SHA),
+            0xC010 => Some(Self::TLS_ECDHE_RSA_WITH_NULL_SHA),
+            0xC011 => Some(Self::TLS_ECDHE_RSA_WITH_RC4_128_SHA),
+            0xC012 => Some(Self::TLS_ECDHE_RSA_WITH_3DES_EDE_CBC_SHA),
+            0xC013 => Some(Self::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA),
+            0xC014 => Some(Self::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA),
+            0xC015 => Some(Self::TLS_ECDH_anon_WITH_NULL_SHA),
+            0xC016 => Some(Self::TLS_ECDH_anon_WITH_RC4_128_SHA),
+            0xC017 => Some(Self::TLS_ECDH_anon_WITH_3DES_EDE_CBC_SHA),
+            0xC018 => Some(Self::TLS_ECDH_anon_WITH_AES_128_CBC_SHA),
+            0xC019 => Some(Self::TLS_ECDH_anon_WITH_AES_256_CBC_SHA),
+            0xC01A => Some(Self::TLS_SRP_SHA_WITH_3DES_EDE_CBC_SHA),
+            0xC01B => Some(Self::TLS_SRP_SHA_RSA_WITH_3DES_EDE_CBC_SHA),
+            0xC01C => Some(Self::TLS_SRP_SHA_DSS_WITH_3DES_EDE_CBC_SHA),
+            0xC01D => Some(Self::TLS_SRP_SHA_WITH_AES_128_CBC_SHA),
+            0xC01E => Some(Self::TLS_SRP_SHA_RSA_WITH_AES_128_CBC_SHA),
+            0xC01F => Some(Self::TLS_SRP_SHA_DSS_WITH_AES_128_CBC_SHA),
+            0xC020 => Some(Self::TLS_SRP_SHA_WITH_AES_256_CBC_SHA),
+            0xC021 => Some(Self::TLS_SRP_SHA_RSA_WITH_AES_256_CBC_SHA),
+            0xC022 => Some(Self::TLS_SRP_SHA_DSS_WITH_AES_256_CBC_SHA),
+            0xC023 => Some(Self::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA256),
+            0xC024 => Some(Self::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA384),
+            0xC025 => Some(Self::TLS_ECDH_ECDSA_WITH_AES_128_CBC_SHA256),
+            0xC026 => Some(Self::TLS_ECDH_ECDSA_WITH_AES_256_CBC_SHA384),
+            0xC027 => Some(Self::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA256),
+            0xC028 => Some(Self::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA384),
+            0xC029 => Some(Self::TLS_ECDH_RSA_WITH_AES_128_CBC_SHA256),
+            0xC02A => Some(Self::TLS_ECDH_RSA_WITH_AES_256_CBC_SHA384),
+            0xC02B => Some(Self::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256),
+            0xC02C => Some(Self::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384),
+            0xC02D => Some(Self::TLS_ECDH_ECDSA_WITH_AES_128_GCM_SHA256),
+            0xC02E => Some(Self::TLS_ECDH_ECDSA_WITH_AES_256_GCM_SHA384),
+            0xC02F => Some(Self::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256),
+            0xC030 => Some(Self::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384),
+            0xC031 => Some(Self::TLS_ECDH_RSA_WITH_AES_128_GCM_SHA256),
+            0xC032 => Some(Self::TLS_ECDH_RSA_WITH_AES_256_GCM_SHA384),
+            0xC072 => Some(Self::TLS_ECDHE_ECDSA_WITH_CAMELLIA_128_CBC_SHA256),
+            0xC073 => Some(Self::TLS_ECDHE_ECDSA_WITH_CAMELLIA_256_CBC_SHA384),
+            0xC074 => Some(Self::TLS_ECDH_ECDSA_WITH_CAMELLIA_128_CBC_SHA256),
+            0xC075 => Some(Self::TLS_ECDH_ECDSA_WITH_CAMELLIA_256_CBC_SHA384),
+            0xC076 => Some(Self::TLS_ECDHE_RSA_WITH_CAMELLIA_128_CBC_SHA256),
+            0xC077 => Some(Self::TLS_ECDHE_RSA_WITH_CAMELLIA_256_CBC_SHA384),
+            0xC078 => Some(Self::TLS_ECDH_RSA_WITH_CAMELLIA_128_CBC_SHA256),
+            0xC079 => Some(Self::TLS_ECDH_RSA_WITH_CAMELLIA_256_CBC_SHA384),
+            0xCCA8 => Some(Self::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256),
+            0xCCA9 => Some(Self::TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256),
+            0xCCAA => Some(Self::TLS_DHE_RSA_WITH_CHACHA20_POLY1305_SHA256),
+            0xCC13 => Some(Self::OLD_TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256),
+            0xCC14 => Some(Self::OLD_TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256),
+            0xCC15 => Some(Self::OLD_TLS_DHE_RSA_WITH_CHACHA20_POLY1305_SHA256),
+            0xC09C => Some(Self::TLS_RSA_WITH_AES_128_CCM),
+            0xC09D => Some(Self::TLS_RSA_WITH_AES_256_CCM),
+            0xC09E => Some(Self::TLS_DHE_RSA_WITH_AES_128_CCM),
+            0xC09F => Some(Self::TLS_DHE_RSA_WITH_AES_256_CCM),
+            0xC0A0 => Some(Self::TLS_RSA_WITH_AES_128_CCM_8),
+            0xC0A1 => Some(Self::TLS_RSA_WITH_AES_256_CCM_8),
+            0xC0A2 => Some(Self::TLS_DHE_RSA_WITH_AES_128_CCM_8),
+            0xC0A3 => Some(Self::TLS_DHE_RSA_WITH_AES_256_CCM_8),
+            0xC0AC => Some(Self::TLS_ECDHE_ECDSA_WITH_AES_128_CCM),
+            0xC0AD => Some(Self::TLS_ECDHE_ECDSA_WITH_AES_256_CCM),
+            0xC0AE => Some(Self::TLS_ECDHE_ECDSA_WITH_AES_128_CCM_8),
+            0xC0AF => Some(Self::TLS_ECDHE_ECDSA_WITH_AES_256_CCM_8),
+            0xC050 => Some(Self::TLS_RSA_WITH_ARIA_128_GCM_SHA256),
+            0xC051 => Some(Self::TLS_RSA_WITH_ARIA_256_GCM_SHA384),
+            0xC056 => Some(Self::TLS_DHE_DSS_WITH_ARIA_128_GCM_SHA256),
+            0xC057 => Some(Self::TLS_DHE_DSS_WITH_ARIA_256_GCM_SHA384),
+            0x008F => Some(Self::TLS_DHE_PSK_WITH_3DES_EDE_CBC_SHA),
+            0x0090 => Some(Self::TLS_DHE_PSK_WITH_AES_128_CBC_SHA),
+            0x00B2 => Some(Self::TLS_DHE_PSK_WITH_AES_128_CBC_SHA256),
+            0xC0A6 => Some(Self::TLS_DHE_PSK_WITH_AES_128_CCM),
+            0xC0AA => Some(Self::TLS_PSK_DHE_WITH_AES_128_CCM_8),
+            0x00AA => Some(Self::TLS_DHE_PSK_WITH_AES_128_GCM_SHA256),
+            0x0091 => Some(Self::TLS_DHE_PSK_WITH_AES_256_CBC_SHA),
+            0x00B3 => Some(Self::TLS_DHE_PSK_WITH_AES_256_CBC_SHA384),
+            0xC0A7 => Some(Self::TLS_DHE_PSK_WITH_AES_256_CCM),
+            0xC0AB => Some(Self::TLS_PSK_DHE_WITH_AES_256_CCM_8),
+            0x00AB => Some(Self::TLS_DHE_PSK_WITH_AES_256_GCM_SHA384),
+            0xC06C => Some(Self::TLS_DHE_PSK_WITH_ARIA_128_GCM_SHA256),
+            0xC06D => Some(Self::TLS_DHE_PSK_WITH_ARIA_256_GCM_SHA384),
+            0xC096 => Some(Self::TLS_DHE_PSK_WITH_CAMELLIA_128_CBC_SHA256),
+            0xC097 => Some(Self::TLS_DHE_PSK_WITH_CAMELLIA_256_CBC_SHA384),
+            0xCCAD => Some(Self::TLS_DHE_PSK_WITH_CHACHA20_POLY1305_SHA256),
+            0x002D => Some(Self::TLS_DHE_PSK_WITH_NULL_SHA),
+            0x00B4 => Some(Self::TLS_DHE_PSK_WITH_NULL_SHA256),
+            0x00B5 => Some(Self::TLS_DHE_PSK_WITH_NULL_SHA384),
+            0x008E => Some(Self::TLS_DHE_PSK_WITH_RC4_128_SHA),
+            0xC052 => Some(Self::TLS_DHE_RSA_WITH_ARIA_128_GCM_SHA256),
+            0xC053 => Some(Self::TLS_DHE_RSA_WITH_ARIA_256_GCM_SHA384),
+            0xC060 => Some(Self::TLS_ECDHE_RSA_WITH_ARIA_128_GCM_SHA256),
+            0xC061 => Some(Self::TLS_ECDHE_RSA_WITH_ARIA_256_GCM_SHA384),
+            0xC05C => Some(Self::TLS_ECDHE_ECDSA_WITH_ARIA_128_GCM_SHA256),
+            0xC05D => Some(Self::TLS_ECDHE_ECDSA_WITH_ARIA_256_GCM_SHA384),
+            0xC034 => Some(Self::TLS_ECDHE_PSK_WITH_3DES_EDE_CBC_SHA),
+            0xC035 => Some(Self::TLS_ECDHE_PSK_WITH_AES_128_CBC_SHA),
+            0xC037 => Some(Self::TLS_ECDHE_PSK_WITH_AES_128_CBC_SHA256),
+            0xC036 => Some(Self::TLS_ECDHE_PSK_WITH_AES_256_CBC_SHA),
+            0xC038 => Some(Self::TLS_ECDHE_PSK_WITH_AES_256_CBC_SHA384),
+            0xC09A => Some(Self::TLS_ECDHE_PSK_WITH_CAMELLIA_128_CBC_SHA256),
+            0xC09B => Some(Self::TLS_ECDHE_PSK_WITH_CAMELLIA_256_CBC_SHA384),
+            0xCCAC => Some(Self::TLS_ECDHE_PSK_WITH_CHACHA20_POLY1305_SHA256),
+            0xC039 => Some(Self::TLS_ECDHE_PSK_WITH_NULL_SHA),
+            0xC03A => Some(Self::TLS_ECDHE_PSK_WITH_NULL_SHA256),
+            0xC03B => Some(Self::TLS_ECDHE_PSK_WITH_NULL_SHA384),
+            0xC033 => Some(Self::TLS_ECDHE_PSK_WITH_RC4_128_SHA),
+            0x0083 => Some(Self::TLS_GOSTR341001_WITH_NULL_GOSTR3411),
+            0x0082 => Some(Self::TLS_GOSTR341094_WITH_NULL_GOSTR3411),
+            0x00AE => Some(Self::TLS_PSK_WITH_AES_128_CBC_SHA256),
+            0xC0A4 => Some(Self::TLS_PSK_WITH_AES_128_CCM),
+            0xC0A8 => Some(Self::TLS_PSK_WITH_AES_128_CCM_8),
+            0x00A8 => Some(Self::TLS_PSK_WITH_AES_128_GCM_SHA256),
+            0x00AF => Some(Self::TLS_PSK_WITH_AES_256_CBC_SHA384),
+            0xC0A5 => Some(Self::TLS_PSK_WITH_AES_256_CCM),
+            0xC0A9 => Some(Self::TLS_PSK_WITH_AES_256_CCM_8),
+            0x00A9 => Some(Self::TLS_PSK_WITH_AES_256_GCM_SHA384),
+            0xC06A => Some(Self::TLS_PSK_WITH_ARIA_128_GCM_SHA256),
+            0xC06B => Some(Self::TLS_PSK_WITH_ARIA_256_GCM_SHA384),
+            0xC094 => Some(Self::TLS_PSK_WITH_CAMELLIA_128_CBC_SHA256),
+            0xC095 => Some(Self::TLS_PSK_WITH_CAMELLIA_256_CBC_SHA384),
+            0xCCAB => Some(Self::TLS_PSK_WITH_CHACHA20_POLY1305_SHA256),
+            0x002C => Some(Self::TLS_PSK_WITH_NULL_SHA),
+            0x00B0 => Some(Self::TLS_PSK_WITH_NULL_SHA256),
+            0x00B1 => Some(Self::TLS_PSK_WITH_NULL_SHA384),
+            0x00B6 => Some(Self::TLS_RSA_PSK_WITH_AES_128_CBC_SHA256),
+            0x00AC => Some(Self::TLS_RSA_PSK_WITH_AES_128_GCM_SHA256),
+            0x00B7 => Some(Self::TLS_RSA_PSK_WITH_AES_256_CBC_SHA384),
+            0x00AD => Some(Self::TLS_RSA_PSK_WITH_AES_256_GCM_SHA384),
+            0xC06E => Some(Self::TLS_RSA_PSK_WITH_ARIA_128_GCM_SHA256),
+            0xC06F => Some(Self::TLS_RSA_PSK_WITH_ARIA_256_GCM_SHA384),
+            0xC098 => Some(Self::TLS_RSA_PSK_WITH_CAMELLIA_128_CBC_SHA256),
+            0xC099 => Some(Self::TLS_RSA_PSK_WITH_CAMELLIA_256_CBC_SHA384),
+            0xCCAE => Some(Self::TLS_RSA_PSK_WITH_CHACHA20_POLY1305_SHA256),
+            0x002E => Some(Self::TLS_RSA_PSK_WITH_NULL_SHA),
+            0x00B8 => Some(Self::TLS_RSA_PSK_WITH_NULL_SHA256),
+            0x00B9 => Some(Self::TLS_RSA_PSK_WITH_NULL_SHA384),
+            0x1301 => Some(Self::TLS_AES_128_GCM_SHA256),
+            0x1302 => Some(Self::TLS_AES_256_GCM_SHA384),
+            0x1303 => Some(Self::TLS_CHACHA20_POLY1305_SHA256),
+            0x1304 => Some(Self::TLS_AES_128_CCM_SHA256),
+            0x1305 => Some(Self::TLS_AES_128_CCM_8_SHA256),
+            0xC100 => Some(Self::TLS_GOSTR341112_256_WITH_KUZNYECHIK_CTR_OMAC),
+            0xC101 => Some(Self::TLS_GOSTR341112_256_WITH_MAGMA_CTR_OMAC),
+            0xC102 => Some(Self::TLS_GOSTR341112_256_WITH_28147_CNT_IMIT),
+            0x0000 => Some(Self::TLS_NULL_WITH_NULL_NULL),
+            0x00C6 => Some(Self::TLS_SM4_GCM_SM3),
+            0x00C7 => Some(Self::TLS_SM4_CCM_SM3),
+            0x00FF => Some(Self::TLS_EMPTY_RENEGOTIATION_INFO_SCSV),
+            0x1306 => Some(Self::TLS_AEGIS_256_SHA512),
+            0x1307 => Some(Self::TLS_AEGIS_128L_SHA256),
+            0xC03C => Some(Self::TLS_RSA_WITH_ARIA_128_CBC_SHA256),
+            0xC03D => Some(Self::TLS_RSA_WITH_ARIA_256_CBC_SHA384),
+            0xC03E => Some(Self::TLS_DH_DSS_WITH_ARIA_128_CBC_SHA256),
+            0xC03F => Some(Self::TLS_DH_DSS_WITH_ARIA_256_CBC_SHA384),
+            0xC040 => Some(Self::TLS_DH_RSA_WITH_ARIA_128_CBC_SHA256),
+            0xC041 => Some(Self::TLS_DH_RSA_WITH_ARIA_256_CBC_SHA384),
+            0xC042 => Some(Self::TLS_DHE_DSS_WITH_ARIA_128_CBC_SHA256),
+            0xC043 => Some(Self::TLS_DHE_DSS_WITH_ARIA_256_CBC_SHA384),
+            0xC044 => Some(Self::TLS_DHE_RSA_WITH_ARIA_128_CBC_SHA256),
+            0xC045 => Some(Self::TLS_DHE_RSA_WITH_ARIA_256_CBC_SHA384),
+            0xC046 => Some(Self::TLS_DH_anon_WITH_ARIA_128_CBC_SHA256),
+            0xC047 => Some(Self::TLS_DH_anon_WITH_ARIA_256_CBC_SHA384),
+            0xC048 => Some(Self::TLS_ECDHE_ECDSA_WITH_ARIA_128_CBC_SHA256),
+            0xC049 => Some(Self::TLS_ECDHE_ECDSA_WITH_ARIA_256_CBC_SHA384),
+            0xC04A => Some(Self::TLS_ECDH_ECDSA_WITH_ARIA_128_CBC_SHA256),
+            0xC04B => Some(Self::TLS_ECDH_ECDSA_WITH_ARIA_256_CBC_SHA384),
+            0xC04C => Some(Self::TLS_ECDHE_RSA_WITH_ARIA_128_CBC_SHA256),
+            0xC04D => Some(Self::TLS_ECDHE_RSA_WITH_ARIA_256_CBC_SHA384),
+            0xC04E => Some(Self::TLS_ECDH_RSA_WITH_ARIA_128_CBC_SHA256),
+            0xC04F => Some(Self::TLS_ECDH_RSA_WITH_ARIA_256_CBC_SHA384),
+            0xC054 => Some(Self::TLS_DH_RSA_WITH_ARIA_128_GCM_SHA256),
+            0xC055 => Some(Self::TLS_DH_RSA_WITH_ARIA_256_GCM_SHA384),
+            0xC058 => Some(Self::TLS_DH_DSS_WITH_ARIA_128_GCM_SHA256),
+            0xC059 => Some(Self::TLS_DH_DSS_WITH_ARIA_256_GCM_SHA384),
+            0xC05A => Some(Self::TLS_DH_anon_WITH_ARIA_128_GCM_SHA256),
+            0xC05B => Some(Self::TLS_DH_anon_WITH_ARIA_256_GCM_SHA384),
+            0xC05E => Some(Self::TLS_ECDH_ECDSA_WITH_ARIA_128_GCM_SHA256),
+            0xC05F => Some(Self::TLS_ECDH_ECDSA_WITH_ARIA_256_GCM_SHA384),
+            0xC062 => Some(Self::TLS_ECDH_RSA_WITH_ARIA_128_GCM_SHA256),
+            0xC063 => Some(Self::TLS_ECDH_RSA_WITH_ARIA_256_GCM_SHA384),
+            0xC064 => Some(Self::TLS_PSK_WITH_ARIA_128_CBC_SHA256),
+            0xC065 => Some(Self::TLS_PSK_WITH_ARIA_256_CBC_SHA384),
+            0xC066 => Some(Self::TLS_DHE_PSK_WITH_ARIA_128_CBC_SHA256),
+            0xC067 => Some(Self::TLS_DHE_PSK_WITH_ARIA_256_CBC_SHA384),
+            0xC068 => Some(Self::TLS_RSA_PSK_WITH_ARIA_128_CBC_SHA256),
+            0xC069 => Some(Self::TLS_RSA_PSK_WITH_ARIA_256_CBC_SHA384),
+            0xC070 => Some(Self::TLS_ECDHE_PSK_WITH_ARIA_128_CBC_SHA256),
+            0xC071 => Some(Self::TLS_ECDHE_PSK_WITH_ARIA_256_CBC_SHA384),
+            0xC07A => Some(Self::TLS_RSA_WITH_CAMELLIA_128_GCM_SHA256),
+            0xC07B => Some(Self::TLS_RSA_WITH_CAMELLIA_256_GCM_SHA384),
+            0xC07C => Some(Self::TLS_DHE_RSA_WITH_CAMELLIA_128_GCM_SHA256),
+            0xC07D => Some(Self::TLS_DHE_RSA_WITH_CAMELLIA_256_GCM_SHA384),
+            0xC07E => Some(Self::TLS_DH_RSA_WITH_CAMELLIA_128_GCM_SHA256),
+            0xC07F => Some(Self::TLS_DH_RSA_WITH_CAMELLIA_256_GCM_SHA384),
+            0xC080 => Some(Self::TLS_DHE_DSS_WITH_CAMELLIA_128_GCM_SHA256),
+            0xC081 => Some(Self::TLS_DHE_DSS_WITH_CAMELLIA_256_GCM_SHA384),
+            0xC082 => Some(Self::TLS_DH_DSS_WITH_CAMELLIA_128_GCM_SHA256),
+            0xC083 => Some(Self::TLS_DH_DSS_WITH_CAMELLIA_256_GCM_SHA384),
+            0xC084 => Some(Self::TLS_DH_anon_WITH_CAMELLIA_128_GCM_SHA256),
+            0xC085 => Some(Self::TLS_DH_anon_WITH_CAMELLIA_256_GCM_SHA384),
+            0xC086 => Some(Self::TLS_ECDHE_ECDSA_WITH_CAMELLIA_128_GCM_SHA256),
+            0xC087 => Some(Self::TLS_ECDHE_ECDSA_WITH_CAMELLIA_256_GCM_SHA384),
+            0xC088 => Some(Self::TLS_ECDH_ECDSA_WITH_CAMELLIA_128_GCM_SHA256),
+            0xC089 => Some(Self::TLS_ECDH_ECDSA_WITH_CAMELLIA_256_GCM_SHA384),
+            0xC08A => Some(Self::TLS_ECDHE_RSA_WITH_CAMELLIA_128_GCM_SHA256),
+            0xC08B => Some(Self::TLS_ECDHE_RSA_WITH_CAMELLIA_256_GCM_SHA384),
+            0xC08C => Some(Self::TLS_ECDH_RSA_WITH_CAMELLIA_128_GCM_SHA256),
+            0xC08D => Some(Self::TLS_ECDH_RSA_WITH_CAMELLIA_256_GCM_SHA384),
+            0xC08E => Some(Self::TLS_PSK_WITH_CAMELLIA_128_GCM_SHA256),
+            0xC08F => Some(Self::TLS_PSK_WITH_CAMELLIA_256_GCM_SHA384),
+            0xC090 => Some(Self::TLS_DHE_PSK_WITH_CAMELLIA_128_GCM_SHA256),
+            0xC091 => Some(Self::TLS_DHE_PSK_WITH_CAMELLIA_256_GCM_SHA384),
+            0xC092 => Some(Self::TLS_RSA_PSK_WITH_CAMELLIA_128_GCM_SHA256),
+            0xC093 => Some(Self::TLS_RSA_PSK_WITH_CAMELLIA_256_GCM_SHA384),
+            0xC0B0 => Some(Self::TLS_ECCPWD_WITH_AES_128_GCM_SHA256),
+            0xC0B1 => Some(Self::TLS_ECCPWD_WITH_AES_256_GCM_SHA384),
+            0xC0B2 => Some(Self::TLS_ECCPWD_WITH_AES_128_CCM_SHA256),
+            0xC0B3 => Some(Self::TLS_ECCPWD_WITH_AES_256_CCM_SHA384),
+            0xC0B4 => Some(Self::TLS_SHA256_SHA256),
+            0xC0B5 => Some(Self::TLS_SHA384_SHA384),
+            0xC103 => Some(Self::TLS_GOSTR341112_256_WITH_KUZNYECHIK_MGM_L),
+            0xC104 => Some(Self::TLS_GOSTR341112_256_WITH_MAGMA_MGM_L),
+            0xC105 => Some(Self::TLS_GOSTR341112_256_WITH_KUZNYECHIK_MGM_S),
+            0xC106 => Some(Self::TLS_GOSTR341112_256_WITH_MAGMA_MGM_S),
+            0xD001 => Some(Self::TLS_ECDHE_PSK_WITH_AES_128_GCM_SHA256),
+            0xD002 => Some(Self::TLS_ECDHE_PSK_WITH_AES_256_GCM_SHA384),
+            0xD003 => Some(Self::TLS_ECDHE_PSK_WITH_AES_128_CCM_8_SHA256),
+            0xD005 => Some(Self::TLS_ECDHE_PSK_WITH_AES_128_CCM_SHA256),
+            _ => None,
+        }
+    }
+
+    pub fn as_u16(&self) -> u16 {
+        match self {
+            Self::TLS_RSA_WITH_NULL_MD5 => 0x0001,
+            Self::TLS_RSA_WITH_NULL_SHA => 0x0002,
+            Self::TLS_RSA_EXPORT_WITH_RC4_40_MD5 => 0x0003,
+            Self::TLS_RSA_WITH_RC4_128_MD5 => 0x0004,
+            Self::TLS_RSA_WITH_RC4_128_SHA => 0x0005,
+            Self::TLS_RSA_EXPORT_WITH_RC2_CBC_40_MD5 => 0x0006,
+            Self::TLS_RSA_WITH_IDEA_CBC_SHA => 0x0007,
+            Self::TLS_RSA_EXPORT_WITH_DES40_CBC_SHA => 0x0008,
+            Self::TLS_RSA_WITH_DES_CBC_SHA => 0x0009,
+            Self::TLS_RSA_WITH_3DES_EDE_CBC_SHA => 0x000A,
+            Self::TLS_DH_DSS_EXPORT_WITH_DES40_CBC_SHA => 0x000B,
+            Self::TLS_DH_DSS_WITH_DES_CBC_SHA => 0x000C,
+            Self::TLS_DH_DSS_WITH_3DES_EDE_CBC_SHA => 0x000D,
+            Self::TLS_DH_RSA_EXPORT_WITH_DES40_CBC_SHA => 0x000E,
+            Self::TLS_DH_RSA_WITH_DES_CBC_SHA => 0x000F,
+            Self::TLS_DH_RSA_WITH_3DES_EDE_CBC_SHA => 0x0010,
+            Self::TLS_DHE_DSS_EXPORT_WITH_DES40_CBC_SHA => 0x0011,
+            Self::TLS_DHE_DSS_WITH_DES_CBC_SHA => 0x0012,
+            Self::TLS_DHE_DSS_WITH_3DES_EDE_CBC_SHA => 0x0013,
+            Self::TLS_DHE_RSA_EXPORT_WITH_DES40_CBC_SHA => 0x0014,
+            Self::TLS_DHE_RSA_WITH_DES_CBC_SHA => 0x0015,
+            Self::TLS_DHE_RSA_WITH_3DES_EDE_CBC_SHA => 0x0016,
+            Self::TLS_DH_anon_EXPORT_WITH_RC4_40_MD5 => 0x0017,
+            Self::TLS_DH_anon_WITH_RC4_128_MD5 => 0x0018,
+            Self::TLS_DH_anon_EXPORT_WITH_DES40_CBC_SHA => 0x0019,
+            Self::TLS_DH_anon_WITH_DES_CBC_SHA => 0x001A,
+            Self::TLS_DH_anon_WITH_3DES_EDE_CBC_SHA => 0x001B,
+            Self::TLS_KRB5_WITH_DES_CBC_SHA => 0x001E,
+            Self::TLS_KRB5_WITH_3DES_EDE_CBC_SHA => 0x001F,
+            Self::TLS_KRB5_WITH_RC4_128_SHA => 0x0020,
+            Self::TLS_KRB5_WITH_IDEA_CBC_SHA => 0x0021,
+            Self::TLS_KRB5_WITH_DES_CBC_MD5 => 0x0022,
+            Self::TLS_KRB5_WITH_3DES_EDE_CBC_MD5 => 0x0023,
+            Self::TLS_KRB5_WITH_RC4_128_MD5 => 0x0024,
+            Self::TLS_KRB5_WITH_IDEA_CBC_MD5 => 0x0025,
+            Self::TLS_KRB5_EXPORT_WITH_DES_CBC_40_SHA => 0x0026,
+            Self::TLS_KRB5_EXPORT_WITH_RC2_CBC_40_SHA => 0x0027,
+            Self::TLS_KRB5_EXPORT_WITH_RC4_40_SHA => 0x0028,
+            Self::TLS_KRB5_EXPORT_WITH_DES_CBC_40_MD5 => 0x0029,
+            Self::TLS_KRB5_EXPORT_WITH_RC2_CBC_40_MD5 => 0x002A,
+            Self::TLS_KRB5_EXPORT_WITH_RC4_40_MD5 => 0x002B,
+            Self::TLS_RSA_WITH_AES_128_CBC_SHA => 0x002F,
+            Self::TLS_DH_DSS_WITH_AES_128_CBC_SHA => 0x0030,
+            Self::TLS_DH_RSA_WITH_AES_128_CBC_SHA => 0x0031,
+            Self::TLS_DHE_DSS_WITH_AES_128_CBC_SHA => 0x0032,
+            Self::TLS_DHE_RSA_WITH_AES_128_CBC_SHA => 0x0033,
+            Self::TLS_DH_anon_WITH_AES_128_CBC_SHA => 0x0034,
+            Self::TLS_RSA_WITH_AES_256_CBC_SHA => 0x0035,
+            Self::TLS_DH_DSS_WITH_AES_256_CBC_SHA => 0x0036,
+            Self::TLS_DH_RSA_WITH_AES_256_CBC_SHA => 0x0037,
+            Self::TLS_DHE_DSS_WITH_AES_256_CBC_SHA => 0x0038,
+            Self::TLS_DHE_RSA_WITH_AES_256_CBC_SHA => 0x0039,
+            Self::TLS_DH_anon_WITH_AES_256_CBC_SHA => 0x003A,
+            Self::TLS_RSA_WITH_NULL_SHA256 => 0x003B,
+            Self::TLS_RSA_WITH_AES_128_CBC_SHA256 => 0x003C,
+            Self::TLS_RSA_WITH_AES_256_CBC_SHA256 => 0x003D,
+            Self::TLS_DH_DSS_WITH_AES_128_CBC_SHA256 => 0x003E,
+            Self::TLS_DH_RSA_WITH_AES_128_CBC_SHA256 => 0x003F,
+            Self::TLS_DHE_DSS_WITH_AES_128_CBC_SHA256 => 0x0040,
+            Self::TLS_RSA_WITH_CAMELLIA_128_CBC_SHA => 0x0041,
+            Self::TLS_DH_DSS_WITH_CAMELLIA_128_CBC_SHA => 0x0042,
+            Self::TLS_DH_RSA_WITH_CAMELLIA_128_CBC_SHA => 0x0043,
+            Self::TLS_DHE_DSS_WITH_CAMELLIA_128_CBC_SHA => 0x0044,
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_128_CBC_SHA => 0x0045,
+            Self::TLS_DH_anon_WITH_CAMELLIA_128_CBC_SHA => 0x0046,
+            Self::TLS_RSA_EXPORT1024_WITH_DES_CBC_SHA => 0x0062,
+            Self::TLS_DHE_DSS_EXPORT1024_WITH_DES_CBC_SHA => 0x0063,
+            Self::TLS_RSA_EXPORT1024_WITH_RC4_56_SHA => 0x0064,
+            Self::TLS_RSA_EXPORT1024_WITH_RC4_56_MD5 => 0x0060,
+            Self::TLS_RSA_EXPORT1024_WITH_RC2_CBC_56_MD5 => 0x0061,
+            Self::TLS_DHE_DSS_EXPORT1024_WITH_RC4_56_SHA => 0x0065,
+            Self::TLS_DHE_DSS_WITH_RC4_128_SHA => 0x0066,
+            Self::TLS_DHE_RSA_WITH_AES_128_CBC_SHA256 => 0x0067,
+            Self::TLS_DH_DSS_WITH_AES_256_CBC_SHA256 => 0x0068,
+            Self::TLS_DH_RSA_WITH_AES_256_CBC_SHA256 => 0x0069,
+            Self::TLS_DHE_DSS_WITH_AES_256_CBC_SHA256 => 0x006A,
+            Self::TLS_DHE_RSA_WITH_AES_256_CBC_SHA256 => 0x006B,
+            Self::TLS_DH_anon_WITH_AES_128_CBC_SHA256 => 0x006C,
+            Self::TLS_DH_anon_WITH_AES_256_CBC_SHA256 => 0x006D,
+            Self::TLS_GOSTR341094_WITH_28147_CNT_IMIT => 0x0080,
+            Self::TLS_GOSTR341001_WITH_28147_CNT_IMIT => 0x0081,
+            Self::TLS_RSA_WITH_CAMELLIA_256_CBC_SHA => 0x0084,
+            Self::TLS_DH_DSS_WITH_CAMELLIA_256_CBC_SHA => 0x0085,
+            Self::TLS_DH_RSA_WITH_CAMELLIA_256_CBC_SHA => 0x0086,
+            Self::TLS_DHE_DSS_WITH_CAMELLIA_256_CBC_SHA => 0x0087,
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_256_CBC_SHA => 0x0088,
+            Self::TLS_DH_anon_WITH_CAMELLIA_256_CBC_SHA => 0x0089,
+            Self::TLS_PSK_WITH_RC4_128_SHA => 0x008A,
+            Self::TLS_PSK_WITH_3DES_EDE_CBC_SHA => 0x008B,
+            Self::TLS_PSK_WITH_AES_128_CBC_SHA => 0x008C,
+            Self::TLS_PSK_WITH_AES_256_CBC_SHA => 0x008D,
+            Self::TLS_RSA_PSK_WITH_RC4_128_SHA => 0x0092,
+            Self::TLS_RSA_PSK_WITH_3DES_EDE_CBC_SHA => 0x0093,
+            Self::TLS_RSA_PSK_WITH_AES_128_CBC_SHA => 0x0094,
+            Self::TLS_RSA_PSK_WITH_AES_256_CBC_SHA => 0x0095,
+            Self::TLS_RSA_WITH_SEED_CBC_SHA => 0x0096,
+            Self::TLS_DH_DSS_WITH_SEED_CBC_SHA => 0x0097,
+            Self::TLS_DH_RSA_WITH_SEED_CBC_SHA => 0x0098,
+            Self::TLS_DHE_DSS_WITH_SEED_CBC_SHA => 0x0099,
+            Self::TLS_DHE_RSA_WITH_SEED_CBC_SHA => 0x009A,
+            Self::TLS_DH_anon_WITH_SEED_CBC_SHA => 0x009B,
+            Self::TLS_RSA_WITH_AES_128_GCM_SHA256 => 0x009C,
+            Self::TLS_RSA_WITH_AES_256_GCM_SHA384 => 0x009D,
+            Self::TLS_DHE_RSA_WITH_AES_128_GCM_SHA256 => 0x009E,
+            Self::TLS_DHE_RSA_WITH_AES_256_GCM_SHA384 => 0x009F,
+            Self::TLS_DH_RSA_WITH_AES_128_GCM_SHA256 => 0x00A0,
+            Self::TLS_DH_RSA_WITH_AES_256_GCM_SHA384 => 0x00A1,
+            Self::TLS_DHE_DSS_WITH_AES_128_GCM_SHA256 => 0x00A2,
+            Self::TLS_DHE_DSS_WITH_AES_256_GCM_SHA384 => 0x00A3,
+            Self::TLS_DH_DSS_WITH_AES_128_GCM_SHA256 => 0x00A4,
+            Self::TLS_DH_DSS_WITH_AES_256_GCM_SHA384 => 0x00A5,
+            Self::TLS_DH_anon_WITH_AES_128_GCM_SHA256 => 0x00A6,
+            Self::TLS_DH_anon_WITH_AES_256_GCM_SHA384 => 0x00A7,
+            Self::TLS_RSA_WITH_CAMELLIA_128_CBC_SHA256 => 0x00BA,
+            Self::TLS_DH_DSS_WITH_CAMELLIA_128_CBC_SHA256 => 0x00BB,
+            Self::TLS_DH_RSA_WITH_CAMELLIA_128_CBC_SHA256 => 0x00BC,
+            Self::TLS_DHE_DSS_WITH_CAMELLIA_128_CBC_SHA256 => 0x00BD,
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_128_CBC_SHA256 => 0x00BE,
+            Self::TLS_DH_anon_WITH_CAMELLIA_128_CBC_SHA256 => 0x00BF,
+            Self::TLS_RSA_WITH_CAMELLIA_256_CBC_SHA256 => 0x00C0,
+            Self::TLS_DH_DSS_WITH_CAMELLIA_256_CBC_SHA256 => 0x00C1,
+            Self::TLS_DH_RSA_WITH_CAMELLIA_256_CBC_SHA256 => 0x00C2,
+            Self::TLS_DHE_DSS_WITH_CAMELLIA_256_CBC_SHA256 => 0x00C3,
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_256_CBC_SHA256 => 0x00C4,
+            Self::TLS_DH_anon_WITH_CAMELLIA_256_CBC_SHA256 => 0x00C5,
+            Self::TLS_FALLBACK_SCSV => 0x5600,
+            Self::TLS_ECDH_ECDSA_WITH_NULL_SHA => 0xC001,
+            Self::TLS_ECDH_ECDSA_WITH_RC4_128_SHA => 0xC002,
+            Self::TLS_ECDH_ECDSA_WITH_3DES_EDE_CBC_SHA => 0xC003,
+            Self::TLS_ECDH_ECDSA_WITH_AES_128_CBC_SHA => 0xC004,
+            Self::TLS_ECDH_ECDSA_WITH_AES_256_CBC_SHA => 0xC005,
+            Self::TLS_ECDHE_ECDSA_WITH_NULL_SHA => 0xC006,
+            Self::TLS_ECDHE_ECDSA_WITH_RC4_128_SHA => 0xC007,
+            Self::TLS_ECDHE_ECDSA_WITH_3DES_EDE_CBC_SHA => 0xC008,
+            Self::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA => 0xC009,
+            Self::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA => 0xC00A,
+            Self::TLS_ECDH_RSA_WITH_NULL_SHA => 0xC00B,
+            Self::TLS_ECDH_RSA_WITH_RC4_128_SHA => 0xC00C,
+            Self::TLS_ECDH_RSA_WITH_3DES_EDE_CBC_SHA => 0xC00D,
+            Self::TLS_ECDH_RSA_WITH_AES_128_CBC_SHA => 0xC00E,
+            Self::TLS_ECDH_RSA_WITH_AES_256_CBC_SHA => 0xC00F,
+            Self::TLS_ECDHE_RSA_WITH_NULL_SHA => 0xC010,
+            Self::TLS_ECDHE_RSA_WITH_RC4_128_SHA => 0xC011,
+            Self::TLS_ECDHE_RSA_WITH_3DES_EDE_CBC_SHA => 0xC012,
+            Self::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA => 0xC013,
+            Self::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA => 0xC014,
+            Self::TLS_ECDH_anon_WITH_NULL_SHA => 0xC015,
+            Self::TLS_ECDH_anon_WITH_RC4_128_SHA => 0xC016,
+            Self::TLS_ECDH_anon_WITH_3DES_EDE_CBC_SHA => 0xC017,
+            Self::TLS_ECDH_anon_WITH_AES_128_CBC_SHA => 0xC018,
+            Self::TLS_ECDH_anon_WITH_AES_256_CBC_SHA => 0xC019,
+            Self::TLS_SRP_SHA_WITH_3DES_EDE_CBC_SHA => 0xC01A,
+            Self::TLS_SRP_SHA_RSA_WITH_3DES_EDE_CBC_SHA => 0xC01B,
+            Self::TLS_SRP_SHA_DSS_WITH_3DES_EDE_CBC_SHA => 0xC01C,
+            Self::TLS_SRP_SHA_WITH_AES_128_CBC_SHA => 0xC01D,
+            Self::TLS_SRP_SHA_RSA_WITH_AES_128_CBC_SHA => 0xC01E,
+            Self::TLS_SRP_SHA_DSS_WITH_AES_128_CBC_SHA => 0xC01F,
+            Self::TLS_SRP_SHA_WITH_AES_256_CBC_SHA => 0xC020,
+            Self::TLS_SRP_SHA_RSA_WITH_AES_256_CBC_SHA => 0xC021,
+            Self::TLS_SRP_SHA_DSS_WITH_AES_256_CBC_SHA => 0xC022,
+            Self::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA256 => 0xC023,
+            Self::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA384 => 0xC024,
+            Self::TLS_ECDH_ECDSA_WITH_AES_128_CBC_SHA256 => 0xC025,
+            Self::TLS_ECDH_ECDSA_WITH_AES_256_CBC_SHA384 => 0xC026,
+            Self::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA256 => 0xC027,
+            Self::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA384 => 0xC028,
+            Self::TLS_ECDH_RSA_WITH_AES_128_CBC_SHA256 => 0xC029,
+            Self::TLS_ECDH_RSA_WITH_AES_256_CBC_SHA384 => 0xC02A,
+            Self::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256 => 0xC02B,
+            Self::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384 => 0xC02C,
+            Self::TLS_ECDH_ECDSA_WITH_AES_128_GCM_SHA256 => 0xC02D,
+            Self::TLS_ECDH_ECDSA_WITH_AES_256_GCM_SHA384 => 0xC02E,
+            Self::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256 => 0xC02F,
+            Self::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384 => 0xC030,
+            Self::TLS_ECDH_RSA_WITH_AES_128_GCM_SHA256 => 0xC031,
+            Self::TLS_ECDH_RSA_WITH_AES_256_GCM_SHA384 => 0xC032,
+            Self::TLS_ECDHE_ECDSA_WITH_CAMELLIA_128_CBC_SHA256 => 0xC072,
+            Self::TLS_ECDHE_ECDSA_WITH_CAMELLIA_256_CBC_SHA384 => 0xC073,
+            Self::TLS_ECDH_ECDSA_WITH_CAMELLIA_128_CBC_SHA256 => 0xC074,
+            Self::TLS_ECDH_ECDSA_WITH_CAMELLIA_256_CBC_SHA384 => 0xC075,
+            Self::TLS_ECDHE_RSA_WITH_CAMELLIA_128_CBC_SHA256 => 0xC076,
+            Self::TLS_ECDHE_RSA_WITH_CAMELLIA_256_CBC_SHA384 => 0xC077,
+            Self::TLS_ECDH_RSA_WITH_CAMELLIA_128_CBC_SHA256 => 0xC078,
+            Self::TLS_ECDH_RSA_WITH_CAMELLIA_256_CBC_SHA384 => 0xC079,
+            Self::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256 => 0xCCA8,
+            Self::TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256 => 0xCCA9,
+            Self::TLS_DHE_RSA_WITH_CHACHA20_POLY1305_SHA256 => 0xCCAA,
+            Self::OLD_TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256 => 0xCC13,
+            Self::OLD_TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256 => 0xCC14,
+            Self::OLD_TLS_DHE_RSA_WITH_CHACHA20_POLY1305_SHA256 => 0xCC15,
+            Self::TLS_RSA_WITH_AES_128_CCM => 0xC09C,
+            Self::TLS_RSA_WITH_AES_256_CCM => 0xC09D,
+            Self::TLS_DHE_RSA_WITH_AES_128_CCM => 0xC09E,
+            Self::TLS_DHE_RSA_WITH_AES_256_CCM => 0xC09F,
+            Self::TLS_RSA_WITH_AES_128_CCM_8 => 0xC0A0,
+            Self::TLS_RSA_WITH_AES_256_CCM_8 => 0xC0A1,
+            Self::TLS_DHE_RSA_WITH_AES_128_CCM_8 => 0xC0A2,
+            Self::TLS_DHE_RSA_WITH_AES_256_CCM_8 => 0xC0A3,
+            Self::TLS_ECDHE_ECDSA_WITH_AES_128_CCM => 0xC0AC,
+            Self::TLS_ECDHE_ECDSA_WITH_AES_256_CCM => 0xC0AD,
+            Self::TLS_ECDHE_ECDSA_WITH_AES_128_CCM_8 => 0xC0AE,
+            Self::TLS_ECDHE_ECDSA_WITH_AES_256_CCM_8 => 0xC0AF,
+            Self::TLS_RSA_WITH_ARIA_128_GCM_SHA256 => 0xC050,
+            Self::TLS_RSA_WITH_ARIA_256_GCM_SHA384 => 0xC051,
+            Self::TLS_DHE_DSS_WITH_ARIA_128_GCM_SHA256 => 0xC056,
+            Self::TLS_DHE_DSS_WITH_ARIA_256_GCM_SHA384 => 0xC057,
+            Self::TLS_DHE_PSK_WITH_3DES_EDE_CBC_SHA => 0x008F,
+            Self::TLS_DHE_PSK_WITH_AES_128_CBC_SHA => 0x0090,
+            Self::TLS_DHE_PSK_WITH_AES_128_CBC_SHA256 => 0x00B2,
+            Self::TLS_DHE_PSK_WITH_AES_128_CCM => 0xC0A6,
+            Self::TLS_PSK_DHE_WITH_AES_128_CCM_8 => 0xC0AA,
+            Self::TLS_DHE_PSK_WITH_AES_128_GCM_SHA256 => 0x00AA,
+            Self::TLS_DHE_PSK_WITH_AES_256_CBC_SHA => 0x0091,
+            Self::TLS_DHE_PSK_WITH_AES_256_CBC_SHA384 => 0x00B3,
+            Self::TLS_DHE_PSK_WITH_AES_256_CCM => 0xC0A7,
+            Self::TLS_PSK_DHE_WITH_AES_256_CCM_8 => 0xC0AB,
+            Self::TLS_DHE_PSK_WITH_AES_256_GCM_SHA384 => 0x00AB,
+            Self::TLS_DHE_PSK_WITH_ARIA_128_GCM_SHA256 => 0xC06C,
+            Self::TLS_DHE_PSK_WITH_ARIA_256_GCM_SHA384 => 0xC06D,
+            Self::TLS_DHE_PSK_WITH_CAMELLIA_128_CBC_SHA256 => 0xC096,
+            Self::TLS_DHE_PSK_WITH_CAMELLIA_256_CBC_SHA384 => 0xC097,
+            Self::TLS_DHE_PSK_WITH_CHACHA20_POLY1305_SHA256 => 0xCCAD,
+            Self::TLS_DHE_PSK_WITH_NULL_SHA => 0x002D,
+            Self::TLS_DHE_PSK_WITH_NULL_SHA256 => 0x00B4,
+            Self::TLS_DHE_PSK_WITH_NULL_SHA384 => 0x00B5,
+            Self::TLS_DHE_PSK_WITH_RC4_128_SHA => 0x008E,
+            Self::TLS_DHE_RSA_WITH_ARIA_128_GCM_SHA256 => 0xC052,
+            Self::TLS_DHE_RSA_WITH_ARIA_256_GCM_SHA384 => 0xC053,
+            Self::TLS_ECDHE_RSA_WITH_ARIA_128_GCM_SHA256 => 0xC060,
+            Self::TLS_ECDHE_RSA_WITH_ARIA_256_GCM_SHA384 => 0xC061,
+            Self::TLS_ECDHE_ECDSA_WITH_ARIA_128_GCM_SHA256 => 0xC05C,
+            Self::TLS_ECDHE_ECDSA_WITH_ARIA_256_GCM_SHA384 => 0xC05D,
+            Self::TLS_ECDHE_PSK_WITH_3DES_EDE_CBC_SHA => 0xC034,
+            Self::TLS_ECDHE_PSK_WITH_AES_128_CBC_SHA => 0xC035,
+            Self::TLS_ECDHE_PSK_WITH_AES_128_CBC_SHA256 => 0xC037,
+            Self::TLS_ECDHE_PSK_WITH_AES_256_CBC_SHA => 0xC036,
+            Self::TLS_ECDHE_PSK_WITH_AES_256_CBC_SHA384 => 0xC038,
+            Self::TLS_ECDHE_PSK_WITH_CAMELLIA_128_CBC_SHA256 => 0xC09A,
+            Self::TLS_ECDHE_PSK_WITH_CAMELLIA_256_CBC_SHA384 => 0xC09B,
+            Self::TLS_ECDHE_PSK_WITH_CHACHA20_POLY1305_SHA256 => 0xCCAC,
+            Self::TLS_ECDHE_PSK_WITH_NULL_SHA => 0xC039,
+            Self::TLS_ECDHE_PSK_WITH_NULL_SHA256 => 0xC03A,
+            Self::TLS_ECDHE_PSK_WITH_NULL_SHA384 => 0xC03B,
+            Self::TLS_ECDHE_PSK_WITH_RC4_128_SHA => 0xC033,
+            Self::TLS_GOSTR341001_WITH_NULL_GOSTR3411 => 0x0083,
+            Self::TLS_GOSTR341094_WITH_NULL_GOSTR3411 => 0x0082,
+            Self::TLS_PSK_WITH_AES_128_CBC_SHA256 => 0x00AE,
+            Self::TLS_PSK_WITH_AES_128_CCM => 0xC0A4,
+            Self::TLS_PSK_WITH_AES_128_CCM_8 => 0xC0A8,
+            Self::TLS_PSK_WITH_AES_128_GCM_SHA256 => 0x00A8,
+            Self::TLS_PSK_WITH_AES_256_CBC_SHA384 => 0x00AF,
+            Self::TLS_PSK_WITH_AES_256_CCM => 0xC0A5,
+            Self::TLS_PSK_WITH_AES_256_CCM_8 => 0xC0A9,
+            Self::TLS_PSK_WITH_AES_256_GCM_SHA384 => 0x00A9,
+            Self::TLS_PSK_WITH_ARIA_128_GCM_SHA256 => 0xC06A,
+            Self::TLS_PSK_WITH_ARIA_256_GCM_SHA384 => 0xC06B,
+            Self::TLS_PSK_WITH_CAMELLIA_128_CBC_SHA256 => 0xC094,
+            Self::TLS_PSK_WITH_CAMELLIA_256_CBC_SHA384 => 0xC095,
+            Self::TLS_PSK_WITH_CHACHA20_POLY1305_SHA256 => 0xCCAB,
+            Self::TLS_PSK_WITH_NULL_SHA => 0x002C,
+            Self::TLS_PSK_WITH_NULL_SHA256 => 0x00B0,
+            Self::TLS_PSK_WITH_NULL_SHA384 => 0x00B1,
+            Self::TLS_RSA_PSK_WITH_AES_128_CBC_SHA256 => 0x00B6,
+            Self::TLS_RSA_PSK_WITH_AES_128_GCM_SHA256 => 0x00AC,
+            Self::TLS_RSA_PSK_WITH_AES_256_CBC_SHA384 => 0x00B7,
+            Self::TLS_RSA_PSK_WITH_AES_256_GCM_SHA384 => 0x00AD,
+            Self::TLS_RSA_PSK_WITH_ARIA_128_GCM_SHA256 => 0xC06E,
+            Self::TLS_RSA_PSK_WITH_ARIA_256_GCM_SHA384 => 0xC06F,
+            Self::TLS_RSA_PSK_WITH_CAMELLIA_128_CBC_SHA256 => 0xC098,
+            Self::TLS_RSA_PSK_WITH_CAMELLIA_256_CBC_SHA384 => 0xC099,
+            Self::TLS_RSA_PSK_WITH_CHACHA20_POLY1305_SHA256 => 0xCCAE,
+            Self::TLS_RSA_PSK_WITH_NULL_SHA => 0x002E,
+            Self::TLS_RSA_PSK_WITH_NULL_SHA256 => 0x00B8,
+            Self::TLS_RSA_PSK_WITH_NULL_SHA384 => 0x00B9,
+            Self::TLS_AES_128_GCM_SHA256 => 0x1301,
+            Self::TLS_AES_256_GCM_SHA384 => 0x1302,
+            Self::TLS_CHACHA20_POLY1305_SHA256 => 0x1303,
+            Self::TLS_AES_128_CCM_SHA256 => 0x1304,
+            Self::TLS_AES_128_CCM_8_SHA256 => 0x1305,
+            Self::TLS_GOSTR341112_256_WITH_KUZNYECHIK_CTR_OMAC => 0xC100,
+            Self::TLS_GOSTR341112_256_WITH_MAGMA_CTR_OMAC => 0xC101,
+            Self::TLS_GOSTR341112_256_WITH_28147_CNT_IMIT => 0xC102,
+            Self::TLS_NULL_WITH_NULL_NULL => 0x0000,
+            Self::TLS_SM4_GCM_SM3 => 0x00C6,
+            Self::TLS_SM4_CCM_SM3 => 0x00C7,
+            Self::TLS_EMPTY_RENEGOTIATION_INFO_SCSV => 0x00FF,
+            Self::TLS_AEGIS_256_SHA512 => 0x1306,
+            Self::TLS_AEGIS_128L_SHA256 => 0x1307,
+            Self::TLS_RSA_WITH_ARIA_128_CBC_SHA256 => 0xC03C,
+            Self::TLS_RSA_WITH_ARIA_256_CBC_SHA384 => 0xC03D,
+            Self::TLS_DH_DSS_WITH_ARIA_128_CBC_SHA256 => 0xC03E,
+            Self::TLS_DH_DSS_WITH_ARIA_256_CBC_SHA384 => 0xC03F,
+            Self::TLS_DH_RSA_WITH_ARIA_128_CBC_SHA256 => 0xC040,
+            Self::TLS_DH_RSA_WITH_ARIA_256_CBC_SHA384 => 0xC041,
+            Self::TLS_DHE_DSS_WITH_ARIA_128_CBC_SHA256 => 0xC042,
+            Self::TLS_DHE_DSS_WITH_ARIA_256_CBC_SHA384 => 0xC043,
+            Self::TLS_DHE_RSA_WITH_ARIA_128_CBC_SHA256 => 0xC044,
+            Self::TLS_DHE_RSA_WITH_ARIA_256_CBC_SHA384 => 0xC045,
+            Self::TLS_DH_anon_WITH_ARIA_128_CBC_SHA256 => 0xC046,
+            Self::TLS_DH_anon_WITH_ARIA_256_CBC_SHA384 => 0xC047,
+            Self::TLS_ECDHE_ECDSA_WITH_ARIA_128_CBC_SHA256 => 0xC048,
+            Self::TLS_ECDHE_ECDSA_WITH_ARIA_256_CBC_SHA384 => 0xC049,
+            Self::TLS_ECDH_ECDSA_WITH_ARIA_128_CBC_SHA256 => 0xC04A,
+            Self::TLS_ECDH_ECDSA_WITH_ARIA_256_CBC_SHA384 => 0xC04B,
+            Self::TLS_ECDHE_RSA_WITH_ARIA_128_CBC_SHA256 => 0xC04C,
+            Self::TLS_ECDHE_RSA_WITH_ARIA_256_CBC_SHA384 => 0xC04D,
+            Self::TLS_ECDH_RSA_WITH_ARIA_128_CBC_SHA256 => 0xC04E,
+            Self::TLS_ECDH_RSA_WITH_ARIA_256_CBC_SHA384 => 0xC04F,
+            Self::TLS_DH_RSA_WITH_ARIA_128_GCM_SHA256 => 0xC054,
+            Self::TLS_DH_RSA_WITH_ARIA_256_GCM_SHA384 => 0xC055,
+            Self::TLS_DH_DSS_WITH_ARIA_128_GCM_SHA256 => 0xC058,
+            Self::TLS_DH_DSS_WITH_ARIA_256_GCM_SHA384 => 0xC059,
+            Self::TLS_DH_anon_WITH_ARIA_128_GCM_SHA256 => 0xC05A,
+            Self::TLS_DH_anon_WITH_ARIA_256_GCM_SHA384 => 0xC05B,
+            Self::TLS_ECDH_ECDSA_WITH_ARIA_128_GCM_SHA256 => 0xC05E,
+            Self::TLS_ECDH_ECDSA_WITH_ARIA_256_GCM_SHA384 => 0xC05F,
+            Self::TLS_ECDH_RSA_WITH_ARIA_128_GCM_SHA256 => 0xC062,
+            Self::TLS_ECDH_RSA_WITH_ARIA_256_GCM_SHA384 => 0xC063,
+            Self::TLS_PSK_WITH_ARIA_128_CBC_SHA256 => 0xC064,
+            Self::TLS_PSK_WITH_ARIA_256_CBC_SHA384 => 0xC065,
+            Self::TLS_DHE_PSK_WITH_ARIA_128_CBC_SHA256 => 0xC066,
+            Self::TLS_DHE_PSK_WITH_ARIA_256_CBC_SHA384 => 0xC067,
+            Self::TLS_RSA_PSK_WITH_ARIA_128_CBC_SHA256 => 0xC068,
+            Self::TLS_RSA_PSK_WITH_ARIA_256_CBC_SHA384 => 0xC069,
+            Self::TLS_ECDHE_PSK_WITH_ARIA_128_CBC_SHA256 => 0xC070,
+            Self::TLS_ECDHE_PSK_WITH_ARIA_256_CBC_SHA384 => 0xC071,
+            Self::TLS_RSA_WITH_CAMELLIA_128_GCM_SHA256 => 0xC07A,
+            Self::TLS_RSA_WITH_CAMELLIA_256_GCM_SHA384 => 0xC07B,
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_128_GCM_SHA256 => 0xC07C,
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_256_GCM_SHA384 => 0xC07D,
+            Self::TLS_DH_RSA_WITH_CAMELLIA_128_GCM_SHA256 => 0xC07E,
+            Self::TLS_DH_RSA_WITH_CAMELLIA_256_GCM_SHA384 => 0xC07F,
+            Self::TLS_DHE_DSS_WITH_CAMELLIA_128_GCM_SHA256 => 0xC080,
+            Self::TLS_DHE_DSS_WITH_CAMELLIA_256_GCM_SHA384 => 0xC081,
+            Self::TLS_DH_DSS_WITH_CAMELLIA_128_GCM_SHA256 => 0xC082,
+            Self::TLS_DH_DSS_WITH_CAMELLIA_256_GCM_SHA384 => 0xC083,
+            Self::TLS_DH_anon_WITH_CAMELLIA_128_GCM_SHA256 => 0xC084,
+            Self::TLS_DH_anon_WITH_CAMELLIA_256_GCM_SHA384 => 0xC085,
+            Self::TLS_ECDHE_ECDSA_WITH_CAMELLIA_128_GCM_SHA256 => 0xC086,
+            Self::TLS_ECDHE_ECDSA_WITH_CAMELLIA_256_GCM_SHA384 => 0xC087,
+            Self::TLS_ECDH_ECDSA_WITH_CAMELLIA_128_GCM_SHA256 => 0xC088,
+            Self::TLS_ECDH_ECDSA_WITH_CAMELLIA_256_GCM_SHA384 => 0xC089,
+            Self::TLS_ECDHE_RSA_WITH_CAMELLIA_128_GCM_SHA256 => 0xC08A,
+            Self::TLS_ECDHE_RSA_WITH_CAMELLIA_256_GCM_SHA384 => 0xC08B,
+            Self::TLS_ECDH_RSA_WITH_CAMELLIA_128_GCM_SHA256 => 0xC08C,
+            Self::TLS_ECDH_RSA_WITH_CAMELLIA_256_GCM_SHA384 => 0xC08D,
+            Self::TLS_PSK_WITH_CAMELLIA_128_GCM_SHA256 => 0xC08E,
+            Self::TLS_PSK_WITH_CAMELLIA_256_GCM_SHA384 => 0xC08F,
+            Self::TLS_DHE_PSK_WITH_CAMELLIA_128_GCM_SHA256 => 0xC090,
+            Self::TLS_DHE_PSK_WITH_CAMELLIA_256_GCM_SHA384 => 0xC091,
+            Self::TLS_RSA_PSK_WITH_CAMELLIA_128_GCM_SHA256 => 0xC092,
+            Self::TLS_RSA_PSK_WITH_CAMELLIA_256_GCM_SHA384 => 0xC093,
+            Self::TLS_ECCPWD_WITH_AES_128_GCM_SHA256 => 0xC0B0,
+            Self::TLS_ECCPWD_WITH_AES_256_GCM_SHA384 => 0xC0B1,
+            Self::TLS_ECCPWD_WITH_AES_128_CCM_SHA256 => 0xC0B2,
+            Self::TLS_ECCPWD_WITH_AES_256_CCM_SHA384 => 0xC0B3,
+            Self::TLS_SHA256_SHA256 => 0xC0B4,
+            Self::TLS_SHA384_SHA384 => 0xC0B5,
+            Self::TLS_GOSTR341112_256_WITH_KUZNYECHIK_MGM_L => 0xC103,
+            Self::TLS_GOSTR341112_256_WITH_MAGMA_MGM_L => 0xC104,
+            Self::TLS_GOSTR341112_256_WITH_KUZNYECHIK_MGM_S => 0xC105,
+            Self::TLS_GOSTR341112_256_WITH_MAGMA_MGM_S => 0xC106,
+            Self::TLS_ECDHE_PSK_WITH_AES_128_GCM_SHA256 => 0xD001,
+            Self::TLS_ECDHE_PSK_WITH_AES_256_GCM_SHA384 => 0xD002,
+            Self::TLS_ECDHE_PSK_WITH_AES_128_CCM_8_SHA256 => 0xD003,
+            Self::TLS_ECDHE_PSK_WITH_AES_128_CCM_SHA256 => 0xD005,
+        }
     }
 }
@@ -0,0 +1,77 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An SSL/TLS protocol version.
+///
+/// Versions are ordered, so `ProtocolVersion::Tls12 > ProtocolVersion::Tls10`.
+/// `Display` uses OpenSSL's spelling, e.g. `TLSv1.2`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ProtocolVersion {
+    Ssl2,
+    Ssl3,
+    Tls10,
+    Tls11,
+    Tls12,
+    Tls13,
+}
+
+impl ProtocolVersion {
+    pub fn from_u16(value: u16) -> Option<ProtocolVersion> {
+        match value {
+            0x0002 => Some(Self::Ssl2),
+            0x0300 => Some(Self::Ssl3),
+            0x0301 => Some(Self::Tls10),
+            0x0302 => Some(Self::Tls11),
+            0x0303 => Some(Self::Tls12),
+            0x0304 => Some(Self::Tls13),
+            _ => None,
+        }
+    }
+
+    pub fn as_u16(&self) -> u16 {
+        match self {
+            Self::Ssl2 => 0x0002,
+            Self::Ssl3 => 0x0300,
+            Self::Tls10 => 0x0301,
+            Self::Tls11 => 0x0302,
+            Self::Tls12 => 0x0303,
+            Self::Tls13 => 0x0304,
+        }
+    }
+}
+
+impl fmt::Display for ProtocolVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Ssl2 => "SSLv2",
+            Self::Ssl3 => "SSLv3",
+            Self::Tls10 => "TLSv1",
+            Self::Tls11 => "TLSv1.1",
+            Self::Tls12 => "TLSv1.2",
+            Self::Tls13 => "TLSv1.3",
+        };
+        f.pad(s)
+    }
+}
+
+impl FromStr for ProtocolVersion {
+    type Err = ();
+
+    /// Parses OpenSSL's spelling of a protocol version. `TLSv1.0` is accepted
+    /// as a synonym for `TLSv1`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "SSLv2" => Ok(Self::Ssl2),
+            "SSLv3" => Ok(Self::Ssl3),
+            "TLSv1" | "TLSv1.0" => Ok(Self::Tls10),
+            "TLSv1.1" => Ok(Self::Tls11),
+            "TLSv1.2" => Ok(Self::Tls12),
+            "TLSv1.3" => Ok(Self::Tls13),
+            _ => Err(()),
+        }
+    }
+}
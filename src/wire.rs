@@ -0,0 +1,319 @@
+use std::fmt;
+
+use crate::{CipherSuiteId, SSLV2CipherSuite};
+
+/// Error returned when encoding or decoding TLS wire data fails.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum WireError {
+    /// The input ended before the announced length.
+    Truncated { needed: usize, available: usize },
+    /// The list length is not a multiple of the entry size, 2 bytes for TLS
+    /// and 3 bytes for SSLv2.
+    OddLength { length: usize, entry: usize },
+    /// Bytes were left over after the data.
+    TrailingData(usize),
+    /// The list does not fit in its 16-bit length field.
+    TooLong(usize),
+    /// The list is empty, which the lower bound of the TLS
+    /// `cipher_suites<2..2^16-2>` vector forbids.
+    Empty,
+    /// An SSLv2 cipher spec does not fit in its 3 bytes.
+    SpecOutOfRange(u32),
+    /// A record or handshake message was not of the expected type.
+    UnexpectedType(u8),
+}
+
+impl fmt::Display for WireError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated { needed, available } => write!(
+                f,
+                "truncated input: need {} bytes, have {}",
+                needed, available
+            ),
+            Self::OddLength { length, entry } => {
+                write!(f, "list length {} is not a multiple of {}", length, entry)
+            }
+            Self::TrailingData(n) => {
+                write!(f, "{} trailing bytes", n)
+            }
+            Self::TooLong(n) => write!(f, "list of {} bytes is too long", n),
+            Self::Empty => write!(f, "empty cipher suite list"),
+            Self::SpecOutOfRange(value) => {
+                write!(f, "cipher spec {:#X} does not fit in 3 bytes", value)
+            }
+            Self::UnexpectedType(t) => write!(f, "unexpected message type {}", t),
+        }
+    }
+}
+
+impl std::error::Error for WireError {}
+
+/// An entry of an SSLv2 `cipher_specs` list.
+///
+/// SSLv2-compatible ClientHellos carry TLS suites alongside SSLv2 ones by
+/// encoding them as `0x00` followed by the TLS code point.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum CipherSpec {
+    Ssl2(SSLV2CipherSuite),
+    Tls(CipherSuiteId),
+    Unknown(u32),
+}
+
+impl CipherSpec {
+    pub fn from_u32(value: u32) -> CipherSpec {
+        if let Some(cipher) = SSLV2CipherSuite::from_u32(value) {
+            return CipherSpec::Ssl2(cipher);
+        }
+        match value {
+            0x000000..=0x00FFFF => CipherSpec::Tls(CipherSuiteId(value as u16)),
+            _ => CipherSpec::Unknown(value),
+        }
+    }
+
+    pub fn as_u32(&self) -> u32 {
+        match self {
+            Self::Ssl2(cipher) => cipher.as_u32(),
+            Self::Tls(id) => u32::from(id.0),
+            Self::Unknown(value) => *value,
+        }
+    }
+}
+
+impl From<SSLV2CipherSuite> for CipherSpec {
+    fn from(cipher: SSLV2CipherSuite) -> CipherSpec {
+        CipherSpec::Ssl2(cipher)
+    }
+}
+
+/// Encodes suites as the TLS `cipher_suites<2..2^16-2>` vector: a big-endian
+/// 16-bit byte length followed by one big-endian 16-bit code point per suite.
+///
+/// Anything convertible into a `CipherSuiteId` can be encoded, so GREASE and
+/// unassigned values can be mixed in for testing. The list must not be
+/// empty.
+///
+/// ```
+/// use openssl_to_rfc::{encode_cipher_suites, TLSCipherSuite};
+///
+/// let bytes = encode_cipher_suites(&[
+///     TLSCipherSuite::TLS_AES_128_GCM_SHA256,
+///     TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+/// ])
+/// .unwrap();
+/// assert_eq!(bytes, [0x00, 0x04, 0x13, 0x01, 0xC0, 0x2F]);
+/// ```
+pub fn encode_cipher_suites<T>(suites: &[T]) -> Result<Vec<u8>, WireError>
+where
+    T: Copy + Into<CipherSuiteId>,
+{
+    let length = suites.len() * 2;
+    if length == 0 {
+        return Err(WireError::Empty);
+    }
+    if length > 0xFFFE {
+        return Err(WireError::TooLong(length));
+    }
+
+    let mut out = Vec::with_capacity(2 + length);
+    out.extend_from_slice(&(length as u16).to_be_bytes());
+    for suite in suites {
+        out.extend_from_slice(&(*suite).into().0.to_be_bytes());
+    }
+    Ok(out)
+}
+
+/// Decodes a TLS `cipher_suites` vector produced by `encode_cipher_suites`.
+/// The input must contain exactly the vector.
+pub fn decode_cipher_suites(input: &[u8]) -> Result<Vec<CipherSuiteId>, WireError> {
+    let (suites, rest) = read_cipher_suites(input)?;
+    if !rest.is_empty() {
+        return Err(WireError::TrailingData(rest.len()));
+    }
+    Ok(suites)
+}
+
+/// Reads a TLS `cipher_suites` vector from the front of `input` and returns it
+/// along with the remaining bytes.
+pub(crate) fn read_cipher_suites(input: &[u8]) -> Result<(Vec<CipherSuiteId>, &[u8]), WireError> {
+    let (list, rest) = read_vec16(input)?;
+    let length = list.len();
+    if length == 0 {
+        return Err(WireError::Empty);
+    }
+    if length % 2 != 0 {
+        return Err(WireError::OddLength { length, entry: 2 });
+    }
+
+    let suites = list
+        .chunks_exact(2)
+        .map(|c| CipherSuiteId(u16::from_be_bytes([c[0], c[1]])))
+        .collect();
+    Ok((suites, rest))
+}
+
+/// Encodes an SSLv2 `cipher_specs` list: three big-endian bytes per entry.
+///
+/// SSLv2 carries the list's length in the CLIENT-HELLO header rather than in
+/// front of the list, so none is written here. `CipherSpec::Unknown` values
+/// above `0xFFFFFF` are rejected.
+pub fn encode_sslv2_cipher_specs<T>(specs: &[T]) -> Result<Vec<u8>, WireError>
+where
+    T: Copy + Into<CipherSpec>,
+{
+    let length = specs.len() * 3;
+    if length > 0xFFFF {
+        return Err(WireError::TooLong(length));
+    }
+
+    let mut out = Vec::with_capacity(length);
+    for spec in specs {
+        let value = (*spec).into().as_u32();
+        if value > 0xFFFFFF {
+            return Err(WireError::SpecOutOfRange(value));
+        }
+        out.extend_from_slice(&value.to_be_bytes()[1..]);
+    }
+    Ok(out)
+}
+
+/// Decodes an SSLv2 `cipher_specs` list. The input must be exactly the list,
+/// as delimited by the CLIENT-HELLO header's `cipher_specs_length`.
+pub fn decode_sslv2_cipher_specs(input: &[u8]) -> Result<Vec<CipherSpec>, WireError> {
+    if input.len() % 3 != 0 {
+        return Err(WireError::OddLength {
+            length: input.len(),
+            entry: 3,
+        });
+    }
+
+    Ok(input
+        .chunks_exact(3)
+        .map(|c| CipherSpec::from_u32(u32::from_be_bytes([0, c[0], c[1], c[2]])))
+        .collect())
+}
+
+pub(crate) fn read_u8(input: &[u8]) -> Result<(u8, &[u8]), WireError> {
+    match input {
+        [b, rest @ ..] => Ok((*b, rest)),
+        _ => Err(WireError::Truncated {
+            needed: 1,
+            available: 0,
+        }),
+    }
+}
+
+pub(crate) fn read_u16(input: &[u8]) -> Result<(u16, &[u8]), WireError> {
+    match input {
+        [hi, lo, rest @ ..] => Ok((u16::from_be_bytes([*hi, *lo]), rest)),
+        _ => Err(WireError::Truncated {
+            needed: 2,
+            available: input.len(),
+        }),
+    }
+}
+
+/// Splits `length` bytes off the front of `input`.
+pub(crate) fn read_bytes(input: &[u8], length: usize) -> Result<(&[u8], &[u8]), WireError> {
+    if input.len() < length {
+        return Err(WireError::Truncated {
+            needed: length,
+            available: input.len(),
+        });
+    }
+    Ok(input.split_at(length))
+}
+
+/// Reads a vector with an 8-bit length prefix.
+pub(crate) fn read_vec8(input: &[u8]) -> Result<(&[u8], &[u8]), WireError> {
+    let (length, rest) = read_u8(input)?;
+    read_bytes(rest, usize::from(length))
+}
+
+/// Reads a vector with a 16-bit length prefix.
+pub(crate) fn read_vec16(input: &[u8]) -> Result<(&[u8], &[u8]), WireError> {
+    let (length, rest) = read_u16(input)?;
+    read_bytes(rest, usize::from(length))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TLSCipherSuite;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn round_trip_tls() {
+        let suites: Vec<_> = TLSCipherSuite::iter().collect();
+        let bytes = encode_cipher_suites(&suites).unwrap();
+        let decoded = decode_cipher_suites(&bytes).unwrap();
+        let decoded: Vec<_> = decoded.iter().map(|id| id.known().unwrap()).collect();
+        assert_eq!(decoded, suites);
+
+        let ids = [CipherSuiteId(0x0A0A), CipherSuiteId(0x1301)];
+        let bytes = encode_cipher_suites(&ids).unwrap();
+        assert_eq!(bytes, [0x00, 0x04, 0x0A, 0x0A, 0x13, 0x01]);
+    }
+
+    #[test]
+    fn malformed_tls() {
+        assert_eq!(
+            decode_cipher_suites(&[0x00]),
+            Err(WireError::Truncated {
+                needed: 2,
+                available: 1
+            })
+        );
+        assert_eq!(
+            decode_cipher_suites(&[0x00, 0x04, 0x13, 0x01]),
+            Err(WireError::Truncated {
+                needed: 4,
+                available: 2
+            })
+        );
+        assert_eq!(
+            decode_cipher_suites(&[0x00, 0x03, 0x13, 0x01, 0x13]),
+            Err(WireError::OddLength {
+                length: 3,
+                entry: 2
+            })
+        );
+        assert_eq!(
+            decode_cipher_suites(&[0x00, 0x02, 0x13, 0x01, 0x00]),
+            Err(WireError::TrailingData(1))
+        );
+        assert_eq!(decode_cipher_suites(&[0x00, 0x00]), Err(WireError::Empty));
+        assert_eq!(
+            encode_cipher_suites::<TLSCipherSuite>(&[]),
+            Err(WireError::Empty)
+        );
+    }
+
+    #[test]
+    fn round_trip_sslv2() {
+        let mut specs: Vec<CipherSpec> = SSLV2CipherSuite::iter().map(CipherSpec::from).collect();
+        specs.push(CipherSpec::Tls(CipherSuiteId(0x002F)));
+        specs.push(CipherSpec::Unknown(0xFF0080));
+
+        let bytes = encode_sslv2_cipher_specs(&specs).unwrap();
+        assert_eq!(&bytes[..3], [0x01, 0x00, 0x80]);
+        assert_eq!(decode_sslv2_cipher_specs(&bytes).unwrap(), specs);
+
+        assert_eq!(
+            decode_sslv2_cipher_specs(&bytes[1..]),
+            Err(WireError::OddLength {
+                length: bytes.len() - 1,
+                entry: 3
+            })
+        );
+
+        assert_eq!(
+            encode_sslv2_cipher_specs(&[CipherSpec::Unknown(0x01020304)]),
+            Err(WireError::SpecOutOfRange(0x01020304))
+        );
+        assert_eq!(
+            encode_sslv2_cipher_specs(&[CipherSpec::Unknown(0xFFFFFF)]).unwrap(),
+            [0xFF, 0xFF, 0xFF]
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use strum::IntoEnumIterator;
+
+use crate::TLSCipherSuite;
+
+impl TLSCipherSuite {
+    /// Returns the name wolfSSL uses for the suite in cipher lists and
+    /// `wolfSSL_CIPHER_get_name`, or `None` if wolfSSL does not implement it.
+    ///
+    /// wolfSSL follows OpenSSL's names except that TLS 1.3 suites are named
+    /// like `TLS13-AES128-GCM-SHA256`, CCM with an 8-byte tag is spelled
+    /// `CCM-8` and the renegotiation SCSV is `RENEGOTIATION-INFO`.
+    ///
+    /// ```
+    /// use openssl_to_rfc::TLSCipherSuite;
+    ///
+    /// let cipher = TLSCipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_CCM_8;
+    /// assert_eq!(cipher.as_openssl_name(), "ECDHE-ECDSA-AES128-CCM8");
+    /// assert_eq!(cipher.as_wolfssl_name(), Some("ECDHE-ECDSA-AES128-CCM-8"));
+    ///
+    /// let cipher = TLSCipherSuite::TLS_ECCPWD_WITH_AES_128_GCM_SHA256;
+    /// assert_eq!(cipher.as_wolfssl_name(), None);
+    /// ```
+    pub fn as_wolfssl_name(&self) -> Option<&'static str> {
+        let name = match self {
+            Self::TLS_RSA_WITH_NULL_MD5 => "NULL-MD5",
+            Self::TLS_RSA_WITH_NULL_SHA => "NULL-SHA",
+            Self::TLS_RSA_WITH_RC4_128_MD5 => "RC4-MD5",
+            Self::TLS_RSA_WITH_RC4_128_SHA => "RC4-SHA",
+            Self::TLS_RSA_WITH_IDEA_CBC_SHA => "IDEA-CBC-SHA",
+            Self::TLS_RSA_WITH_3DES_EDE_CBC_SHA => "DES-CBC3-SHA",
+            Self::TLS_DHE_RSA_WITH_3DES_EDE_CBC_SHA => "EDH-RSA-DES-CBC3-SHA",
+            Self::TLS_RSA_WITH_AES_128_CBC_SHA => "AES128-SHA",
+            Self::TLS_DHE_RSA_WITH_AES_128_CBC_SHA => "DHE-RSA-AES128-SHA",
+            Self::TLS_DH_anon_WITH_AES_128_CBC_SHA => "ADH-AES128-SHA",
+            Self::TLS_RSA_WITH_AES_256_CBC_SHA => "AES256-SHA",
+            Self::TLS_DHE_RSA_WITH_AES_256_CBC_SHA => "DHE-RSA-AES256-SHA",
+            Self::TLS_RSA_WITH_NULL_SHA256 => "NULL-SHA256",
+            Self::TLS_RSA_WITH_AES_128_CBC_SHA256 => "AES128-SHA256",
+            Self::TLS_RSA_WITH_AES_256_CBC_SHA256 => "AES256-SHA256",
+            Self::TLS_RSA_WITH_CAMELLIA_128_CBC_SHA => "CAMELLIA128-SHA",
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_128_CBC_SHA => "DHE-RSA-CAMELLIA128-SHA",
+            Self::TLS_DHE_RSA_WITH_AES_128_CBC_SHA256 => "DHE-RSA-AES128-SHA256",
+            Self::TLS_DHE_RSA_WITH_AES_256_CBC_SHA256 => "DHE-RSA-AES256-SHA256",
+            Self::TLS_RSA_WITH_CAMELLIA_256_CBC_SHA => "CAMELLIA256-SHA",
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_256_CBC_SHA => "DHE-RSA-CAMELLIA256-SHA",
+            Self::TLS_PSK_WITH_AES_128_CBC_SHA => "PSK-AES128-CBC-SHA",
+            Self::TLS_PSK_WITH_AES_256_CBC_SHA => "PSK-AES256-CBC-SHA",
+            Self::TLS_RSA_WITH_AES_128_GCM_SHA256 => "AES128-GCM-SHA256",
+            Self::TLS_RSA_WITH_AES_256_GCM_SHA384 => "AES256-GCM-SHA384",
+            Self::TLS_DHE_RSA_WITH_AES_128_GCM_SHA256 => "DHE-RSA-AES128-GCM-SHA256",
+            Self::TLS_DHE_RSA_WITH_AES_256_GCM_SHA384 => "DHE-RSA-AES256-GCM-SHA384",
+            Self::TLS_DH_anon_WITH_AES_256_GCM_SHA384 => "ADH-AES256-GCM-SHA384",
+            Self::TLS_RSA_WITH_CAMELLIA_128_CBC_SHA256 => "CAMELLIA128-SHA256",
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_128_CBC_SHA256 => "DHE-RSA-CAMELLIA128-SHA256",
+            Self::TLS_RSA_WITH_CAMELLIA_256_CBC_SHA256 => "CAMELLIA256-SHA256",
+            Self::TLS_DHE_RSA_WITH_CAMELLIA_256_CBC_SHA256 => "DHE-RSA-CAMELLIA256-SHA256",
+            Self::TLS_ECDH_ECDSA_WITH_RC4_128_SHA => "ECDH-ECDSA-RC4-SHA",
+            Self::TLS_ECDH_ECDSA_WITH_3DES_EDE_CBC_SHA => "ECDH-ECDSA-DES-CBC3-SHA",
+            Self::TLS_ECDH_ECDSA_WITH_AES_128_CBC_SHA => "ECDH-ECDSA-AES128-SHA",
+            Self::TLS_ECDH_ECDSA_WITH_AES_256_CBC_SHA => "ECDH-ECDSA-AES256-SHA",
+            Self::TLS_ECDHE_ECDSA_WITH_NULL_SHA => "ECDHE-ECDSA-NULL-SHA",
+            Self::TLS_ECDHE_ECDSA_WITH_RC4_128_SHA => "ECDHE-ECDSA-RC4-SHA",
+            Self::TLS_ECDHE_ECDSA_WITH_3DES_EDE_CBC_SHA => "ECDHE-ECDSA-DES-CBC3-SHA",
+            Self::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA => "ECDHE-ECDSA-AES128-SHA",
+            Self::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA => "ECDHE-ECDSA-AES256-SHA",
+            Self::TLS_ECDH_RSA_WITH_RC4_128_SHA => "ECDH-RSA-RC4-SHA",
+            Self::TLS_ECDH_RSA_WITH_3DES_EDE_CBC_SHA => "ECDH-RSA-DES-CBC3-SHA",
+            Self::TLS_ECDH_RSA_WITH_AES_128_CBC_SHA => "ECDH-RSA-AES128-SHA",
+            Self::TLS_ECDH_RSA_WITH_AES_256_CBC_SHA => "ECDH-RSA-AES256-SHA",
+            Self::TLS_ECDHE_RSA_WITH_RC4_128_SHA => "ECDHE-RSA-RC4-SHA",
+            Self::TLS_ECDHE_RSA_WITH_3DES_EDE_CBC_SHA => "ECDHE-RSA-DES-CBC3-SHA",
+            Self::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA => "ECDHE-RSA-AES128-SHA",
+            Self::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA => "ECDHE-RSA-AES256-SHA",
+            Self::TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA256 => "ECDHE-ECDSA-AES128-SHA256",
+            Self::TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA384 => "ECDHE-ECDSA-AES256-SHA384",
+            Self::TLS_ECDH_ECDSA_WITH_AES_128_CBC_SHA256 => "ECDH-ECDSA-AES128-SHA256",
+            Self::TLS_ECDH_ECDSA_WITH_AES_256_CBC_SHA384 => "ECDH-ECDSA-AES256-SHA384",
+            Self::TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA256 => "ECDHE-RSA-AES128-SHA256",
+            Self::TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA384 => "ECDHE-RSA-AES256-SHA384",
+            Self::TLS_ECDH_RSA_WITH_AES_128_CBC_SHA256 => "ECDH-RSA-AES128-SHA256",
+            Self::TLS_ECDH_RSA_WITH_AES_256_CBC_SHA384 => "ECDH-RSA-AES256-SHA384",
+            Self::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256 => "ECDHE-ECDSA-AES128-GCM-SHA256",
+            Self::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384 => "ECDHE-ECDSA-AES256-GCM-SHA384",
+            Self::TLS_ECDH_ECDSA_WITH_AES_128_GCM_SHA256 => "ECDH-ECDSA-AES128-GCM-SHA256",
+            Self::TLS_ECDH_ECDSA_WITH_AES_256_GCM_SHA384 => "ECDH-ECDSA-AES256-GCM-SHA384",
+            Self::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256 => "ECDHE-RSA-AES128-GCM-SHA256",
+            Self::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384 => "ECDHE-RSA-AES256-GCM-SHA384",
+            Self::TLS_ECDH_RSA_WITH_AES_128_GCM_SHA256 => "ECDH-RSA-AES128-GCM-SHA256",
+            Self::TLS_ECDH_RSA_WITH_AES_256_GCM_SHA384 => "ECDH-RSA-AES256-GCM-SHA384",
+            Self::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256 => "ECDHE-RSA-CHACHA20-POLY1305",
+            Self::TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256 => "ECDHE-ECDSA-CHACHA20-POLY1305",
+            Self::TLS_DHE_RSA_WITH_CHACHA20_POLY1305_SHA256 => "DHE-RSA-CHACHA20-POLY1305",
+            Self::OLD_TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256 => {
+                "ECDHE-RSA-CHACHA20-POLY1305-OLD"
+            }
+            Self::OLD_TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256 => {
+                "ECDHE-ECDSA-CHACHA20-POLY1305-OLD"
+            }
+            Self::OLD_TLS_DHE_RSA_WITH_CHACHA20_POLY1305_SHA256 => "DHE-RSA-CHACHA20-POLY1305-OLD",
+            Self::TLS_RSA_WITH_AES_128_CCM_8 => "AES128-CCM-8",
+            Self::TLS_RSA_WITH_AES_256_CCM_8 => "AES256-CCM-8",
+            Self::TLS_ECDHE_ECDSA_WITH_AES_128_CCM => "ECDHE-ECDSA-AES128-CCM",
+            Self::TLS_ECDHE_ECDSA_WITH_AES_128_CCM_8 => "ECDHE-ECDSA-AES128-CCM-8",
+            Self::TLS_ECDHE_ECDSA_WITH_AES_256_CCM_8 => "ECDHE-ECDSA-AES256-CCM-8",
+            Self::TLS_DHE_PSK_WITH_AES_128_CBC_SHA256 => "DHE-PSK-AES128-CBC-SHA256",
+            Self::TLS_DHE_PSK_WITH_AES_128_CCM => "DHE-PSK-AES128-CCM",
+            Self::TLS_DHE_PSK_WITH_AES_128_GCM_SHA256 => "DHE-PSK-AES128-GCM-SHA256",
+            Self::TLS_DHE_PSK_WITH_AES_256_CBC_SHA384 => "DHE-PSK-AES256-CBC-SHA384",
+            Self::TLS_DHE_PSK_WITH_AES_256_CCM => "DHE-PSK-AES256-CCM",
+            Self::TLS_DHE_PSK_WITH_AES_256_GCM_SHA384 => "DHE-PSK-AES256-GCM-SHA384",
+            Self::TLS_DHE_PSK_WITH_CHACHA20_POLY1305_SHA256 => "DHE-PSK-CHACHA20-POLY1305",
+            Self::TLS_DHE_PSK_WITH_NULL_SHA256 => "DHE-PSK-NULL-SHA256",
+            Self::TLS_DHE_PSK_WITH_NULL_SHA384 => "DHE-PSK-NULL-SHA384",
+            Self::TLS_ECDHE_ECDSA_WITH_ARIA_128_GCM_SHA256 => "ECDHE-ECDSA-ARIA128-GCM-SHA256",
+            Self::TLS_ECDHE_ECDSA_WITH_ARIA_256_GCM_SHA384 => "ECDHE-ECDSA-ARIA256-GCM-SHA384",
+            Self::TLS_ECDHE_PSK_WITH_AES_128_CBC_SHA256 => "ECDHE-PSK-AES128-CBC-SHA256",
+            Self::TLS_ECDHE_PSK_WITH_CHACHA20_POLY1305_SHA256 => "ECDHE-PSK-CHACHA20-POLY1305",
+            Self::TLS_ECDHE_PSK_WITH_NULL_SHA256 => "ECDHE-PSK-NULL-SHA256",
+            Self::TLS_PSK_WITH_AES_128_CBC_SHA256 => "PSK-AES128-CBC-SHA256",
+            Self::TLS_PSK_WITH_AES_128_CCM => "PSK-AES128-CCM",
+            Self::TLS_PSK_WITH_AES_128_CCM_8 => "PSK-AES128-CCM-8",
+            Self::TLS_PSK_WITH_AES_128_GCM_SHA256 => "PSK-AES128-GCM-SHA256",
+            Self::TLS_PSK_WITH_AES_256_CBC_SHA384 => "PSK-AES256-CBC-SHA384",
+            Self::TLS_PSK_WITH_AES_256_CCM => "PSK-AES256-CCM",
+            Self::TLS_PSK_WITH_AES_256_CCM_8 => "PSK-AES256-CCM-8",
+            Self::TLS_PSK_WITH_AES_256_GCM_SHA384 => "PSK-AES256-GCM-SHA384",
+            Self::TLS_PSK_WITH_CHACHA20_POLY1305_SHA256 => "PSK-CHACHA20-POLY1305",
+            Self::TLS_PSK_WITH_NULL_SHA => "PSK-NULL-SHA",
+            Self::TLS_PSK_WITH_NULL_SHA256 => "PSK-NULL-SHA256",
+            Self::TLS_PSK_WITH_NULL_SHA384 => "PSK-NULL-SHA384",
+            Self::TLS_AES_128_GCM_SHA256 => "TLS13-AES128-GCM-SHA256",
+            Self::TLS_AES_256_GCM_SHA384 => "TLS13-AES256-GCM-SHA384",
+            Self::TLS_CHACHA20_POLY1305_SHA256 => "TLS13-CHACHA20-POLY1305-SHA256",
+            Self::TLS_AES_128_CCM_SHA256 => "TLS13-AES128-CCM-SHA256",
+            Self::TLS_AES_128_CCM_8_SHA256 => "TLS13-AES128-CCM-8-SHA256",
+            Self::TLS_SM4_GCM_SM3 => "TLS13-SM4-GCM-SM3",
+            Self::TLS_SM4_CCM_SM3 => "TLS13-SM4-CCM-SM3",
+            Self::TLS_EMPTY_RENEGOTIATION_INFO_SCSV => "RENEGOTIATION-INFO",
+            Self::TLS_SHA256_SHA256 => "TLS13-SHA256-SHA256",
+            Self::TLS_SHA384_SHA384 => "TLS13-SHA384-SHA384",
+            Self::TLS_ECDHE_PSK_WITH_AES_128_GCM_SHA256 => "ECDHE-PSK-AES128-GCM-SHA256",
+            _ => return None,
+        };
+        Some(name)
+    }
+
+    /// Resolves a wolfSSL suite name.
+    pub fn from_wolfssl_name(name: &str) -> Option<TLSCipherSuite> {
+        TLSCipherSuite::iter().find(|cipher| cipher.as_wolfssl_name() == Some(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_wolfssl() {
+        for cipher in TLSCipherSuite::iter() {
+            if let Some(name) = cipher.as_wolfssl_name() {
+                assert_eq!(TLSCipherSuite::from_wolfssl_name(name), Some(cipher));
+            }
+        }
+
+        assert_eq!(
+            TLSCipherSuite::from_wolfssl_name("TLS13-AES128-CCM-8-SHA256"),
+            Some(TLSCipherSuite::TLS_AES_128_CCM_8_SHA256)
+        );
+        assert_eq!(
+            TLSCipherSuite::from_wolfssl_name("ECDHE-RSA-AES128-GCM-SHA256"),
+            Some(TLSCipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256)
+        );
+        assert_eq!(TLSCipherSuite::from_wolfssl_name("AES128-CCM8"), None);
+    }
+
+    #[test]
+    fn unimplemented_suites() {
+        for cipher in [
+            TLSCipherSuite::TLS_KRB5_WITH_3DES_EDE_CBC_SHA,
+            TLSCipherSuite::TLS_RSA_WITH_ARIA_128_CBC_SHA256,
+            TLSCipherSuite::TLS_GOSTR341112_256_WITH_KUZNYECHIK_CTR_OMAC,
+            TLSCipherSuite::TLS_AEGIS_256_SHA512,
+        ] {
+            assert_eq!(cipher.as_wolfssl_name(), None, "{}", cipher);
+        }
+        assert_eq!(
+            TLSCipherSuite::from_wolfssl_name("TLS_RSA_WITH_ARIA_128_CBC_SHA256"),
+            None
+        );
+    }
+}
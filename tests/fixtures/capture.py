@@ -0,0 +1,187 @@
+#!/usr/bin/env python3
+"""Regenerates the capture fixtures.
+
+Runs real `openssl s_client`/`s_server` handshakes through a local relay that
+records the bytes each side sends, then writes them out as pcap and pcapng
+files with synthetic Ethernet, IP and TCP framing. The first client segment
+of each flow is split in two and delivered out of order so that readers have
+to reassemble the ClientHello.
+"""
+
+import os
+import socket
+import struct
+import subprocess
+import tempfile
+import threading
+import time
+
+HERE = os.path.dirname(os.path.abspath(__file__))
+
+
+def record(server_args, client_args):
+    """Returns the (from_client, data) chunks of one handshake."""
+    tmp = tempfile.mkdtemp()
+    key, cert = os.path.join(tmp, "key.pem"), os.path.join(tmp, "cert.pem")
+    subprocess.run(
+        ["openssl", "req", "-x509", "-newkey", "rsa:2048", "-nodes", "-keyout", key,
+         "-out", cert, "-subj", "/CN=localhost", "-days", "1"],
+        check=True, capture_output=True,
+    )
+
+    server = subprocess.Popen(
+        ["openssl", "s_server", "-accept", "44330", "-key", key, "-cert", cert, "-naccept", "1"]
+        + server_args,
+        stdin=subprocess.PIPE, stdout=subprocess.DEVNULL, stderr=subprocess.DEVNULL,
+    )
+    time.sleep(0.5)
+
+    relay = socket.socket()
+    relay.setsockopt(socket.SOL_SOCKET, socket.SO_REUSEADDR, 1)
+    relay.bind(("127.0.0.1", 44331))
+    relay.listen(1)
+    chunks = []
+    lock = threading.Lock()
+
+    def pump(src, dst, from_client):
+        while True:
+            try:
+                data = src.recv(65536)
+            except OSError:
+                break
+            if not data:
+                break
+            with lock:
+                chunks.append((from_client, data))
+            try:
+                dst.sendall(data)
+            except OSError:
+                break
+
+    def accept():
+        client, _ = relay.accept()
+        upstream = socket.create_connection(("127.0.0.1", 44330))
+        threading.Thread(target=pump, args=(client, upstream, True), daemon=True).start()
+        threading.Thread(target=pump, args=(upstream, client, False), daemon=True).start()
+
+    threading.Thread(target=accept, daemon=True).start()
+    subprocess.run(
+        ["openssl", "s_client", "-connect", "127.0.0.1:44331", "-servername", "localhost"]
+        + client_args,
+        input=b"Q\n", capture_output=True, timeout=10,
+    )
+    time.sleep(0.5)
+    server.kill()
+    relay.close()
+    return chunks
+
+
+def checksum(data):
+    if len(data) % 2:
+        data += b"\0"
+    total = sum(struct.unpack("!%dH" % (len(data) // 2), data))
+    while total >> 16:
+        total = (total & 0xFFFF) + (total >> 16)
+    return ~total & 0xFFFF
+
+
+def tcp(src_port, dst_port, seq, ack, flags, payload, pseudo):
+    header = struct.pack("!HHIIBBHHH", src_port, dst_port, seq, ack, 5 << 4, flags, 65535, 0, 0)
+    segment = header + payload
+    csum = checksum(pseudo + struct.pack("!H", len(segment)) + segment)
+    return segment[:16] + struct.pack("!H", csum) + segment[18:]
+
+
+def ipv4(src, dst, segment):
+    header = struct.pack("!BBHHHBBH4s4s", 0x45, 0, 20 + len(segment), 0, 0x4000, 64, 6, 0,
+                         socket.inet_aton(src), socket.inet_aton(dst))
+    header = header[:10] + struct.pack("!H", checksum(header)) + header[12:]
+    return header, 0x0800
+
+
+def ipv6(src, dst, segment):
+    header = struct.pack("!IHBB16s16s", 6 << 28, len(segment), 6, 64,
+                         socket.inet_pton(socket.AF_INET6, src),
+                         socket.inet_pton(socket.AF_INET6, dst))
+    return header, 0x86DD
+
+
+def packets(chunks, client, server, v6=False):
+    """Frames the recorded chunks as Ethernet packets of one TCP flow."""
+    (cip, cport), (sip, sport) = client, server
+    family = socket.AF_INET6 if v6 else socket.AF_INET
+    cseq, sseq = 1000, 5000
+    out = []
+
+    def frame(from_client, flags, payload):
+        nonlocal cseq, sseq
+        src, dst = (client, server) if from_client else (server, client)
+        seq, ack = (cseq, sseq) if from_client else (sseq, cseq)
+        pseudo = (socket.inet_pton(family, src[0]) + socket.inet_pton(family, dst[0])
+                  + struct.pack("!BB", 0, 6))
+        segment = tcp(src[1], dst[1], seq, ack, flags, payload, pseudo)
+        header, ethertype = (ipv6 if v6 else ipv4)(src[0], dst[0], segment)
+        mac = b"\x02\x00\x00\x00\x00\x01", b"\x02\x00\x00\x00\x00\x02"
+        eth = (mac[1] + mac[0] if from_client else mac[0] + mac[1]) + struct.pack("!H", ethertype)
+        return eth + header + segment
+
+    out.append(frame(True, 0x02, b""))
+    cseq += 1
+    out.append(frame(False, 0x12, b""))
+    sseq += 1
+    out.append(frame(True, 0x10, b""))
+
+    first = True
+    for from_client, data in chunks:
+        if from_client and first:
+            first = False
+            half = len(data) // 2
+            a = frame(True, 0x18, data[:half])
+            cseq += half
+            b = frame(True, 0x18, data[half:])
+            cseq += len(data) - half
+            out += [b, a]
+            continue
+        out.append(frame(from_client, 0x18, data))
+        if from_client:
+            cseq += len(data)
+        else:
+            sseq += len(data)
+    return out
+
+
+def write_pcap(path, frames):
+    with open(path, "wb") as f:
+        f.write(struct.pack("<IHHiIII", 0xA1B2C3D4, 2, 4, 0, 0, 65535, 1))
+        for i, frame in enumerate(frames):
+            f.write(struct.pack("<IIII", 1700000000, i, len(frame), len(frame)))
+            f.write(frame)
+
+
+def write_pcapng(path, frames):
+    def block(kind, body):
+        body += b"\0" * (-len(body) % 4)
+        length = 12 + len(body)
+        return struct.pack("<II", kind, length) + body + struct.pack("<I", length)
+
+    with open(path, "wb") as f:
+        f.write(block(0x0A0D0D0A, struct.pack("<IHHq", 0x1A2B3C4D, 1, 0, -1)))
+        f.write(block(1, struct.pack("<HHI", 1, 0, 65535)))
+        for i, frame in enumerate(frames):
+            f.write(block(6, struct.pack("<IIIII", 0, 0, i, len(frame), len(frame)) + frame))
+
+
+def main():
+    tls13 = record([], [])
+    tls12 = record(["-tls1_2"], ["-tls1_2", "-cipher", "ECDHE-RSA-AES128-GCM-SHA256:AES128-SHA"])
+
+    frames = packets(tls13, ("10.0.0.1", 50000), ("10.0.0.2", 443))
+    frames += packets(tls12, ("10.0.0.1", 50001), ("10.0.0.2", 443))
+    write_pcap(os.path.join(HERE, "handshakes.pcap"), frames)
+
+    frames = packets(tls12, ("2001:db8::1", 50002), ("2001:db8::2", 443), v6=True)
+    write_pcapng(os.path.join(HERE, "handshake-ipv6.pcapng"), frames)
+
+
+if __name__ == "__main__":
+    main()